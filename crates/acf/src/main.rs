@@ -0,0 +1,29 @@
+//! A SP1 program committing the autocorrelation function of a time series.
+//!
+//! Alongside the autocorrelations the program commits the white-noise significance bound, so a
+//! verifier can judge which lags are significant without access to the data.
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use alloy_sol_types::SolValue;
+use lib_timeseries::TimeSeries;
+
+pub fn main() {
+    // Read the timestamps and values from the prover
+    let timestamps = sp1_zkvm::io::read::<Vec<u64>>();
+    let values = sp1_zkvm::io::read::<Vec<f64>>();
+    let max_lag = sp1_zkvm::io::read::<usize>();
+    let confidence = sp1_zkvm::io::read::<f64>();
+
+    // Create a TimeSeries instance for statistical analysis
+    let time_series = TimeSeries::new(timestamps, values);
+
+    // Generate the public values struct for the autocorrelation function
+    let public_values = time_series.to_acf_public_values(max_lag, confidence);
+
+    // Encode the public values using ABI encoding
+    let bytes = public_values.abi_encode();
+
+    // Commit the encoded public values as output of the ZK proof
+    sp1_zkvm::io::commit_slice(&bytes);
+}
@@ -0,0 +1,30 @@
+//! A SP1 program committing how many z-score anomalies a time series contains and when the first
+//! one occurred.
+//!
+//! A point is anomalous when its distance from the mean exceeds `z_threshold` standard
+//! deviations, so an operator can prove that fewer than N anomalies occurred over a period without
+//! revealing the raw telemetry. A series with zero variance has no anomalies.
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use alloy_sol_types::SolValue;
+use lib_timeseries::TimeSeries;
+
+pub fn main() {
+    // Read the timestamps, values and z-score threshold from the prover
+    let timestamps = sp1_zkvm::io::read::<Vec<u64>>();
+    let values = sp1_zkvm::io::read::<Vec<f64>>();
+    let z_threshold = sp1_zkvm::io::read::<f64>();
+
+    // Create a TimeSeries instance for statistical analysis
+    let time_series = TimeSeries::new(timestamps, values);
+
+    // Generate the public values struct for the anomaly count
+    let public_values = time_series.to_anomaly_public_values(z_threshold);
+
+    // Encode the public values using ABI encoding
+    let bytes = public_values.abi_encode();
+
+    // Commit the encoded public values as output of the ZK proof
+    sp1_zkvm::io::commit_slice(&bytes);
+}
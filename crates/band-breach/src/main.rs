@@ -0,0 +1,29 @@
+//! A SP1 program committing how many points of a time series breached their upper Bollinger band.
+//!
+//! The upper band is the rolling mean plus `k` rolling standard deviations, so the proof shows how
+//! often the hidden series spiked without revealing it.
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use alloy_sol_types::SolValue;
+use lib_timeseries::TimeSeries;
+
+pub fn main() {
+    // Read the timestamps, values, window size and band width from the prover
+    let timestamps = sp1_zkvm::io::read::<Vec<u64>>();
+    let values = sp1_zkvm::io::read::<Vec<f64>>();
+    let window_size = sp1_zkvm::io::read::<usize>();
+    let k = sp1_zkvm::io::read::<f64>();
+
+    // Create a TimeSeries instance for statistical analysis
+    let time_series = TimeSeries::new(timestamps, values);
+
+    // Generate the public values struct for the band breaches
+    let public_values = time_series.to_band_breach_public_values(window_size, k);
+
+    // Encode the public values using ABI encoding
+    let bytes = public_values.abi_encode();
+
+    // Commit the encoded public values as output of the ZK proof
+    sp1_zkvm::io::commit_slice(&bytes);
+}
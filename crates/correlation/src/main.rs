@@ -0,0 +1,33 @@
+//! A SP1 program proving that the Pearson correlation of two time series exceeds a threshold.
+//!
+//! Only the hashes of both series, the threshold and the outcome are committed, so neither
+//! series nor their exact correlation is revealed.
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use alloy_sol_types::SolValue;
+use lib_timeseries::TimeSeries;
+
+pub fn main() {
+    // Read both series and the correlation threshold from the prover
+    let timestamps = sp1_zkvm::io::read::<Vec<u64>>();
+    let values = sp1_zkvm::io::read::<Vec<f64>>();
+    let other_timestamps = sp1_zkvm::io::read::<Vec<u64>>();
+    let other_values = sp1_zkvm::io::read::<Vec<f64>>();
+    let threshold = sp1_zkvm::io::read::<f64>();
+
+    // Create TimeSeries instances for statistical analysis
+    let time_series = TimeSeries::new(timestamps, values);
+    let other = TimeSeries::new(other_timestamps, other_values);
+
+    // Generate the public values struct for the correlation claim
+    let public_values = time_series
+        .to_correlation_public_values(&other, threshold)
+        .expect("series cannot be correlated");
+
+    // Encode the public values using ABI encoding
+    let bytes = public_values.abi_encode();
+
+    // Commit the encoded public values as output of the ZK proof
+    sp1_zkvm::io::commit_slice(&bytes);
+}
@@ -0,0 +1,32 @@
+//! A SP1 program committing the number of CUSUM change points in a time series.
+//!
+//! A committed count of zero attests that the series never drifted from the target level for
+//! the duration of the attested period.
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use alloy_sol_types::SolValue;
+use lib_timeseries::TimeSeries;
+
+pub fn main() {
+    // Read the timestamps, values and CUSUM parameters from the prover
+    let timestamps = sp1_zkvm::io::read::<Vec<u64>>();
+    let values = sp1_zkvm::io::read::<Vec<f64>>();
+    let target = sp1_zkvm::io::read::<f64>();
+    let slack = sp1_zkvm::io::read::<f64>();
+    let threshold = sp1_zkvm::io::read::<f64>();
+
+    // Create a TimeSeries instance for statistical analysis
+    let time_series = TimeSeries::new(timestamps, values);
+
+    // Generate the public values struct for the change-point count
+    let public_values = time_series
+        .to_cusum_public_values(target, slack, threshold)
+        .expect("invalid values or CUSUM parameters");
+
+    // Encode the public values using ABI encoding
+    let bytes = public_values.abi_encode();
+
+    // Commit the encoded public values as output of the ZK proof
+    sp1_zkvm::io::commit_slice(&bytes);
+}
@@ -31,7 +31,9 @@ pub fn main() {
     let time_series = TimeSeries::new(timestamps, forecast_values);
 
     // Generate the public values struct
-    let public_values = time_series.to_public_values();
+    let public_values = time_series
+        .to_public_values()
+        .expect("values must be finite");
 
     // Encode the public values using ABI encoding
     let bytes = public_values.abi_encode();
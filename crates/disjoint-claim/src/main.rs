@@ -0,0 +1,29 @@
+//! A SP1 program committing how much of a provider's time range is not yet claimed.
+//!
+//! The previously claimed ranges are public: the program commits their hash, so a payout contract
+//! can check the proof was made against its own record of claims and pay only for the
+//! non-overlapping seconds.
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use alloy_sol_types::SolValue;
+use lib_timeseries::TimeSeries;
+
+pub fn main() {
+    // Read the timestamps, values and previously claimed ranges from the prover
+    let timestamps = sp1_zkvm::io::read::<Vec<u64>>();
+    let values = sp1_zkvm::io::read::<Vec<f64>>();
+    let claimed = sp1_zkvm::io::read::<Vec<(u64, u64)>>();
+
+    // Create a TimeSeries instance for statistical analysis
+    let time_series = TimeSeries::new(timestamps, values);
+
+    // Generate the public values struct for the claim
+    let public_values = time_series.to_claim_public_values(&claimed);
+
+    // Encode the public values using ABI encoding
+    let bytes = public_values.abi_encode();
+
+    // Commit the encoded public values as output of the ZK proof
+    sp1_zkvm::io::commit_slice(&bytes);
+}
@@ -0,0 +1,28 @@
+//! A SP1 program committing both a Keccak256 and a SHA-256 hash of a time series.
+//!
+//! Both hashes cover the same preimage, so one proof serves Keccak-native and SHA-256-native
+//! verifiers alike. The workspace patches `sha2` and `sha3` so both hashes run on the SP1
+//! precompiles.
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use alloy_sol_types::SolValue;
+use lib_timeseries::TimeSeries;
+
+pub fn main() {
+    // Read the timestamps and values from the prover
+    let timestamps = sp1_zkvm::io::read::<Vec<u64>>();
+    let values = sp1_zkvm::io::read::<Vec<f64>>();
+
+    // Create a TimeSeries instance for statistical analysis
+    let time_series = TimeSeries::new(timestamps, values);
+
+    // Generate the public values struct carrying both hashes
+    let public_values = time_series.to_dual_hash_public_values();
+
+    // Encode the public values using ABI encoding
+    let bytes = public_values.abi_encode();
+
+    // Commit the encoded public values as output of the ZK proof
+    sp1_zkvm::io::commit_slice(&bytes);
+}
@@ -0,0 +1,28 @@
+//! A SP1 program committing the exponential moving average of a time series.
+//!
+//! The smoothing factor is given either directly or as a period and convention. In the latter
+//! case the period and convention are committed too, so the verifier knows which alpha was meant.
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use alloy_sol_types::SolValue;
+use lib_timeseries::{EmaSmoothing, TimeSeries};
+
+pub fn main() {
+    // Read the timestamps, values and smoothing specification from the prover
+    let timestamps = sp1_zkvm::io::read::<Vec<u64>>();
+    let values = sp1_zkvm::io::read::<Vec<f64>>();
+    let smoothing = sp1_zkvm::io::read::<EmaSmoothing>();
+
+    // Create a TimeSeries instance for statistical analysis
+    let time_series = TimeSeries::new(timestamps, values);
+
+    // Generate the public values struct for the exponential moving average
+    let public_values = time_series.to_ema_public_values(smoothing);
+
+    // Encode the public values using ABI encoding
+    let bytes = public_values.abi_encode();
+
+    // Commit the encoded public values as output of the ZK proof
+    sp1_zkvm::io::commit_slice(&bytes);
+}
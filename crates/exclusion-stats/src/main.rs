@@ -0,0 +1,28 @@
+//! A SP1 program computing statistics over a time series with maintenance windows excluded.
+//!
+//! The exclusion ranges are public: the program commits their hash and total duration, together
+//! with the hashes of both the full and the filtered series.
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use alloy_sol_types::SolValue;
+use lib_timeseries::TimeSeries;
+
+pub fn main() {
+    // Read the timestamps, values and exclusion ranges from the prover
+    let timestamps = sp1_zkvm::io::read::<Vec<u64>>();
+    let values = sp1_zkvm::io::read::<Vec<f64>>();
+    let ranges = sp1_zkvm::io::read::<Vec<(u64, u64)>>();
+
+    // Create a TimeSeries instance for statistical analysis
+    let time_series = TimeSeries::new(timestamps, values);
+
+    // Generate the public values struct over the points outside the exclusion ranges
+    let public_values = time_series.to_exclusion_public_values(&ranges);
+
+    // Encode the public values using ABI encoding
+    let bytes = public_values.abi_encode();
+
+    // Commit the encoded public values as output of the ZK proof
+    sp1_zkvm::io::commit_slice(&bytes);
+}
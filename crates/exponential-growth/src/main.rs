@@ -0,0 +1,29 @@
+//! A SP1 program committing the exponential growth rate of a time series.
+//!
+//! The rate comes from a least-squares fit of the log values against time, committed with the
+//! R² of the fit so a verifier can tell a steady compounding trend from a noisy one.
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use alloy_sol_types::SolValue;
+use lib_timeseries::TimeSeries;
+
+pub fn main() {
+    // Read the timestamps and values from the prover
+    let timestamps = sp1_zkvm::io::read::<Vec<u64>>();
+    let values = sp1_zkvm::io::read::<Vec<f64>>();
+
+    // Create a TimeSeries instance for statistical analysis
+    let time_series = TimeSeries::new(timestamps, values);
+
+    // Generate the public values struct for the exponential growth rate
+    let public_values = time_series
+        .to_exponential_growth_public_values()
+        .expect("growth rate is undefined for this series");
+
+    // Encode the public values using ABI encoding
+    let bytes = public_values.abi_encode();
+
+    // Commit the encoded public values as output of the ZK proof
+    sp1_zkvm::io::commit_slice(&bytes);
+}
@@ -0,0 +1,28 @@
+//! A SP1 program committing the longest flatline of a time series.
+//!
+//! A long run of (nearly) identical consecutive readings is the signature of a stuck sensor, so
+//! committing the longest run lets a buyer check the feed was live.
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use alloy_sol_types::SolValue;
+use lib_timeseries::TimeSeries;
+
+pub fn main() {
+    // Read the timestamps, values and flatness tolerance from the prover
+    let timestamps = sp1_zkvm::io::read::<Vec<u64>>();
+    let values = sp1_zkvm::io::read::<Vec<f64>>();
+    let epsilon = sp1_zkvm::io::read::<f64>();
+
+    // Create a TimeSeries instance for statistical analysis
+    let time_series = TimeSeries::new(timestamps, values);
+
+    // Generate the public values struct for the longest flatline
+    let public_values = time_series.to_flatline_public_values(epsilon);
+
+    // Encode the public values using ABI encoding
+    let bytes = public_values.abi_encode();
+
+    // Commit the encoded public values as output of the ZK proof
+    sp1_zkvm::io::commit_slice(&bytes);
+}
@@ -0,0 +1,30 @@
+//! A SP1 program committing a simple exponential smoothing forecast with prediction bands.
+//!
+//! Committing the lower and upper bands alongside the point forecast proves the forecast together
+//! with its uncertainty.
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use alloy_sol_types::SolValue;
+use lib_timeseries::TimeSeries;
+
+pub fn main() {
+    // Read the timestamps, values and forecast parameters from the prover
+    let timestamps = sp1_zkvm::io::read::<Vec<u64>>();
+    let values = sp1_zkvm::io::read::<Vec<f64>>();
+    let alpha = sp1_zkvm::io::read::<f64>();
+    let horizon = sp1_zkvm::io::read::<usize>();
+    let z = sp1_zkvm::io::read::<f64>();
+
+    // Create a TimeSeries instance for statistical analysis
+    let time_series = TimeSeries::new(timestamps, values);
+
+    // Generate the public values struct for the forecast and its bands
+    let public_values = time_series.to_forecast_interval_public_values(alpha, horizon, z);
+
+    // Encode the public values using ABI encoding
+    let bytes = public_values.abi_encode();
+
+    // Commit the encoded public values as output of the ZK proof
+    sp1_zkvm::io::commit_slice(&bytes);
+}
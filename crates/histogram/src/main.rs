@@ -0,0 +1,31 @@
+//! A SP1 program committing a histogram of a time series.
+//!
+//! The bin edges are fixed by the prover's input rather than derived from the data, and only they,
+//! the counts and the number of values below and above the edges are committed, so the proof
+//! shows the shape of the distribution without revealing individual readings.
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use alloy_sol_types::SolValue;
+use lib_timeseries::TimeSeries;
+
+pub fn main() {
+    // Read the timestamps, values and bin edges from the prover
+    let timestamps = sp1_zkvm::io::read::<Vec<u64>>();
+    let values = sp1_zkvm::io::read::<Vec<f64>>();
+    let edges = sp1_zkvm::io::read::<Vec<f64>>();
+
+    // Create a TimeSeries instance for statistical analysis
+    let time_series = TimeSeries::new(timestamps, values);
+
+    // Generate the public values struct for the histogram
+    let public_values = time_series
+        .to_histogram_public_values(&edges)
+        .expect("invalid values or bin edges");
+
+    // Encode the public values using ABI encoding
+    let bytes = public_values.abi_encode();
+
+    // Commit the encoded public values as output of the ZK proof
+    sp1_zkvm::io::commit_slice(&bytes);
+}
@@ -0,0 +1,34 @@
+//! A SP1 program committing an additive Holt-Winters forecast of a time series.
+//!
+//! The smoothing parameters, season length and horizon are committed with the forecast, so a
+//! verifier knows exactly which model produced it.
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use alloy_sol_types::SolValue;
+use lib_timeseries::TimeSeries;
+
+pub fn main() {
+    // Read the timestamps, values and model parameters from the prover
+    let timestamps = sp1_zkvm::io::read::<Vec<u64>>();
+    let values = sp1_zkvm::io::read::<Vec<f64>>();
+    let alpha = sp1_zkvm::io::read::<f64>();
+    let beta = sp1_zkvm::io::read::<f64>();
+    let gamma = sp1_zkvm::io::read::<f64>();
+    let season_length = sp1_zkvm::io::read::<usize>();
+    let horizon = sp1_zkvm::io::read::<usize>();
+
+    // Create a TimeSeries instance for statistical analysis
+    let time_series = TimeSeries::new(timestamps, values);
+
+    // Generate the public values struct for the Holt-Winters forecast
+    let public_values = time_series
+        .to_holt_winters_public_values(alpha, beta, gamma, season_length, horizon)
+        .expect("invalid Holt-Winters input");
+
+    // Encode the public values using ABI encoding
+    let bytes = public_values.abi_encode();
+
+    // Commit the encoded public values as output of the ZK proof
+    sp1_zkvm::io::commit_slice(&bytes);
+}
@@ -0,0 +1,29 @@
+//! A SP1 program smoothing a time series with a local-level Kalman filter and RTS smoother.
+//!
+//! The hash of the smoothed series is committed next to the hash of the raw readings, binding the
+//! denoised trace to the data it came from.
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use alloy_sol_types::SolValue;
+use lib_timeseries::TimeSeries;
+
+pub fn main() {
+    // Read the timestamps, values and noise variances from the prover
+    let timestamps = sp1_zkvm::io::read::<Vec<u64>>();
+    let values = sp1_zkvm::io::read::<Vec<f64>>();
+    let process_var = sp1_zkvm::io::read::<f64>();
+    let measurement_var = sp1_zkvm::io::read::<f64>();
+
+    // Create a TimeSeries instance for statistical analysis
+    let time_series = TimeSeries::new(timestamps, values);
+
+    // Generate the public values struct for the smoothed series
+    let public_values = time_series.to_kalman_public_values(process_var, measurement_var);
+
+    // Encode the public values using ABI encoding
+    let bytes = public_values.abi_encode();
+
+    // Commit the encoded public values as output of the ZK proof
+    sp1_zkvm::io::commit_slice(&bytes);
+}
@@ -0,0 +1,48 @@
+//! Compares the sequential and `rayon` statistics on large series.
+//!
+//! Run with `cargo bench -p lib-timeseries --features rayon --bench parallel`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use lib_timeseries::{batch_summary, TimeSeries};
+
+/// Runs `f` `iterations` times and returns the mean time per run.
+fn time<T>(iterations: u32, mut f: impl FnMut() -> T) -> Duration {
+    let start = Instant::now();
+    for _ in 0..iterations {
+        black_box(f());
+    }
+    start.elapsed() / iterations
+}
+
+fn series(n: u64, seed: u64) -> TimeSeries {
+    TimeSeries::new(
+        (0..n).collect(),
+        (0..n).map(|i| ((i * 7919 + seed) % 1000) as f64).collect(),
+    )
+}
+
+fn main() {
+    let large = series(10_000_000, 0);
+    println!(
+        "mean            {:>12?} sequential {:>12?} parallel",
+        time(10, || large.mean()),
+        time(10, || large.mean_par())
+    );
+    println!(
+        "std_dev         {:>12?} sequential {:>12?} parallel",
+        time(10, || large.std_dev()),
+        time(10, || large.std_dev_par())
+    );
+
+    let batch: Vec<TimeSeries> = (0..64).map(|seed| series(100_000, seed)).collect();
+    println!(
+        "summary (64x)   {:>12?} sequential {:>12?} parallel",
+        time(3, || batch
+            .iter()
+            .map(TimeSeries::summary)
+            .collect::<Vec<_>>()),
+        time(3, || batch_summary(&batch))
+    );
+}
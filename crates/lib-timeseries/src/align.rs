@@ -0,0 +1,119 @@
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+use crate::{Extrapolation, TimeSeries};
+
+/// Which timestamps [`TimeSeries::align`] keeps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum AlignMode {
+    /// Only the timestamps present in both series.
+    Inner,
+    /// Every timestamp present in either series. A series missing a timestamp is linearly
+    /// interpolated there, or takes its nearest end value outside its own range.
+    Outer,
+}
+
+impl TimeSeries {
+    /// Restricts two series to a common set of timestamps, so they can be paired point by
+    /// point for [`TimeSeries::covariance`] and similar statistics.
+    ///
+    /// Both series are sorted by timestamp first, and among points sharing a timestamp the
+    /// last one in the input is kept, as in [`TimeSeries::interpolate_linear`].
+    ///
+    /// # Arguments
+    /// * `other` - The series to align with
+    /// * `mode` - Whether to keep the intersection or the union of the timestamps
+    ///
+    /// # Returns
+    /// `self` and `other` on the same sorted timestamps. Both are empty if the series share no
+    /// timestamp in [`AlignMode::Inner`], or if either series is empty.
+    pub fn align(&self, other: &TimeSeries, mode: AlignMode) -> (TimeSeries, TimeSeries) {
+        let left = self.deduplicated_by_time();
+        let right = other.deduplicated_by_time();
+        if left.values.is_empty() || right.values.is_empty() {
+            return (
+                TimeSeries::new(vec![], vec![]),
+                TimeSeries::new(vec![], vec![]),
+            );
+        }
+        match mode {
+            AlignMode::Inner => {
+                let (mut i, mut j) = (0, 0);
+                let mut timestamps = Vec::new();
+                let (mut left_values, mut right_values) = (Vec::new(), Vec::new());
+                while i < left.timestamps.len() && j < right.timestamps.len() {
+                    let (t, u) = (left.timestamps[i], right.timestamps[j]);
+                    if t == u {
+                        timestamps.push(t);
+                        left_values.push(left.values[i]);
+                        right_values.push(right.values[j]);
+                    }
+                    if t <= u {
+                        i += 1;
+                    }
+                    if u <= t {
+                        j += 1;
+                    }
+                }
+                (
+                    TimeSeries::new(timestamps.clone(), left_values),
+                    TimeSeries::new(timestamps, right_values),
+                )
+            }
+            AlignMode::Outer => {
+                let mut timestamps = left.timestamps.clone();
+                timestamps.extend_from_slice(&right.timestamps);
+                timestamps.sort_unstable();
+                timestamps.dedup();
+                let interpolate = |series: &TimeSeries| {
+                    series
+                        .interpolate_linear(&timestamps, Extrapolation::Clamp)
+                        .expect("targets are sorted and the series is not empty")
+                };
+                (interpolate(&left), interpolate(&right))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_align_subset() {
+        let daily = TimeSeries::new(vec![0, 1, 2, 3, 4], vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+        let sparse = TimeSeries::new(vec![3, 1], vec![30.0, 10.0]);
+
+        let (left, right) = daily.align(&sparse, AlignMode::Inner);
+        assert_eq!(left.timestamps, vec![1, 3]);
+        assert_eq!(left.values, vec![2.0, 4.0]);
+        assert_eq!(right.timestamps, vec![1, 3]);
+        assert_eq!(right.values, vec![10.0, 30.0]);
+
+        let (left, right) = daily.align(&sparse, AlignMode::Outer);
+        assert_eq!(left.values, daily.values);
+        assert_eq!(right.timestamps, daily.timestamps);
+        assert_eq!(right.values, vec![10.0, 10.0, 20.0, 30.0, 30.0]);
+    }
+
+    #[test]
+    fn test_align_disjoint() {
+        let even = TimeSeries::new(vec![0, 2, 4], vec![1.0, 2.0, 3.0]);
+        let odd = TimeSeries::new(vec![1, 3], vec![5.0, 7.0]);
+
+        let (left, right) = even.align(&odd, AlignMode::Inner);
+        assert!(left.values.is_empty() && left.timestamps.is_empty());
+        assert!(right.values.is_empty() && right.timestamps.is_empty());
+
+        let (left, right) = even.align(&odd, AlignMode::Outer);
+        assert_eq!(left.timestamps, vec![0, 1, 2, 3, 4]);
+        assert_eq!(left.values, vec![1.0, 1.5, 2.0, 2.5, 3.0]);
+        assert_eq!(right.values, vec![5.0, 5.0, 6.0, 7.0, 7.0]);
+
+        let empty = TimeSeries::new(vec![], vec![]);
+        assert!(even.align(&empty, AlignMode::Outer).0.values.is_empty());
+    }
+}
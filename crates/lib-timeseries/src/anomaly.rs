@@ -0,0 +1,171 @@
+use crate::prelude::*;
+use crate::{f64_to_u256, AnomalyPublicValuesStruct, TimeSeries, TimeSeriesError};
+
+impl TimeSeries {
+    /// Finds the points whose z-score against the series mean and standard deviation exceeds
+    /// `threshold` in absolute value.
+    ///
+    /// The z-score uses the population standard deviation (see [`TimeSeries::std_dev`]). A
+    /// series with zero variance has no anomalies, and neither does an empty series.
+    ///
+    /// # Arguments
+    /// * `threshold` - The absolute z-score a point must exceed to count (threshold >= 0)
+    ///
+    /// # Returns
+    /// The `(timestamp, value)` pairs of the anomalous points, in series order.
+    pub fn anomalies_zscore(&self, threshold: f64) -> Vec<(u64, f64)> {
+        assert!(threshold >= 0.0, "Z threshold must be non-negative");
+        if self.values.is_empty() {
+            return Vec::new();
+        }
+        let mean = self.mean();
+        let std_dev = self.std_dev();
+        if std_dev == 0.0 {
+            return Vec::new();
+        }
+        self.timestamps
+            .iter()
+            .zip(self.values.iter())
+            .filter(|(_, &value)| ((value - mean) / std_dev).abs() > threshold)
+            .map(|(&timestamp, &value)| (timestamp, value))
+            .collect()
+    }
+
+    /// Finds the indices of the points whose [robust z-score](TimeSeries::robust_zscore) exceeds
+    /// `threshold` in absolute value.
+    ///
+    /// This is the robust counterpart of [`TimeSeries::anomalies_zscore`]: the median and MAD
+    /// hardly move when a few readings are wild, so one bad sensor value cannot hide the others.
+    ///
+    /// # Arguments
+    /// * `threshold` - The absolute robust z-score a point must exceed to count (threshold >= 0)
+    ///
+    /// # Errors
+    /// Returns the errors of [`TimeSeries::robust_zscore`], including
+    /// [`TimeSeriesError::ZeroMad`] when more than half the values are identical.
+    pub fn anomalies_mad(&self, threshold: f64) -> Result<Vec<usize>, TimeSeriesError> {
+        assert!(threshold >= 0.0, "Z threshold must be non-negative");
+        let scores = self.robust_zscore()?;
+        Ok(scores
+            .values
+            .iter()
+            .enumerate()
+            .filter(|(_, z)| z.abs() > threshold)
+            .map(|(index, _)| index)
+            .collect())
+    }
+
+    /// Counts the points flagged by [`TimeSeries::anomalies_zscore`].
+    ///
+    /// # Arguments
+    /// * `z_threshold` - The absolute z-score a point must exceed to count (z_threshold >= 0)
+    pub fn anomaly_count(&self, z_threshold: f64) -> usize {
+        self.anomalies_zscore(z_threshold).len()
+    }
+
+    /// Builds the public values of the anomaly program.
+    ///
+    /// `first_anomaly_timestamp` is 0 when the series has no anomalies.
+    pub fn to_anomaly_public_values(&self, z_threshold: f64) -> AnomalyPublicValuesStruct {
+        let values_hash = self.compute_hash();
+        let start_timestamp = *self.timestamps.first().unwrap_or(&0);
+        let end_timestamp = *self.timestamps.last().unwrap_or(&0);
+        let anomalies = self.anomalies_zscore(z_threshold);
+        let first_anomaly_timestamp = anomalies.first().map_or(0, |&(timestamp, _)| timestamp);
+
+        AnomalyPublicValuesStruct {
+            start_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(start_timestamp),
+            end_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(end_timestamp),
+            values_hash: alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(values_hash),
+            z_threshold: f64_to_u256(z_threshold),
+            anomaly_count: alloy_sol_types::private::Uint::<256, 4>::from(anomalies.len()),
+            first_anomaly_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(
+                first_anomaly_timestamp,
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_anomaly_count_finds_two_outliers() {
+        let mut values = vec![10.0; 20];
+        values[5] = 100.0;
+        values[14] = -80.0;
+        let ts = TimeSeries::new((0..20).collect(), values);
+        assert_eq!(ts.anomaly_count(2.0), 2);
+
+        let public_values = ts.to_anomaly_public_values(2.0);
+        assert_eq!(
+            public_values.anomaly_count,
+            alloy_sol_types::private::Uint::<256, 4>::from(2u64)
+        );
+        assert_eq!(public_values.z_threshold, f64_to_u256(2.0));
+        assert_eq!(
+            public_values.end_timestamp,
+            alloy_sol_types::private::Uint::<256, 4>::from(19u64)
+        );
+    }
+
+    #[test]
+    fn test_anomalies_zscore_returns_points() {
+        let ts = TimeSeries::new(
+            vec![10, 20, 30, 40, 50, 60, 70, 80, 90, 100],
+            vec![1.0, 1.0, 1.0, 1.0, 30.0, 1.0, 1.0, 1.0, 1.0, 1.0],
+        );
+        assert_eq!(ts.anomalies_zscore(2.0), vec![(50, 30.0)]);
+
+        let public_values = ts.to_anomaly_public_values(2.0);
+        assert_eq!(
+            public_values.first_anomaly_timestamp,
+            alloy_sol_types::private::Uint::<256, 4>::from(50u64)
+        );
+        assert_eq!(
+            public_values.anomaly_count,
+            alloy_sol_types::private::Uint::<256, 4>::from(1u64)
+        );
+
+        let none = ts.to_anomaly_public_values(10.0);
+        assert_eq!(
+            none.first_anomaly_timestamp,
+            alloy_sol_types::private::Uint::<256, 4>::from(0u64)
+        );
+    }
+
+    #[test]
+    fn test_anomalies_mad_survives_masking() {
+        let mut values: Vec<f64> = (0..20).map(|i| 10.0 + (i % 5) as f64).collect();
+        values[3] = 60.0;
+        values[11] = 500.0;
+        let ts = TimeSeries::new((0..20).collect(), values);
+
+        // The 500 inflates the standard deviation enough to hide the 60 from the z-score.
+        assert_eq!(ts.anomalies_zscore(3.0), vec![(11, 500.0)]);
+        assert_eq!(ts.anomalies_mad(3.5), Ok(vec![3, 11]));
+
+        let mostly_flat = TimeSeries::new(vec![1, 2, 3], vec![1.0, 1.0, 7.0]);
+        assert_eq!(
+            mostly_flat.anomalies_mad(3.5),
+            Err(TimeSeriesError::ZeroMad)
+        );
+    }
+
+    #[test]
+    fn test_anomaly_count_edge_cases() {
+        let flat = TimeSeries::new(vec![1, 2, 3], vec![5.0, 5.0, 5.0]);
+        assert_eq!(flat.anomaly_count(0.0), 0);
+        assert!(flat.anomalies_zscore(0.0).is_empty());
+
+        let empty = TimeSeries::new(vec![], vec![]);
+        assert_eq!(empty.anomaly_count(3.0), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Z threshold must be non-negative")]
+    fn test_anomaly_count_rejects_negative_threshold() {
+        TimeSeries::new(vec![1], vec![1.0]).anomaly_count(-1.0);
+    }
+}
@@ -0,0 +1,152 @@
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+use crate::{TimeSeries, TimeSeriesError};
+
+/// An autoregressive model of order `p` fitted to a series.
+///
+/// The model describes deviations from the series mean:
+/// `x[t] - mean = Σ coefficients[i] * (x[t - 1 - i] - mean) + noise`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ArModel {
+    /// The autoregressive coefficients, starting with lag 1.
+    pub coefficients: Vec<f64>,
+    /// The mean of the fitted series.
+    pub mean: f64,
+    /// The estimated variance of the noise term.
+    pub noise_variance: f64,
+    /// The last `p` values of the fitted series, oldest first, used to seed forecasts.
+    history: Vec<f64>,
+}
+
+impl ArModel {
+    /// Forecasts the next `horizon` values after the end of the fitted series.
+    ///
+    /// Each forecast feeds back in as history for the following ones, so forecasts decay towards
+    /// the mean for a stationary model.
+    pub fn forecast(&self, horizon: usize) -> Vec<f64> {
+        let mut deviations: Vec<f64> = self.history.iter().map(|v| v - self.mean).collect();
+        let mut forecast = Vec::with_capacity(horizon);
+        for _ in 0..horizon {
+            let next: f64 = self
+                .coefficients
+                .iter()
+                .zip(deviations.iter().rev())
+                .map(|(c, d)| c * d)
+                .sum();
+            deviations.push(next);
+            forecast.push(self.mean + next);
+        }
+        forecast
+    }
+}
+
+impl TimeSeries {
+    /// Fits an AR(`order`) model by solving the Yule-Walker equations.
+    ///
+    /// The autocorrelations come from [`TimeSeries::acf`], and the Toeplitz system is solved
+    /// with the Levinson-Durbin recursion.
+    ///
+    /// # Errors
+    /// Returns [`TimeSeriesError::InsufficientData`] if the series has no more than `order`
+    /// points, [`TimeSeriesError::ZeroVariance`] if it is constant, and
+    /// [`TimeSeriesError::SingularMatrix`] if the Yule-Walker system is singular.
+    pub fn ar_fit(&self, order: usize) -> Result<ArModel, TimeSeriesError> {
+        if self.values.len() <= order {
+            return Err(TimeSeriesError::InsufficientData {
+                required: order + 1,
+                actual: self.values.len(),
+            });
+        }
+        let mean = self.mean();
+        let variance =
+            self.values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / self.values.len() as f64;
+        if variance == 0.0 {
+            return Err(TimeSeriesError::ZeroVariance);
+        }
+
+        let acf = self.acf(order);
+        let mut coefficients: Vec<f64> = Vec::with_capacity(order);
+        let mut prediction_error = 1.0;
+        for k in 1..=order {
+            let residual = acf[k]
+                - coefficients
+                    .iter()
+                    .enumerate()
+                    .map(|(j, c)| c * acf[k - 1 - j])
+                    .sum::<f64>();
+            let reflection = residual / prediction_error;
+            let previous = coefficients.clone();
+            for (j, c) in coefficients.iter_mut().enumerate() {
+                *c -= reflection * previous[k - 2 - j];
+            }
+            coefficients.push(reflection);
+            prediction_error *= 1.0 - reflection * reflection;
+            if prediction_error.is_nan() || prediction_error <= f64::EPSILON {
+                return Err(TimeSeriesError::SingularMatrix);
+            }
+        }
+
+        Ok(ArModel {
+            coefficients,
+            mean,
+            noise_variance: prediction_error * variance,
+            history: self.values[self.values.len() - order..].to_vec(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Simulates `x[t] = 0.6 x[t-1] - 0.3 x[t-2] + noise` with small uniform noise.
+    fn ar2_process(n: usize) -> TimeSeries {
+        let mut state: u64 = 7;
+        let mut values = vec![10.0, 10.0];
+        for t in 2..n {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            let noise = ((state >> 11) as f64 / (1u64 << 53) as f64 - 0.5) * 0.1;
+            values.push(10.0 + 0.6 * (values[t - 1] - 10.0) - 0.3 * (values[t - 2] - 10.0) + noise);
+        }
+        TimeSeries::new((0..n as u64).collect(), values)
+    }
+
+    #[test]
+    fn test_ar_fit_recovers_ar2_coefficients() {
+        let model = ar2_process(5000).ar_fit(2).unwrap();
+        assert!((model.coefficients[0] - 0.6).abs() < 0.05);
+        assert!((model.coefficients[1] + 0.3).abs() < 0.05);
+        // Uniform noise on [-0.05, 0.05) has variance 0.1² / 12.
+        assert!((model.noise_variance / (0.01 / 12.0) - 1.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_ar_forecast_recursion() {
+        let model = ArModel {
+            coefficients: vec![0.5],
+            mean: 1.0,
+            noise_variance: 0.0,
+            history: vec![3.0],
+        };
+        assert_eq!(model.forecast(3), vec![2.0, 1.5, 1.25]);
+    }
+
+    #[test]
+    fn test_ar_fit_errors() {
+        let short = TimeSeries::new(vec![1, 2], vec![1.0, 2.0]);
+        assert_eq!(
+            short.ar_fit(2),
+            Err(TimeSeriesError::InsufficientData {
+                required: 3,
+                actual: 2
+            })
+        );
+        let constant = TimeSeries::new(vec![1, 2, 3, 4], vec![5.0; 4]);
+        assert_eq!(constant.ar_fit(1), Err(TimeSeriesError::ZeroVariance));
+    }
+}
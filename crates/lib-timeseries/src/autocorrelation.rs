@@ -0,0 +1,218 @@
+use crate::prelude::*;
+use crate::{f64_to_u256, vec_f64_to_i256, AcfPublicValuesStruct, TimeSeries};
+
+impl TimeSeries {
+    /// Computes the autocorrelation function for lags `0..=max_lag`.
+    ///
+    /// Uses the standard biased estimator: the lag-`k` autocovariance is summed over the
+    /// `n - k` overlapping pairs but divided by `n`, then normalized by the variance at lag 0.
+    /// Lags at or beyond the length of the series are `0.0`. For a constant series the
+    /// autocorrelation is undefined and every lag but 0 is NaN.
+    ///
+    /// # Arguments
+    /// * `max_lag` - The largest lag to compute
+    pub fn acf(&self, max_lag: usize) -> Vec<f64> {
+        let (deviations, variance) = self.deviations_from_mean();
+        (0..=max_lag)
+            .map(|lag| lagged_autocorrelation(&deviations, variance, lag))
+            .collect()
+    }
+
+    /// Computes the autocorrelation at a single lag.
+    ///
+    /// Uses the same estimator as [`TimeSeries::acf`], so lag 0 is `1.0`, lags at or beyond the
+    /// length of the series are `0.0`, and every other lag of a constant series is NaN.
+    ///
+    /// # Arguments
+    /// * `lag` - The lag to compute
+    pub fn autocorrelation(&self, lag: usize) -> f64 {
+        let (deviations, variance) = self.deviations_from_mean();
+        lagged_autocorrelation(&deviations, variance, lag)
+    }
+
+    /// Returns each value's deviation from the mean and the sum of their squares.
+    fn deviations_from_mean(&self) -> (Vec<f64>, f64) {
+        let mean = self.mean();
+        let deviations: Vec<f64> = self.values.iter().map(|&v| v - mean).collect();
+        let variance = deviations.iter().map(|d| d * d).sum();
+        (deviations, variance)
+    }
+
+    /// Computes the autocorrelation function together with its significance bound.
+    ///
+    /// Under the null hypothesis of white noise, the sample autocorrelations are approximately
+    /// normal with standard deviation `1/√n`, so lags whose absolute autocorrelation exceeds
+    /// `z/√n` are significant at the given confidence level.
+    ///
+    /// # Arguments
+    /// * `max_lag` - The largest lag to compute
+    /// * `confidence` - The two-sided confidence level (0 < confidence < 1), e.g. 0.95
+    ///
+    /// # Returns
+    /// The autocorrelations for lags `0..=max_lag` and the bound `z/√n`.
+    pub fn acf_with_bounds(&self, max_lag: usize, confidence: f64) -> (Vec<f64>, f64) {
+        assert!(
+            confidence > 0.0 && confidence < 1.0,
+            "Confidence must be between 0 and 1"
+        );
+        let z = normal_quantile(1.0 - (1.0 - confidence) / 2.0);
+        let bound = z / (self.values.len() as f64).sqrt();
+        (self.acf(max_lag), bound)
+    }
+
+    pub fn to_acf_public_values(&self, max_lag: usize, confidence: f64) -> AcfPublicValuesStruct {
+        let start_timestamp = *self.timestamps.first().unwrap_or(&0);
+        let end_timestamp = *self.timestamps.last().unwrap_or(&0);
+        let values_hash = self.compute_hash();
+        let (acf, bound) = self.acf_with_bounds(max_lag, confidence);
+
+        AcfPublicValuesStruct {
+            start_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(start_timestamp),
+            end_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(end_timestamp),
+            values_hash: alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(values_hash),
+            max_lag: alloy_sol_types::private::Uint::<256, 4>::from(max_lag),
+            confidence: f64_to_u256(confidence),
+            bound: f64_to_u256(bound),
+            acf: vec_f64_to_i256(&acf),
+        }
+    }
+}
+
+/// The estimator behind [`TimeSeries::acf`]: the lag-`lag` autocovariance of `deviations`, summed
+/// over the overlapping pairs and divided by `variance`, their total sum of squares.
+fn lagged_autocorrelation(deviations: &[f64], variance: f64, lag: usize) -> f64 {
+    if lag == 0 {
+        1.0
+    } else if lag >= deviations.len() {
+        0.0
+    } else {
+        let autocovariance: f64 = deviations
+            .iter()
+            .zip(deviations[lag..].iter())
+            .map(|(a, b)| a * b)
+            .sum();
+        autocovariance / variance
+    }
+}
+
+/// Computes the quantile function (inverse CDF) of the standard normal distribution.
+///
+/// Uses Acklam's rational approximation, which has a relative error below 1.15e-9 over `(0, 1)`.
+pub(crate) fn normal_quantile(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969683028665376e1,
+        2.209460984245205e2,
+        -2.759285104469687e2,
+        1.38357751867269e2,
+        -3.066479806614716e1,
+        2.506628277459239,
+    ];
+    const B: [f64; 5] = [
+        -5.447609879822406e1,
+        1.615858368580409e2,
+        -1.556989798598866e2,
+        6.680131188771972e1,
+        -1.328068155288572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784894002430293e-3,
+        -3.223964580411365e-1,
+        -2.400758277161838,
+        -2.549732539343734,
+        4.374664141464968,
+        2.938163982698783,
+    ];
+    const D: [f64; 4] = [
+        7.784695709041462e-3,
+        3.224671290700398e-1,
+        2.445134137142996,
+        3.754408661907416,
+    ];
+    const P_LOW: f64 = 0.02425;
+
+    let tail = |q: f64| {
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    };
+    if p < P_LOW {
+        tail((-2.0 * p.ln()).sqrt())
+    } else if p <= 1.0 - P_LOW {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    } else {
+        -tail((-2.0 * (1.0 - p).ln()).sqrt())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Generates deterministic uniform noise in `[-0.5, 0.5)` with a linear congruential generator.
+    fn white_noise(n: usize) -> TimeSeries {
+        let mut state: u64 = 42;
+        let values = (0..n)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                (state >> 11) as f64 / (1u64 << 53) as f64 - 0.5
+            })
+            .collect();
+        TimeSeries::new((0..n as u64).collect(), values)
+    }
+
+    #[test]
+    fn test_normal_quantile() {
+        assert!((normal_quantile(0.975) - 1.959963984540054).abs() < 1e-8);
+        assert!((normal_quantile(0.5)).abs() < 1e-12);
+        assert!((normal_quantile(0.005) + 2.5758293035489).abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_white_noise_has_few_significant_lags() {
+        let ts = white_noise(500);
+        let (acf, bound) = ts.acf_with_bounds(20, 0.95);
+        assert_eq!(acf.len(), 21);
+        assert_eq!(acf[0], 1.0);
+        assert!((bound - 1.959963984540054 / 500f64.sqrt()).abs() < 1e-8);
+        let significant = acf[1..].iter().filter(|r| r.abs() > bound).count();
+        assert!(significant <= 3, "{significant} lags exceed the bound");
+    }
+
+    #[test]
+    fn test_acf_peaks_at_period() {
+        let values: Vec<f64> = (0..40).map(|i| [1.0, 3.0, 0.0, -2.0][i % 4]).collect();
+        let ts = TimeSeries::new((0..40).collect(), values);
+        let acf = ts.acf(6);
+        let peak = (1..=6).max_by(|&a, &b| acf[a].total_cmp(&acf[b]));
+        assert_eq!(peak, Some(4));
+        for (lag, r) in acf.iter().enumerate() {
+            assert!((ts.autocorrelation(lag) - r).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_autocorrelation_edge_cases() {
+        let constant = TimeSeries::new(vec![1, 2, 3], vec![5.0, 5.0, 5.0]);
+        assert_eq!(constant.autocorrelation(0), 1.0);
+        assert!(constant.autocorrelation(1).is_nan());
+        assert_eq!(constant.autocorrelation(3), 0.0);
+
+        let ts = white_noise(10);
+        assert_eq!(ts.autocorrelation(0), 1.0);
+        assert_eq!(ts.acf(0), vec![1.0]);
+        assert_eq!(ts.autocorrelation(25), 0.0);
+    }
+
+    #[test]
+    fn test_acf_public_values() {
+        let ts = TimeSeries::new(vec![1, 2, 3, 4], vec![1.0, -1.0, 1.0, -1.0]);
+        let public_values = ts.to_acf_public_values(2, 0.95);
+        assert_eq!(public_values.acf.len(), 3);
+        assert!(public_values.acf[1].is_negative());
+        assert!(public_values.acf[2].is_positive());
+    }
+}
@@ -0,0 +1,152 @@
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::prelude::*;
+use crate::{TimeSeries, TimeSeriesError};
+
+/// A forecasting method and its parameters, as evaluated by [`TimeSeries::backtest_one_step`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum ForecastMethod {
+    /// [`TimeSeries::simple_exponential_smoothing`] with the given smoothing factor.
+    SimpleExponentialSmoothing { alpha: f64 },
+    /// [`TimeSeries::holt_linear`] with the given level and trend smoothing factors.
+    HoltLinear { alpha: f64, beta: f64 },
+}
+
+impl ForecastMethod {
+    /// Forecasts the value one step after the end of `history`.
+    fn forecast_next(self, history: &TimeSeries) -> f64 {
+        let forecast = match self {
+            ForecastMethod::SimpleExponentialSmoothing { alpha } => {
+                history.simple_exponential_smoothing(alpha, 1)
+            }
+            ForecastMethod::HoltLinear { alpha, beta } => history.holt_linear(alpha, beta, 1),
+        };
+        forecast.values[forecast.values.len() - 1]
+    }
+}
+
+/// Accuracy of the one-step-ahead forecasts made by [`TimeSeries::backtest_one_step`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ForecastErrors {
+    /// The mean absolute error.
+    pub mae: f64,
+    /// The root mean squared error, which penalizes large misses more than `mae`.
+    pub rmse: f64,
+    /// The mean absolute percentage error, in percent, over the points whose actual value is
+    /// nonzero. NaN if every actual value is zero.
+    pub mape: f64,
+    /// The number of forecasts evaluated.
+    pub n: usize,
+}
+
+impl TimeSeries {
+    /// Backtests a forecasting method by predicting each point from the points before it.
+    ///
+    /// For every index `t` from `train_min` on, the method is fitted to the first `t` points and
+    /// its one-step forecast is compared with the actual value at `t`. Refitting each prefix
+    /// keeps the forecasts identical to calling the method directly, at O(n²) cost overall.
+    /// Comparing the errors of several parameter choices picks them empirically.
+    ///
+    /// # Errors
+    /// Returns [`TimeSeriesError::InsufficientData`] unless the series has more than
+    /// `train_min` points, so that at least one forecast is made.
+    ///
+    /// # Panics
+    /// Panics if `train_min` is 0 or the method's parameters are out of range.
+    pub fn backtest_one_step(
+        &self,
+        method: ForecastMethod,
+        train_min: usize,
+    ) -> Result<ForecastErrors, TimeSeriesError> {
+        assert!(train_min > 0, "Training size must be positive");
+        let n = self.values.len();
+        if n <= train_min {
+            return Err(TimeSeriesError::InsufficientData {
+                required: train_min + 1,
+                actual: n,
+            });
+        }
+
+        let mut absolute_sum = 0.0;
+        let mut squared_sum = 0.0;
+        let mut percentage_sum = 0.0;
+        let mut percentage_count = 0;
+        for t in train_min..n {
+            let history = TimeSeries::new(self.timestamps[..t].to_vec(), self.values[..t].to_vec());
+            let actual = self.values[t];
+            let error = actual - method.forecast_next(&history);
+            absolute_sum += error.abs();
+            squared_sum += error * error;
+            if actual != 0.0 {
+                percentage_sum += (error / actual).abs();
+                percentage_count += 1;
+            }
+        }
+
+        let forecasts = (n - train_min) as f64;
+        Ok(ForecastErrors {
+            mae: absolute_sum / forecasts,
+            rmse: (squared_sum / forecasts).sqrt(),
+            mape: 100.0 * percentage_sum / percentage_count as f64,
+            n: n - train_min,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backtest_naive_forecast() {
+        // With alpha = 1 the forecast is the previous value, so every error is 1.
+        let ts = TimeSeries::new(vec![1, 2, 3, 4], vec![1.0, 2.0, 3.0, 4.0]);
+        let errors = ts
+            .backtest_one_step(ForecastMethod::SimpleExponentialSmoothing { alpha: 1.0 }, 1)
+            .unwrap();
+        assert_eq!(errors.n, 3);
+        assert_eq!(errors.mae, 1.0);
+        assert_eq!(errors.rmse, 1.0);
+        let expected_mape = 100.0 * (1.0 / 2.0 + 1.0 / 3.0 + 1.0 / 4.0) / 3.0;
+        assert!((errors.mape - expected_mape).abs() < 1e-12);
+
+        assert_eq!(
+            ts.backtest_one_step(ForecastMethod::SimpleExponentialSmoothing { alpha: 1.0 }, 4),
+            Err(TimeSeriesError::InsufficientData {
+                required: 5,
+                actual: 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_holt_beats_ses_on_trending_series() {
+        let noise = [0.4, -0.3, 0.1, -0.5, 0.2, 0.0, -0.2, 0.5, -0.1, 0.3];
+        let values = (0..50)
+            .map(|i| 10.0 + 2.0 * i as f64 + noise[i % 10])
+            .collect();
+        let ts = TimeSeries::new((0..50).collect(), values);
+
+        let ses = ts
+            .backtest_one_step(ForecastMethod::SimpleExponentialSmoothing { alpha: 0.5 }, 5)
+            .unwrap();
+        let holt = ts
+            .backtest_one_step(
+                ForecastMethod::HoltLinear {
+                    alpha: 0.5,
+                    beta: 0.3,
+                },
+                5,
+            )
+            .unwrap();
+        assert_eq!(ses.n, 45);
+        // SES trails a trend of 2 per step by about 2 / alpha on each forecast.
+        assert!(ses.rmse > 1.5);
+        assert!(holt.rmse < ses.rmse);
+        assert!(holt.mae <= holt.rmse && ses.mae <= ses.rmse);
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{f64_to_u256, BandBreachPublicValuesStruct, TimeSeries};
+
+impl TimeSeries {
+    /// Computes Bollinger bands: the rolling mean plus and minus `k` rolling standard
+    /// deviations.
+    ///
+    /// All three series use the shrinking windows of [`TimeSeries::rolling_mean`] and
+    /// [`TimeSeries::rolling_std`], so each has one value per input point. Each window includes
+    /// its own point, so a spike raises the bands it is compared against.
+    ///
+    /// # Arguments
+    /// * `window_size` - The size of the moving window (window_size > 0)
+    /// * `k` - The number of standard deviations between the middle and each band (k >= 0)
+    ///
+    /// # Returns
+    /// The `(lower, middle, upper)` bands.
+    pub fn bollinger_bands(
+        &self,
+        window_size: usize,
+        k: f64,
+    ) -> (TimeSeries, TimeSeries, TimeSeries) {
+        assert!(k >= 0.0, "K must be non-negative");
+        let middle = self.rolling_mean(window_size);
+        let std = self.rolling_std(window_size);
+        let band = |sign: f64| {
+            let values = middle
+                .values
+                .iter()
+                .zip(std.values.iter())
+                .map(|(mean, std)| mean + sign * k * std)
+                .collect();
+            TimeSeries::new(self.timestamps.clone(), values)
+        };
+        let (lower, upper) = (band(-1.0), band(1.0));
+        (lower, middle, upper)
+    }
+
+    /// Counts the points strictly above their upper Bollinger band.
+    ///
+    /// # Arguments
+    /// * `window_size` - The size of the moving window (window_size > 0)
+    /// * `k` - The number of standard deviations between the middle and the band (k >= 0)
+    pub fn upper_band_breaches(&self, window_size: usize, k: f64) -> usize {
+        let (_, _, upper) = self.bollinger_bands(window_size, k);
+        self.values
+            .iter()
+            .zip(upper.values.iter())
+            .filter(|(value, upper)| value > upper)
+            .count()
+    }
+
+    pub fn to_band_breach_public_values(
+        &self,
+        window_size: usize,
+        k: f64,
+    ) -> BandBreachPublicValuesStruct {
+        let start_timestamp = *self.timestamps.first().unwrap_or(&0);
+        let end_timestamp = *self.timestamps.last().unwrap_or(&0);
+        let values_hash = self.compute_hash();
+
+        BandBreachPublicValuesStruct {
+            start_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(start_timestamp),
+            end_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(end_timestamp),
+            values_hash: alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(values_hash),
+            window_size: alloy_sol_types::private::Uint::<256, 4>::from(window_size),
+            k: f64_to_u256(k),
+            breach_count: alloy_sol_types::private::Uint::<256, 4>::from(
+                self.upper_band_breaches(window_size, k),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bollinger_bands() {
+        let ts = TimeSeries::new(vec![1, 2, 3, 4], vec![1.0, 3.0, 5.0, 7.0]);
+        let (lower, middle, upper) = ts.bollinger_bands(2, 2.0);
+        assert_eq!(middle.values, vec![1.0, 2.0, 4.0, 6.0]);
+        assert_eq!(lower.values, vec![1.0, 0.0, 2.0, 4.0]);
+        assert_eq!(upper.values, vec![1.0, 4.0, 6.0, 8.0]);
+        assert_eq!(upper.timestamps, ts.timestamps);
+    }
+
+    #[test]
+    fn test_spike_breaches_upper_band_once() {
+        let mut values = vec![1.0; 30];
+        values[15] = 20.0;
+        let ts = TimeSeries::new((0..30).collect(), values);
+        assert_eq!(ts.upper_band_breaches(10, 2.0), 1);
+
+        let public_values = ts.to_band_breach_public_values(10, 2.0);
+        assert_eq!(
+            public_values.breach_count,
+            alloy_sol_types::private::Uint::<256, 4>::from(1u64)
+        );
+        assert_eq!(
+            public_values.window_size,
+            alloy_sol_types::private::Uint::<256, 4>::from(10u64)
+        );
+        assert_eq!(public_values.k, f64_to_u256(2.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "K must be non-negative")]
+    fn test_bollinger_bands_rejects_negative_k() {
+        TimeSeries::new(vec![1], vec![1.0]).bollinger_bands(1, -1.0);
+    }
+}
@@ -0,0 +1,274 @@
+use crate::prelude::*;
+use crate::{vec_f64_to_u256, MovingAveragePublicValuesStruct, TimeSeries, TimeSeriesError};
+
+/// Builds a [`TimeSeries`] one point at a time, e.g. from streaming data.
+///
+/// Each point is pushed as a `(timestamp, value)` pair, so the two vectors always have the same
+/// length.
+#[derive(Clone, Debug, Default)]
+pub struct TimeSeriesBuilder {
+    timestamps: Vec<u64>,
+    values: Vec<f64>,
+}
+
+impl TimeSeriesBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a builder with room for `capacity` points.
+    pub fn with_capacity(capacity: usize) -> Self {
+        TimeSeriesBuilder {
+            timestamps: Vec::with_capacity(capacity),
+            values: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Turns this into a builder that rejects a timestamp earlier than the previous one.
+    /// Equal timestamps are still accepted, as in [`TimeSeries::is_monotonic`].
+    pub fn require_monotonic(self) -> MonotonicTimeSeriesBuilder {
+        MonotonicTimeSeriesBuilder { inner: self }
+    }
+
+    /// Appends a point.
+    pub fn push(&mut self, timestamp: u64, value: f64) {
+        self.timestamps.push(timestamp);
+        self.values.push(value);
+    }
+
+    /// Returns the number of points pushed so far.
+    pub fn len(&self) -> usize {
+        self.timestamps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.timestamps.is_empty()
+    }
+
+    pub fn build(self) -> TimeSeries {
+        TimeSeries {
+            timestamps: self.timestamps,
+            values: self.values,
+        }
+    }
+}
+
+/// A [`TimeSeriesBuilder`] whose timestamps must never decrease, created with
+/// [`TimeSeriesBuilder::require_monotonic`].
+#[derive(Clone, Debug, Default)]
+pub struct MonotonicTimeSeriesBuilder {
+    inner: TimeSeriesBuilder,
+}
+
+impl MonotonicTimeSeriesBuilder {
+    /// Appends a point.
+    ///
+    /// # Errors
+    /// Returns [`TimeSeriesError::NonMonotonicTimestamp`] if `timestamp` is earlier than the last
+    /// one pushed. The point is not added.
+    pub fn push(&mut self, timestamp: u64, value: f64) -> Result<(), TimeSeriesError> {
+        if self
+            .inner
+            .timestamps
+            .last()
+            .is_some_and(|&last| timestamp < last)
+        {
+            return Err(TimeSeriesError::NonMonotonicTimestamp {
+                index: self.inner.len(),
+            });
+        }
+        self.inner.push(timestamp, value);
+        Ok(())
+    }
+
+    /// Returns the number of points pushed so far.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    pub fn build(self) -> TimeSeries {
+        self.inner.build()
+    }
+}
+
+/// Entry point for building [`MovingAveragePublicValuesStruct`] by field name.
+///
+/// Guests should build the struct through [`MovingAveragePublicValues::builder`] rather than a
+/// struct literal: every field of the literal is a `uint256`, so transposing two of them compiles
+/// and verifies but commits the wrong values.
+pub struct MovingAveragePublicValues;
+
+impl MovingAveragePublicValues {
+    pub fn builder() -> MovingAveragePublicValuesBuilder {
+        MovingAveragePublicValuesBuilder::default()
+    }
+}
+
+/// A builder for [`MovingAveragePublicValuesStruct`] taking each field in its native type.
+#[derive(Clone, Debug, Default)]
+pub struct MovingAveragePublicValuesBuilder {
+    start_timestamp: Option<u64>,
+    end_timestamp: Option<u64>,
+    values_hash: Option<[u8; 32]>,
+    window_size: Option<usize>,
+    moving_averages: Option<Vec<f64>>,
+}
+
+impl MovingAveragePublicValuesBuilder {
+    pub fn start_timestamp(mut self, start_timestamp: u64) -> Self {
+        self.start_timestamp = Some(start_timestamp);
+        self
+    }
+
+    pub fn end_timestamp(mut self, end_timestamp: u64) -> Self {
+        self.end_timestamp = Some(end_timestamp);
+        self
+    }
+
+    pub fn values_hash(mut self, values_hash: [u8; 32]) -> Self {
+        self.values_hash = Some(values_hash);
+        self
+    }
+
+    pub fn window(mut self, window_size: usize) -> Self {
+        self.window_size = Some(window_size);
+        self
+    }
+
+    pub fn moving_averages(mut self, moving_averages: &[f64]) -> Self {
+        self.moving_averages = Some(moving_averages.to_vec());
+        self
+    }
+
+    /// Builds the struct, converting each field to its Solidity representation.
+    ///
+    /// # Errors
+    /// Returns [`TimeSeriesError::NonFiniteValue`] if a moving average is NaN or infinite, which
+    /// has no `uint256` representation.
+    ///
+    /// # Panics
+    /// Panics if a field was not set.
+    pub fn build(self) -> Result<MovingAveragePublicValuesStruct, TimeSeriesError> {
+        let start_timestamp = self.start_timestamp.expect("start_timestamp is required");
+        let end_timestamp = self.end_timestamp.expect("end_timestamp is required");
+        let values_hash = self.values_hash.expect("values_hash is required");
+        let window_size = self.window_size.expect("window is required");
+        let moving_averages = self.moving_averages.expect("moving_averages is required");
+        if let Some(index) = moving_averages.iter().position(|v| !v.is_finite()) {
+            return Err(TimeSeriesError::NonFiniteValue { index });
+        }
+
+        Ok(MovingAveragePublicValuesStruct {
+            start_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(start_timestamp),
+            end_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(end_timestamp),
+            values_hash: alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(values_hash),
+            window_size: alloy_sol_types::private::Uint::<256, 4>::from(window_size),
+            moving_averages: vec_f64_to_u256(&moving_averages),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_sol_types::SolValue;
+
+    use super::*;
+
+    #[test]
+    fn test_time_series_builder_matches_new() {
+        let timestamps: Vec<u64> = vec![10, 20, 20, 40];
+        let values = vec![1.5, -2.0, 3.0, 0.25];
+        let mut builder = TimeSeriesBuilder::with_capacity(4);
+        for (&timestamp, &value) in timestamps.iter().zip(values.iter()) {
+            builder.push(timestamp, value);
+        }
+        assert_eq!(builder.len(), 4);
+        let built = builder.build();
+        let expected = TimeSeries::new(timestamps, values);
+        assert_eq!(built.timestamps, expected.timestamps);
+        assert_eq!(built.values, expected.values);
+        assert_eq!(built.compute_hash(), expected.compute_hash());
+
+        assert!(TimeSeriesBuilder::new().build().values.is_empty());
+    }
+
+    #[test]
+    fn test_time_series_builder_requires_monotonic() {
+        let mut builder = TimeSeriesBuilder::new().require_monotonic();
+        builder.push(10, 1.0).unwrap();
+        builder.push(10, 2.0).unwrap();
+        assert_eq!(
+            builder.push(5, 3.0),
+            Err(TimeSeriesError::NonMonotonicTimestamp { index: 2 })
+        );
+        builder.push(30, 4.0).unwrap();
+        assert_eq!(builder.build().timestamps, vec![10, 10, 30]);
+
+        let mut unchecked = TimeSeriesBuilder::new();
+        unchecked.push(10, 1.0);
+        unchecked.push(5, 3.0);
+        assert_eq!(unchecked.build().timestamps, vec![10, 5]);
+    }
+
+    #[test]
+    fn test_builder_matches_hand_constructed_struct() {
+        // The fixture used by the evm script: n = 5, window size 3.
+        let timestamps: Vec<u64> = (0..5).map(|i| i * 86400).collect();
+        let values: Vec<f64> = (0..5).map(|i| i as f64 * 1.5).collect();
+        let ts = TimeSeries::new(timestamps, values);
+        let values_hash = ts.compute_hash();
+        let ma = ts.moving_average(3);
+
+        let hand_constructed = MovingAveragePublicValuesStruct {
+            start_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(0u64),
+            end_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(4u64 * 86400),
+            values_hash: alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(values_hash),
+            window_size: alloy_sol_types::private::Uint::<256, 4>::from(3usize),
+            moving_averages: vec_f64_to_u256(&ma.values),
+        };
+        let built = MovingAveragePublicValues::builder()
+            .start_timestamp(0)
+            .end_timestamp(4 * 86400)
+            .values_hash(values_hash)
+            .window(3)
+            .moving_averages(&ma.values)
+            .build()
+            .unwrap();
+
+        assert_eq!(built.abi_encode(), hand_constructed.abi_encode());
+        assert_eq!(
+            ts.to_moving_average_public_values(3).unwrap().abi_encode(),
+            hand_constructed.abi_encode()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "window is required")]
+    fn test_builder_rejects_missing_field() {
+        let _ = MovingAveragePublicValues::builder()
+            .start_timestamp(0)
+            .end_timestamp(1)
+            .values_hash([0; 32])
+            .moving_averages(&[1.0])
+            .build();
+    }
+
+    #[test]
+    fn test_builder_rejects_non_finite_average() {
+        let built = MovingAveragePublicValues::builder()
+            .start_timestamp(0)
+            .end_timestamp(1)
+            .values_hash([0; 32])
+            .window(2)
+            .moving_averages(&[1.0, f64::NAN])
+            .build();
+        assert_eq!(
+            built.err(),
+            Some(TimeSeriesError::NonFiniteValue { index: 1 })
+        );
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{NonNegativityPublicValuesStruct, TimeSeries, TimeSeriesError};
+
+impl TimeSeries {
+    /// Checks that every value is finite.
+    ///
+    /// NaN and infinite values have no fixed-point representation: [`f64_to_u256`] would commit
+    /// a meaningless integer for them, and statistics such as [`TimeSeries::median`] return NaN
+    /// rather than panicking, so the corruption would go unnoticed. Proof programs call this
+    /// first so the guest fails cleanly instead.
+    ///
+    /// [`f64_to_u256`]: crate::f64_to_u256
+    ///
+    /// # Errors
+    /// Returns [`TimeSeriesError::NonFiniteValue`] naming the first NaN or infinite value.
+    pub fn validate_finite(&self) -> Result<(), TimeSeriesError> {
+        match self.values.iter().position(|v| !v.is_finite()) {
+            Some(index) => Err(TimeSeriesError::NonFiniteValue { index }),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns whether every value is at least zero.
+    ///
+    /// An empty series is trivially non-negative, while a NaN value is not.
+    pub fn all_non_negative(&self) -> bool {
+        self.values.iter().all(|&v| v >= 0.0)
+    }
+
+    pub fn to_non_negativity_public_values(&self) -> NonNegativityPublicValuesStruct {
+        let start_timestamp = *self.timestamps.first().unwrap_or(&0);
+        let end_timestamp = *self.timestamps.last().unwrap_or(&0);
+        let values_hash = self.compute_hash();
+
+        NonNegativityPublicValuesStruct {
+            start_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(start_timestamp),
+            end_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(end_timestamp),
+            values_hash: alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(values_hash),
+            n: alloy_sol_types::private::Uint::<256, 4>::from(self.values.len()),
+            all_non_negative: self.all_non_negative(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_finite() {
+        let ts = TimeSeries::new(vec![1, 2, 3], vec![1.0, -2.0, 3.0]);
+        assert_eq!(ts.validate_finite(), Ok(()));
+
+        let nan = TimeSeries::new(vec![1, 2, 3], vec![1.0, f64::NAN, 3.0]);
+        assert_eq!(
+            nan.validate_finite(),
+            Err(TimeSeriesError::NonFiniteValue { index: 1 })
+        );
+        assert_eq!(
+            nan.to_public_values().err(),
+            Some(TimeSeriesError::NonFiniteValue { index: 1 })
+        );
+
+        let infinite = TimeSeries::new(vec![1, 2, 3], vec![1.0, 2.0, f64::INFINITY]);
+        assert_eq!(
+            infinite.to_moving_average_public_values(2).err(),
+            Some(TimeSeriesError::NonFiniteValue { index: 2 })
+        );
+    }
+
+    #[test]
+    fn test_all_non_negative() {
+        let positive = TimeSeries::new(vec![1, 2, 3], vec![5.0, 0.0, 2.5]);
+        assert!(positive.all_non_negative());
+        assert!(positive.to_non_negativity_public_values().all_non_negative);
+
+        let dipped = TimeSeries::new(vec![1, 2, 3], vec![5.0, -0.01, 2.5]);
+        assert!(!dipped.all_non_negative());
+        assert!(!dipped.to_non_negativity_public_values().all_non_negative);
+    }
+
+    #[test]
+    fn test_all_non_negative_edge_cases() {
+        assert!(TimeSeries::new(vec![], vec![]).all_non_negative());
+        assert!(!TimeSeries::new(vec![1], vec![f64::NAN]).all_non_negative());
+    }
+}
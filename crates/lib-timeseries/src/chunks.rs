@@ -0,0 +1,138 @@
+use crate::prelude::*;
+use crate::TimeSeries;
+
+impl TimeSeries {
+    /// Splits the series into consecutive chunks of `chunk_len` points, each sharing its first
+    /// `overlap` points with the end of the previous chunk.
+    ///
+    /// Chunk `k` starts at index `k * (chunk_len - overlap)`. The last chunk ends at the end of
+    /// the series and may be shorter than `chunk_len`. For per-chunk moving averages to match
+    /// the full series, use an overlap of `window - 1` and reassemble with
+    /// [`stitch_moving_averages`].
+    ///
+    /// # Panics
+    /// Panics if `chunk_len` is zero or `overlap >= chunk_len`.
+    pub fn chunks(&self, chunk_len: usize, overlap: usize) -> Vec<TimeSeries> {
+        assert!(chunk_len > 0, "Chunk length must be positive");
+        assert!(
+            overlap < chunk_len,
+            "Overlap must be less than the chunk length"
+        );
+        let n = self.values.len();
+        let mut chunks = Vec::new();
+        let mut start = 0;
+        while start < n {
+            let end = (start + chunk_len).min(n);
+            chunks.push(TimeSeries::new(
+                self.timestamps[start..end].to_vec(),
+                self.values[start..end].to_vec(),
+            ));
+            if end == n {
+                break;
+            }
+            start += chunk_len - overlap;
+        }
+        chunks
+    }
+}
+
+/// Reassembles the moving average of a series from chunks produced by
+/// [`TimeSeries::chunks`] with an overlap of `window - 1`.
+///
+/// Each chunk's moving average starts with `window - 1` warm-up points whose windows are cut
+/// short at the chunk start. For every chunk after the first, those points repeat the end of
+/// the previous chunk, so they are dropped, and the remaining points see full windows.
+///
+/// ```
+/// use lib_timeseries::{stitch_moving_averages, TimeSeries};
+///
+/// let ts = TimeSeries::new((0..10).collect(), (0..10).map(|i| (i * i) as f64).collect());
+/// let chunks = ts.chunks(4, 2);
+/// let stitched = stitch_moving_averages(&chunks, 3);
+/// assert_eq!(stitched.values, ts.moving_average(3).values);
+/// ```
+///
+/// # Panics
+/// Panics if `window` is zero, or if a chunk does not begin with the last `window - 1`
+/// timestamps of the previous one.
+pub fn stitch_moving_averages(chunks: &[TimeSeries], window: usize) -> TimeSeries {
+    assert!(window > 0, "Window size must be positive");
+    let warm_up = window - 1;
+    let mut timestamps = Vec::new();
+    let mut values = Vec::new();
+    for (k, chunk) in chunks.iter().enumerate() {
+        let skip = if k == 0 {
+            0
+        } else {
+            let previous = &chunks[k - 1].timestamps;
+            assert!(
+                chunk.timestamps.len() > warm_up
+                    && previous.len() >= warm_up
+                    && chunk.timestamps[..warm_up] == previous[previous.len() - warm_up..],
+                "Chunks must overlap by window - 1 points"
+            );
+            warm_up
+        };
+        let ma = chunk.moving_average(window);
+        timestamps.extend_from_slice(&ma.timestamps[skip..]);
+        values.extend_from_slice(&ma.values[skip..]);
+    }
+    TimeSeries::new(timestamps, values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_series(n: u64) -> TimeSeries {
+        TimeSeries::new(
+            (0..n).map(|i| i * 60).collect(),
+            (0..n).map(|i| ((i * 7) % 11) as f64).collect(),
+        )
+    }
+
+    #[test]
+    fn test_chunks_with_overlap_and_partial_tail() {
+        let ts = sample_series(10);
+        let chunks = ts.chunks(4, 1);
+        let starts: Vec<u64> = chunks.iter().map(|c| c.timestamps[0] / 60).collect();
+        let lens: Vec<usize> = chunks.iter().map(|c| c.values.len()).collect();
+        assert_eq!(starts, vec![0, 3, 6]);
+        assert_eq!(lens, vec![4, 4, 4]);
+
+        let chunks = ts.chunks(4, 0);
+        let lens: Vec<usize> = chunks.iter().map(|c| c.values.len()).collect();
+        assert_eq!(lens, vec![4, 4, 2]);
+        assert_eq!(chunks[2].values, ts.values[8..]);
+
+        assert_eq!(ts.chunks(20, 5).len(), 1);
+        assert!(TimeSeries::new(vec![], vec![]).chunks(4, 1).is_empty());
+    }
+
+    #[test]
+    fn test_stitched_moving_average_matches_single_pass() {
+        let ts = sample_series(103);
+        for window in 1..6 {
+            for chunk_len in window..20 {
+                let chunks = ts.chunks(chunk_len, window - 1);
+                let stitched = stitch_moving_averages(&chunks, window);
+                let single = ts.moving_average(window);
+                assert_eq!(stitched.timestamps, single.timestamps);
+                assert_eq!(stitched.values, single.values);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Overlap must be less than the chunk length")]
+    fn test_chunks_rejects_full_overlap() {
+        sample_series(5).chunks(3, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "Chunks must overlap by window - 1 points")]
+    fn test_stitch_rejects_wrong_overlap() {
+        let chunks = sample_series(10).chunks(4, 0);
+        stitch_moving_averages(&chunks, 3);
+    }
+}
@@ -0,0 +1,222 @@
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+use crate::{TimeSeries, TimeSeriesError};
+
+/// Which value [`TimeSeries::merge`] keeps when both series have a point at the same timestamp.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum ConflictPolicy {
+    /// Keep the value from `self`.
+    PreferSelf,
+    /// Keep the value from `other`.
+    PreferOther,
+    /// Keep the mean of both values.
+    Mean,
+}
+
+impl TimeSeries {
+    /// Appends `other` to the end of the series.
+    ///
+    /// # Errors
+    /// Returns [`TimeSeriesError::NonMonotonicTimestamp`], with the index the first point of
+    /// `other` would have had, unless that point is strictly after the last point of `self`.
+    /// Either series may be empty.
+    pub fn concat(&self, other: &TimeSeries) -> Result<TimeSeries, TimeSeriesError> {
+        if let (Some(&last), Some(&first)) = (self.timestamps.last(), other.timestamps.first()) {
+            if first <= last {
+                return Err(TimeSeriesError::NonMonotonicTimestamp {
+                    index: self.timestamps.len(),
+                });
+            }
+        }
+        let mut concatenated = self.clone();
+        concatenated.timestamps.extend_from_slice(&other.timestamps);
+        concatenated.values.extend_from_slice(&other.values);
+        Ok(concatenated)
+    }
+
+    /// Appends the series in order into one, without checking their timestamps.
+    ///
+    /// Use [`TimeSeries::concat`] to reject chunks that overlap. The values hash commits to
+    /// points in stored order, so the result hashes like a series that held all the points in
+    /// this order from the start; the order of `series` matters, and the hash of the whole
+    /// cannot be derived from the hashes of the parts.
+    pub fn concat_all(series: &[TimeSeries]) -> TimeSeries {
+        let len = series.iter().map(|s| s.values.len()).sum();
+        let mut timestamps = Vec::with_capacity(len);
+        let mut values = Vec::with_capacity(len);
+        for s in series {
+            timestamps.extend_from_slice(&s.timestamps);
+            values.extend_from_slice(&s.values);
+        }
+        TimeSeries::new(timestamps, values)
+    }
+
+    /// Interleaves two sorted series by timestamp in O(n + m), keeping every point.
+    ///
+    /// Points sharing a timestamp are all kept, those from `a` first and each series' own
+    /// points in their stored order, so the merge is stable. Use [`TimeSeries::merge`] to
+    /// collapse shared timestamps instead. Both series must be sorted by timestamp.
+    pub fn merge_sorted(a: &TimeSeries, b: &TimeSeries) -> TimeSeries {
+        debug_assert!(
+            a.is_monotonic() && b.is_monotonic(),
+            "Timestamps must be sorted"
+        );
+        let capacity = a.values.len() + b.values.len();
+        let mut timestamps = Vec::with_capacity(capacity);
+        let mut values = Vec::with_capacity(capacity);
+        let (mut i, mut j) = (0, 0);
+        while i < a.values.len() || j < b.values.len() {
+            let take_a = match (a.timestamps.get(i), b.timestamps.get(j)) {
+                (Some(t), Some(u)) => t <= u,
+                (Some(_), None) => true,
+                _ => false,
+            };
+            if take_a {
+                timestamps.push(a.timestamps[i]);
+                values.push(a.values[i]);
+                i += 1;
+            } else {
+                timestamps.push(b.timestamps[j]);
+                values.push(b.values[j]);
+                j += 1;
+            }
+        }
+        TimeSeries::new(timestamps, values)
+    }
+
+    /// Interleaves two series by timestamp, resolving shared timestamps with `conflict`.
+    ///
+    /// Each series is first sorted by timestamp with only the last of its own points at each
+    /// timestamp kept, as in [`TimeSeries::interpolate_linear`]; already sorted series skip the
+    /// sort, so the merge itself takes O(n + m) time.
+    pub fn merge(&self, other: &TimeSeries, conflict: ConflictPolicy) -> TimeSeries {
+        let left = self.deduplicated_by_time();
+        let right = other.deduplicated_by_time();
+        let capacity = left.values.len() + right.values.len();
+        let mut timestamps = Vec::with_capacity(capacity);
+        let mut values = Vec::with_capacity(capacity);
+        let (mut i, mut j) = (0, 0);
+        while i < left.values.len() || j < right.values.len() {
+            let (t, v) = match (left.timestamps.get(i), right.timestamps.get(j)) {
+                (Some(&t), Some(&u)) if t == u => {
+                    let (a, b) = (left.values[i], right.values[j]);
+                    i += 1;
+                    j += 1;
+                    let value = match conflict {
+                        ConflictPolicy::PreferSelf => a,
+                        ConflictPolicy::PreferOther => b,
+                        ConflictPolicy::Mean => (a + b) / 2.0,
+                    };
+                    (t, value)
+                }
+                (Some(&t), Some(&u)) if t < u => {
+                    i += 1;
+                    (t, left.values[i - 1])
+                }
+                (Some(&t), None) => {
+                    i += 1;
+                    (t, left.values[i - 1])
+                }
+                (_, Some(&u)) => {
+                    j += 1;
+                    (u, right.values[j - 1])
+                }
+                (None, None) => unreachable!(),
+            };
+            timestamps.push(t);
+            values.push(v);
+        }
+        TimeSeries::new(timestamps, values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_concat() {
+        let monday = TimeSeries::new(vec![0, 3600], vec![1.0, 2.0]);
+        let tuesday = TimeSeries::new(vec![86400, 90000], vec![3.0, 4.0]);
+        let both = monday.concat(&tuesday).unwrap();
+        assert_eq!(both.timestamps, vec![0, 3600, 86400, 90000]);
+        assert_eq!(both.values, vec![1.0, 2.0, 3.0, 4.0]);
+
+        let empty = TimeSeries::new(vec![], vec![]);
+        assert_eq!(empty.concat(&monday).unwrap().values, monday.values);
+        assert_eq!(monday.concat(&empty).unwrap().values, monday.values);
+    }
+
+    #[test]
+    fn test_concat_rejects_overlap() {
+        let first = TimeSeries::new(vec![0, 10], vec![1.0, 2.0]);
+        let overlapping = TimeSeries::new(vec![10, 20], vec![3.0, 4.0]);
+        assert_eq!(
+            first.concat(&overlapping).err(),
+            Some(TimeSeriesError::NonMonotonicTimestamp { index: 2 })
+        );
+    }
+
+    #[test]
+    fn test_concat_all() {
+        let parts = [
+            TimeSeries::new(vec![0, 1], vec![1.0, 2.0]),
+            TimeSeries::new(vec![], vec![]),
+            TimeSeries::new(vec![2, 3, 4], vec![3.0, 4.0, 5.0]),
+        ];
+        let whole = TimeSeries::concat_all(&parts);
+        assert_eq!(whole.timestamps, vec![0, 1, 2, 3, 4]);
+        assert_eq!(whole.values, vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+        let direct = TimeSeries::new(vec![0, 1, 2, 3, 4], vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+        assert_eq!(whole.compute_hash(), direct.compute_hash());
+        assert!(TimeSeries::concat_all(&[]).values.is_empty());
+    }
+
+    #[test]
+    fn test_merge_sorted_alternating() {
+        let even = TimeSeries::new(vec![0, 2, 4], vec![0.0, 2.0, 4.0]);
+        let odd = TimeSeries::new(vec![1, 3, 5, 7], vec![1.0, 3.0, 5.0, 7.0]);
+        let merged = TimeSeries::merge_sorted(&even, &odd);
+        assert_eq!(merged.timestamps, vec![0, 1, 2, 3, 4, 5, 7]);
+        assert_eq!(merged.values, vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 7.0]);
+
+        let duplicates = TimeSeries::merge_sorted(
+            &TimeSeries::new(vec![1, 2], vec![10.0, 20.0]),
+            &TimeSeries::new(vec![2, 3], vec![-20.0, 30.0]),
+        );
+        assert_eq!(duplicates.timestamps, vec![1, 2, 2, 3]);
+        assert_eq!(duplicates.values, vec![10.0, 20.0, -20.0, 30.0]);
+    }
+
+    #[test]
+    fn test_merge_overlapping_ranges() {
+        let a = TimeSeries::new(vec![0, 20, 40], vec![1.0, 3.0, 5.0]);
+        let b = TimeSeries::new(vec![30, 10, 50], vec![4.0, 2.0, 6.0]);
+        let merged = a.merge(&b, ConflictPolicy::PreferSelf);
+        assert_eq!(merged.timestamps, vec![0, 10, 20, 30, 40, 50]);
+        assert_eq!(merged.values, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    }
+
+    #[test]
+    fn test_merge_duplicate_timestamps() {
+        let a = TimeSeries::new(vec![0, 10, 20], vec![1.0, 2.0, 3.0]);
+        let b = TimeSeries::new(vec![10, 20, 30], vec![4.0, 6.0, 8.0]);
+        let merge = |conflict| a.merge(&b, conflict);
+        assert_eq!(
+            merge(ConflictPolicy::PreferSelf).timestamps,
+            vec![0, 10, 20, 30]
+        );
+        assert_eq!(
+            merge(ConflictPolicy::PreferSelf).values,
+            vec![1.0, 2.0, 3.0, 8.0]
+        );
+        assert_eq!(
+            merge(ConflictPolicy::PreferOther).values,
+            vec![1.0, 4.0, 6.0, 8.0]
+        );
+        assert_eq!(merge(ConflictPolicy::Mean).values, vec![1.0, 3.0, 4.5, 8.0]);
+    }
+}
@@ -0,0 +1,177 @@
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+use crate::{f64_to_i256, CompliancePublicValuesStruct, TimeSeries};
+
+/// A condition that a window of values must satisfy to be compliant.
+///
+/// All comparisons are strict, e.g. a window whose mean equals the limit of
+/// [`Predicate::MeanBelow`] is not compliant.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum Predicate {
+    /// The window mean is below the limit.
+    MeanBelow(f64),
+    /// The window mean is above the limit.
+    MeanAbove(f64),
+    /// Every value in the window is below the limit.
+    MaxBelow(f64),
+    /// Every value in the window is above the limit.
+    MinAbove(f64),
+}
+
+impl Predicate {
+    /// Returns the identifier committed for this predicate.
+    pub fn id(&self) -> u8 {
+        match self {
+            Predicate::MeanBelow(_) => 0,
+            Predicate::MeanAbove(_) => 1,
+            Predicate::MaxBelow(_) => 2,
+            Predicate::MinAbove(_) => 3,
+        }
+    }
+
+    /// Returns the limit the predicate compares against.
+    pub fn limit(&self) -> f64 {
+        match *self {
+            Predicate::MeanBelow(limit)
+            | Predicate::MeanAbove(limit)
+            | Predicate::MaxBelow(limit)
+            | Predicate::MinAbove(limit) => limit,
+        }
+    }
+
+    /// Evaluates the predicate over a window of values.
+    pub fn evaluate(&self, window: &[f64]) -> bool {
+        match *self {
+            Predicate::MeanBelow(limit) => {
+                window.iter().sum::<f64>() / (window.len() as f64) < limit
+            }
+            Predicate::MeanAbove(limit) => {
+                window.iter().sum::<f64>() / (window.len() as f64) > limit
+            }
+            Predicate::MaxBelow(limit) => window.iter().all(|&v| v < limit),
+            Predicate::MinAbove(limit) => window.iter().all(|&v| v > limit),
+        }
+    }
+}
+
+impl TimeSeries {
+    /// Evaluates a predicate over every rolling window of the time series.
+    ///
+    /// Windows follow the same convention as [`TimeSeries::moving_average`]: the window ending at
+    /// index `i` covers the last `window_size` values, shrinking at the start of the series, so the
+    /// output has one entry per point.
+    ///
+    /// # Arguments
+    /// * `window_size` - The size of the rolling window
+    /// * `predicate` - The condition each window must satisfy
+    ///
+    /// # Panics
+    /// Panics if `window_size` is zero.
+    pub fn rolling_compliance(&self, window_size: usize, predicate: Predicate) -> Vec<bool> {
+        assert!(window_size > 0, "Window size must be positive");
+        (0..self.values.len())
+            .map(|i| {
+                let start = (i + 1).saturating_sub(window_size);
+                predicate.evaluate(&self.values[start..=i])
+            })
+            .collect()
+    }
+
+    /// Builds the public values of the rolling compliance program, committing the
+    /// [`TimeSeries::rolling_compliance`] bits packed with [`pack_bits`].
+    ///
+    /// # Panics
+    /// Panics if `window_size` is zero.
+    pub fn to_compliance_public_values(
+        &self,
+        window_size: usize,
+        predicate: Predicate,
+    ) -> CompliancePublicValuesStruct {
+        let start_timestamp = *self.timestamps.first().unwrap_or(&0);
+        let end_timestamp = *self.timestamps.last().unwrap_or(&0);
+        let values_hash = self.compute_hash();
+        let compliance = self.rolling_compliance(window_size, predicate);
+
+        CompliancePublicValuesStruct {
+            start_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(start_timestamp),
+            end_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(end_timestamp),
+            values_hash: alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(values_hash),
+            window_size: alloy_sol_types::private::Uint::<256, 4>::from(window_size),
+            predicate: predicate.id(),
+            limit: f64_to_i256(predicate.limit()),
+            n: alloy_sol_types::private::Uint::<256, 4>::from(compliance.len()),
+            compliance_bitmap: pack_bits(&compliance),
+        }
+    }
+}
+
+/// Packs a slice of booleans into 256-bit words for Solidity compatibility.
+///
+/// Bit `i` is stored in word `i / 256` at bit position `i % 256` (least significant bit first),
+/// so a verifier can check it with `(bitmap[i / 256] >> (i % 256)) & 1`.
+pub fn pack_bits(bits: &[bool]) -> Vec<alloy_sol_types::private::Uint<256, 4>> {
+    bits.chunks(256)
+        .map(|chunk| {
+            let mut word = alloy_sol_types::private::Uint::<256, 4>::ZERO;
+            for (i, &bit) in chunk.iter().enumerate() {
+                word.set_bit(i, bit);
+            }
+            word
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rolling_compliance_with_failing_windows() {
+        let ts = TimeSeries::new(vec![1, 2, 3, 4, 5, 6], vec![1.0, 2.0, 9.0, 9.0, 1.0, 1.0]);
+        let compliance = ts.rolling_compliance(2, Predicate::MeanBelow(6.0));
+        assert_eq!(compliance, vec![true, true, true, false, true, true]);
+
+        let compliance = ts.rolling_compliance(2, Predicate::MaxBelow(5.0));
+        assert_eq!(compliance, vec![true, true, false, false, false, true]);
+    }
+
+    #[test]
+    fn test_pack_bits() {
+        let mut bits = vec![false; 300];
+        bits[0] = true;
+        bits[3] = true;
+        bits[256] = true;
+        let packed = pack_bits(&bits);
+        assert_eq!(packed.len(), 2);
+        assert_eq!(
+            packed[0],
+            alloy_sol_types::private::Uint::<256, 4>::from(9u64)
+        );
+        assert_eq!(
+            packed[1],
+            alloy_sol_types::private::Uint::<256, 4>::from(1u64)
+        );
+    }
+
+    #[test]
+    fn test_compliance_public_values_bitmap() {
+        let ts = TimeSeries::new(vec![1, 2, 3, 4], vec![1.0, 9.0, 1.0, 1.0]);
+        let public_values = ts.to_compliance_public_values(1, Predicate::MaxBelow(5.0));
+        let bitmap = public_values.compliance_bitmap[0];
+        assert!(bitmap.bit(0));
+        assert!(!bitmap.bit(1));
+        assert!(bitmap.bit(2));
+        assert!(bitmap.bit(3));
+        assert_eq!(public_values.predicate, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Window size must be positive")]
+    fn test_rolling_compliance_rejects_zero_window() {
+        let ts = TimeSeries::new(vec![1, 2], vec![1.0, 2.0]);
+        ts.rolling_compliance(0, Predicate::MeanBelow(5.0));
+    }
+}
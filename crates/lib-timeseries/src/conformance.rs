@@ -0,0 +1,423 @@
+//! A conformance kit for third-party implementations of the committed values.
+//!
+//! Each dataset is written as `<name>.json` holding the input points and everything this crate
+//! commits for them: the statistics as exact f64 bits and in their 1e18 fixed-point form, the
+//! values hash under every hashing scheme, and the full ABI-encoded public values. A Go or
+//! Solidity implementation conforms if it reproduces every expected field from the inputs.
+//!
+//! The fixed-point forms follow [`f64_to_u256`], which commits the magnitude and drops the sign:
+//! the `negatives` case's mean of about -30.35 is expected as `30350000000199999488`, the same as
+//! a mean of +30.35 would be. The sign is only recoverable from the f64 bits.
+//!
+//! Files carry [`SEMANTICS_VERSION`]. Bump it whenever a change alters any committed value, and
+//! regenerate the frozen kit under `conformance/` in this crate.
+
+use core::fmt;
+use std::path::Path;
+
+use alloy_sol_types::SolValue;
+use serde::{Deserialize, Serialize};
+
+use crate::{f64_to_u256, TimeSeries};
+
+/// The version of the committed semantics the kit describes.
+pub const SEMANTICS_VERSION: u32 = 2;
+
+/// Errors returned when writing or checking a conformance kit.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConformanceError {
+    /// A file could not be read or written.
+    Io(String),
+    /// A file is not a valid conformance case.
+    Parse { file: String, message: String },
+    /// A case was generated for a different [`SEMANTICS_VERSION`].
+    VersionMismatch { file: String, version: u32 },
+    /// A case's expected value differs from the current implementation.
+    Mismatch {
+        file: String,
+        field: &'static str,
+        expected: String,
+        actual: String,
+    },
+    /// The directory holds no conformance cases.
+    Empty,
+}
+
+impl fmt::Display for ConformanceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConformanceError::Io(e) => write!(f, "i/o error: {e}"),
+            ConformanceError::Parse { file, message } => {
+                write!(f, "invalid conformance case {file}: {message}")
+            }
+            ConformanceError::VersionMismatch { file, version } => write!(
+                f,
+                "{file} has semantics version {version}, expected {SEMANTICS_VERSION}"
+            ),
+            ConformanceError::Mismatch {
+                file,
+                field,
+                expected,
+                actual,
+            } => write!(f, "{file}: {field} is {actual}, expected {expected}"),
+            ConformanceError::Empty => write!(f, "no conformance cases found"),
+        }
+    }
+}
+
+impl std::error::Error for ConformanceError {}
+
+/// A statistic in both of its committed forms.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ExpectedNumber {
+    /// The f64 bit pattern as 0x-prefixed hex.
+    pub f64_bits: String,
+    /// The 1e18 fixed-point `uint256` of the value's magnitude, in decimal; see [`f64_to_u256`].
+    pub scaled: String,
+}
+
+impl ExpectedNumber {
+    fn new(value: f64) -> Self {
+        ExpectedNumber {
+            f64_bits: format!("0x{:016x}", value.to_bits()),
+            scaled: f64_to_u256(value).to_string(),
+        }
+    }
+}
+
+/// The values hash under every supported scheme, as 0x-prefixed hex.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ExpectedHashes {
+    /// Keccak256 of [`TimeSeries::hash_preimage`]; the hash the programs commit.
+    pub keccak256: String,
+    /// SHA-256 of [`TimeSeries::hash_preimage`].
+    pub sha256: String,
+    /// [`TimeSeries::canonical_hash`].
+    pub canonical_keccak256: String,
+}
+
+/// The ABI-encoded public values, as 0x-prefixed hex.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ExpectedPublicValues {
+    /// [`TimeSeries::to_public_values`].
+    pub statistics: String,
+    /// [`TimeSeries::to_dual_hash_public_values`].
+    pub dual_hash: String,
+}
+
+/// A dataset and every value this crate commits for it.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ConformanceCase {
+    pub semantics_version: u32,
+    pub name: String,
+    pub description: String,
+    pub timestamps: Vec<u64>,
+    /// The values as f64 bit patterns in 0x-prefixed hex, so every bit survives the JSON.
+    pub value_bits: Vec<String>,
+    pub mean: ExpectedNumber,
+    pub median: ExpectedNumber,
+    pub std_dev: ExpectedNumber,
+    pub values_hash: ExpectedHashes,
+    pub public_values: ExpectedPublicValues,
+}
+
+impl ConformanceCase {
+    /// Computes the expected values for a series with the current implementation.
+    ///
+    /// Every value must be finite, since the canonical hash is undefined otherwise.
+    pub fn generate(name: &str, description: &str, series: &TimeSeries) -> Self {
+        let (keccak256, sha256) = series.dual_hash();
+        let canonical = series
+            .canonical_hash()
+            .expect("conformance datasets are finite");
+        ConformanceCase {
+            semantics_version: SEMANTICS_VERSION,
+            name: name.to_string(),
+            description: description.to_string(),
+            timestamps: series.timestamps.clone(),
+            value_bits: series
+                .values
+                .iter()
+                .map(|v| format!("0x{:016x}", v.to_bits()))
+                .collect(),
+            mean: ExpectedNumber::new(series.mean()),
+            median: ExpectedNumber::new(series.median()),
+            std_dev: ExpectedNumber::new(series.std_dev()),
+            values_hash: ExpectedHashes {
+                keccak256: to_hex(&keccak256),
+                sha256: to_hex(&sha256),
+                canonical_keccak256: to_hex(&canonical),
+            },
+            public_values: ExpectedPublicValues {
+                statistics: to_hex(
+                    &series
+                        .to_public_values()
+                        .expect("conformance datasets are finite")
+                        .abi_encode(),
+                ),
+                dual_hash: to_hex(&series.to_dual_hash_public_values().abi_encode()),
+            },
+        }
+    }
+
+    /// Rebuilds the input series from the timestamps and value bits.
+    pub fn series(&self) -> Result<TimeSeries, String> {
+        if self.timestamps.len() != self.value_bits.len() {
+            return Err("timestamps and value_bits differ in length".to_string());
+        }
+        let values = self
+            .value_bits
+            .iter()
+            .map(|bits| {
+                u64::from_str_radix(bits.trim_start_matches("0x"), 16)
+                    .map(f64::from_bits)
+                    .map_err(|e| format!("invalid value bits `{bits}`: {e}"))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(TimeSeries::new(self.timestamps.clone(), values))
+    }
+
+    /// Returns the first field whose value differs from `other`, as `(field, ours, theirs)`.
+    fn first_difference(&self, other: &Self) -> Option<(&'static str, String, String)> {
+        let fields = vec![
+            (
+                "mean.f64_bits",
+                self.mean.f64_bits.clone(),
+                other.mean.f64_bits.clone(),
+            ),
+            (
+                "mean.scaled",
+                self.mean.scaled.clone(),
+                other.mean.scaled.clone(),
+            ),
+            (
+                "median.f64_bits",
+                self.median.f64_bits.clone(),
+                other.median.f64_bits.clone(),
+            ),
+            (
+                "median.scaled",
+                self.median.scaled.clone(),
+                other.median.scaled.clone(),
+            ),
+            (
+                "std_dev.f64_bits",
+                self.std_dev.f64_bits.clone(),
+                other.std_dev.f64_bits.clone(),
+            ),
+            (
+                "std_dev.scaled",
+                self.std_dev.scaled.clone(),
+                other.std_dev.scaled.clone(),
+            ),
+            (
+                "values_hash.keccak256",
+                self.values_hash.keccak256.clone(),
+                other.values_hash.keccak256.clone(),
+            ),
+            (
+                "values_hash.sha256",
+                self.values_hash.sha256.clone(),
+                other.values_hash.sha256.clone(),
+            ),
+            (
+                "values_hash.canonical_keccak256",
+                self.values_hash.canonical_keccak256.clone(),
+                other.values_hash.canonical_keccak256.clone(),
+            ),
+            (
+                "public_values.statistics",
+                self.public_values.statistics.clone(),
+                other.public_values.statistics.clone(),
+            ),
+            (
+                "public_values.dual_hash",
+                self.public_values.dual_hash.clone(),
+                other.public_values.dual_hash.clone(),
+            ),
+        ];
+        fields.into_iter().find(|(_, ours, theirs)| ours != theirs)
+    }
+}
+
+/// Returns the conformance datasets as `(name, description, series)`.
+pub fn datasets() -> Vec<(&'static str, &'static str, TimeSeries)> {
+    const DAY: u64 = 86_400;
+    const START: u64 = 1_700_000_000;
+    vec![
+        (
+            "tiny",
+            "Three evenly spaced points",
+            TimeSeries::new(vec![0, DAY, 2 * DAY], vec![0.0, 1.5, 3.0]),
+        ),
+        (
+            "typical",
+            "Two days of hourly readings with a daily cycle and a trend",
+            TimeSeries::new(
+                (0..48).map(|i| START + i * 3600).collect(),
+                (0..48)
+                    .map(|i| 100.0 + 0.25 * i as f64 + [0.0, 4.0, 6.5, 4.0][i % 24 / 6])
+                    .collect(),
+            ),
+        ),
+        (
+            "negatives",
+            "Negative values and a negative zero; the scaled forms are magnitudes",
+            TimeSeries::new(
+                vec![START, START + 60, START + 120, START + 180, START + 240],
+                vec![-3.25, -0.0, -1e-9, -150.5, 2.0],
+            ),
+        ),
+        (
+            "near_overflow",
+            "Values whose 1e18 fixed-point form approaches the u128 limit",
+            TimeSeries::new(
+                vec![START, START + 1, START + 2],
+                vec![1.0e20, 3.0e20, 2.5e20],
+            ),
+        ),
+        (
+            "duplicates",
+            "Repeated timestamps and repeated values",
+            TimeSeries::new(
+                vec![START, START, START + 10, START + 10, START + 20],
+                vec![5.0, 5.0, 7.0, 5.0, 7.0],
+            ),
+        ),
+        (
+            "irregular",
+            "Irregularly spaced timestamps with fractional values",
+            TimeSeries::new(
+                vec![START, START + 1, START + 7, START + 300, START + 86_399],
+                vec![0.1, 0.2, 0.3, 1.0 / 3.0, 2.0f64.sqrt()],
+            ),
+        ),
+    ]
+}
+
+/// Writes every dataset in [`datasets`] to `dir` as `<name>.json`, creating `dir` if needed.
+pub fn write_kit(dir: &Path) -> Result<(), ConformanceError> {
+    std::fs::create_dir_all(dir).map_err(|e| ConformanceError::Io(e.to_string()))?;
+    for (name, description, series) in datasets() {
+        let case = ConformanceCase::generate(name, description, &series);
+        let json =
+            serde_json::to_string_pretty(&case).map_err(|e| ConformanceError::Io(e.to_string()))?;
+        std::fs::write(dir.join(format!("{name}.json")), json + "\n")
+            .map_err(|e| ConformanceError::Io(e.to_string()))?;
+    }
+    Ok(())
+}
+
+/// Checks every case in `dir` against the current implementation.
+///
+/// # Returns
+/// The number of cases checked.
+pub fn verify_kit(dir: &Path) -> Result<usize, ConformanceError> {
+    let mut files: Vec<_> = std::fs::read_dir(dir)
+        .map_err(|e| ConformanceError::Io(e.to_string()))?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<_, _>>()
+        .map_err(|e| ConformanceError::Io(e.to_string()))?;
+    files.retain(|path| path.extension().is_some_and(|ext| ext == "json"));
+    files.sort();
+    if files.is_empty() {
+        return Err(ConformanceError::Empty);
+    }
+
+    for path in &files {
+        let file = path.display().to_string();
+        let json =
+            std::fs::read_to_string(path).map_err(|e| ConformanceError::Io(e.to_string()))?;
+        let case: ConformanceCase =
+            serde_json::from_str(&json).map_err(|e| ConformanceError::Parse {
+                file: file.clone(),
+                message: e.to_string(),
+            })?;
+        if case.semantics_version != SEMANTICS_VERSION {
+            return Err(ConformanceError::VersionMismatch {
+                file,
+                version: case.semantics_version,
+            });
+        }
+        let series = case.series().map_err(|message| ConformanceError::Parse {
+            file: file.clone(),
+            message,
+        })?;
+        let actual = ConformanceCase::generate(&case.name, &case.description, &series);
+        if let Some((field, expected, actual)) = case.first_difference(&actual) {
+            return Err(ConformanceError::Mismatch {
+                file,
+                field,
+                expected,
+                actual,
+            });
+        }
+    }
+    Ok(files.len())
+}
+
+/// Encodes bytes as 0x-prefixed lowercase hex.
+fn to_hex(bytes: &[u8]) -> String {
+    let mut hex = String::with_capacity(2 + bytes.len() * 2);
+    hex.push_str("0x");
+    for byte in bytes {
+        hex.push_str(&format!("{byte:02x}"));
+    }
+    hex
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The frozen kit checked in with the crate. Set `UPDATE_CONFORMANCE=1` to regenerate it
+    /// after an intentional change, together with a [`SEMANTICS_VERSION`] bump.
+    fn frozen_dir() -> std::path::PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("conformance")
+    }
+
+    #[test]
+    fn test_fresh_kit_passes_self_test() {
+        let dir = std::env::temp_dir().join(format!("conformance-{}", std::process::id()));
+        write_kit(&dir).unwrap();
+        assert_eq!(verify_kit(&dir), Ok(datasets().len()));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_frozen_kit_matches_implementation() {
+        if std::env::var_os("UPDATE_CONFORMANCE").is_some() {
+            write_kit(&frozen_dir()).unwrap();
+        }
+        assert_eq!(verify_kit(&frozen_dir()), Ok(datasets().len()));
+    }
+
+    #[test]
+    fn test_scaled_form_drops_the_sign() {
+        let negative = ExpectedNumber::new(-30.35);
+        assert_eq!(negative.scaled, ExpectedNumber::new(30.35).scaled);
+        assert_eq!(negative.scaled, "30350000000000000000");
+        assert_ne!(negative.f64_bits, ExpectedNumber::new(30.35).f64_bits);
+    }
+
+    #[test]
+    fn test_self_test_reports_drift() {
+        let dir = std::env::temp_dir().join(format!("conformance-drift-{}", std::process::id()));
+        write_kit(&dir).unwrap();
+        let path = dir.join("tiny.json");
+        let mut case: ConformanceCase =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        case.median.scaled = "0".to_string();
+        std::fs::write(&path, serde_json::to_string(&case).unwrap()).unwrap();
+
+        let error = verify_kit(&dir).unwrap_err();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(matches!(
+            error,
+            ConformanceError::Mismatch {
+                field: "median.scaled",
+                ..
+            }
+        ));
+    }
+}
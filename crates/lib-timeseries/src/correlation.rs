@@ -0,0 +1,296 @@
+use crate::prelude::*;
+use crate::{f64_to_i256, CorrelationPublicValuesStruct, TimeSeries, TimeSeriesError};
+
+impl TimeSeries {
+    /// Computes the ranks of the time series values.
+    ///
+    /// Ranks start at 1 for the smallest value. Tied values all receive the average of the ranks
+    /// they span, e.g. `[10, 20, 20, 30]` ranks as `[1, 2.5, 2.5, 4]`.
+    pub fn rank(&self) -> Vec<f64> {
+        let mut order: Vec<usize> = (0..self.values.len()).collect();
+        order.sort_by(|&a, &b| self.values[a].total_cmp(&self.values[b]));
+
+        let mut ranks = vec![0.0; self.values.len()];
+        let mut start = 0;
+        while start < order.len() {
+            let mut end = start + 1;
+            while end < order.len() && self.values[order[end]] == self.values[order[start]] {
+                end += 1;
+            }
+            // Positions start..end hold ranks start+1..=end, whose average is:
+            let average_rank = (start + end + 1) as f64 / 2.0;
+            for &index in &order[start..end] {
+                ranks[index] = average_rank;
+            }
+            start = end;
+        }
+        ranks
+    }
+
+    /// Computes the Spearman rank correlation between this series and another.
+    ///
+    /// This is the Pearson correlation of the two rank vectors, so it measures how well the
+    /// relationship between the series can be described by a monotonic function.
+    ///
+    /// # Errors
+    /// Returns [`TimeSeriesError::LengthMismatch`] if the series differ in length,
+    /// [`TimeSeriesError::InsufficientData`] if they have fewer than two points, and
+    /// [`TimeSeriesError::ZeroVariance`] if either series is constant (the correlation is
+    /// undefined in that case, so no NaN is returned).
+    pub fn spearman_correlation(&self, other: &TimeSeries) -> Result<f64, TimeSeriesError> {
+        self.check_correlation_pair(other)?;
+        pearson(&self.rank(), &other.rank())
+    }
+
+    /// Computes the Pearson correlation between this series and another, pairing the values
+    /// point by point.
+    ///
+    /// # Errors
+    /// Returns the same errors as [`TimeSeries::spearman_correlation`].
+    pub fn pearson_correlation(&self, other: &TimeSeries) -> Result<f64, TimeSeriesError> {
+        self.check_correlation_pair(other)?;
+        pearson(&self.values, &other.values)
+    }
+
+    /// Builds the public values of the correlation program.
+    ///
+    /// Only the hashes of both series, the threshold and whether the Pearson correlation
+    /// exceeds it are committed, so a proof can show that one hidden series tracks another
+    /// without revealing either, or even the correlation itself.
+    ///
+    /// # Errors
+    /// Returns the errors of [`TimeSeries::pearson_correlation`].
+    pub fn to_correlation_public_values(
+        &self,
+        other: &TimeSeries,
+        threshold: f64,
+    ) -> Result<CorrelationPublicValuesStruct, TimeSeriesError> {
+        let correlation = self.pearson_correlation(other)?;
+
+        Ok(CorrelationPublicValuesStruct {
+            values_hash: alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(
+                self.compute_hash(),
+            ),
+            other_values_hash: alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(
+                other.compute_hash(),
+            ),
+            threshold: f64_to_i256(threshold),
+            above_threshold: correlation > threshold,
+        })
+    }
+
+    /// Computes the covariance between this series and another.
+    ///
+    /// # Arguments
+    /// * `other` - The series to pair with, point by point
+    /// * `ddof` - Delta degrees of freedom: the sum of products is divided by `n - ddof`, so use
+    ///   `0` for the population covariance and `1` for the sample covariance
+    ///
+    /// # Errors
+    /// Returns [`TimeSeriesError::LengthMismatch`] if the series differ in length and
+    /// [`TimeSeriesError::InsufficientData`] if they have no more than `ddof` points.
+    pub fn covariance(&self, other: &TimeSeries, ddof: usize) -> Result<f64, TimeSeriesError> {
+        if self.values.len() != other.values.len() {
+            return Err(TimeSeriesError::LengthMismatch {
+                expected: self.values.len(),
+                actual: other.values.len(),
+            });
+        }
+        if self.values.len() <= ddof {
+            return Err(TimeSeriesError::InsufficientData {
+                required: ddof + 1,
+                actual: self.values.len(),
+            });
+        }
+        let mean_x = self.mean();
+        let mean_y = other.mean();
+        let sum_of_products: f64 = self
+            .values
+            .iter()
+            .zip(other.values.iter())
+            .map(|(&x, &y)| (x - mean_x) * (y - mean_y))
+            .sum();
+        Ok(sum_of_products / (self.values.len() - ddof) as f64)
+    }
+
+    /// Computes the beta of this series against a benchmark series.
+    ///
+    /// Beta is the covariance with the benchmark divided by the variance of the benchmark, both
+    /// computed with the same `ddof` (see [`TimeSeries::covariance`]).
+    ///
+    /// # Errors
+    /// Returns the errors of [`TimeSeries::covariance`], and [`TimeSeriesError::ZeroVariance`] if
+    /// the benchmark is constant.
+    pub fn beta(&self, benchmark: &TimeSeries, ddof: usize) -> Result<f64, TimeSeriesError> {
+        let covariance = self.covariance(benchmark, ddof)?;
+        let benchmark_variance = benchmark.covariance(benchmark, ddof)?;
+        if benchmark_variance == 0.0 {
+            return Err(TimeSeriesError::ZeroVariance);
+        }
+        Ok(covariance / benchmark_variance)
+    }
+
+    /// Checks that two series can be correlated: equal lengths and at least two points.
+    fn check_correlation_pair(&self, other: &TimeSeries) -> Result<(), TimeSeriesError> {
+        if self.values.len() != other.values.len() {
+            return Err(TimeSeriesError::LengthMismatch {
+                expected: self.values.len(),
+                actual: other.values.len(),
+            });
+        }
+        if self.values.len() < 2 {
+            return Err(TimeSeriesError::InsufficientData {
+                required: 2,
+                actual: self.values.len(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Computes the Pearson correlation of two equal-length slices.
+fn pearson(x: &[f64], y: &[f64]) -> Result<f64, TimeSeriesError> {
+    let n = x.len() as f64;
+    let mean_x = x.iter().sum::<f64>() / n;
+    let mean_y = y.iter().sum::<f64>() / n;
+    let mut covariance = 0.0;
+    let mut variance_x = 0.0;
+    let mut variance_y = 0.0;
+    for (&a, &b) in x.iter().zip(y.iter()) {
+        covariance += (a - mean_x) * (b - mean_y);
+        variance_x += (a - mean_x).powi(2);
+        variance_y += (b - mean_y).powi(2);
+    }
+    if variance_x == 0.0 || variance_y == 0.0 {
+        return Err(TimeSeriesError::ZeroVariance);
+    }
+    Ok(covariance / (variance_x * variance_y).sqrt())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rank_averages_ties() {
+        let ts = TimeSeries::new(vec![1, 2, 3, 4, 5], vec![30.0, 10.0, 20.0, 20.0, 40.0]);
+        assert_eq!(ts.rank(), vec![4.0, 1.0, 2.5, 2.5, 5.0]);
+    }
+
+    #[test]
+    fn test_spearman_monotonic_relationship() {
+        let a = TimeSeries::new(vec![1, 2, 3, 4, 5], vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+        let b = TimeSeries::new(vec![1, 2, 3, 4, 5], vec![1.0, 4.0, 9.0, 16.0, 1000.0]);
+        assert!((a.spearman_correlation(&b).unwrap() - 1.0).abs() < 1e-10);
+
+        let reversed = TimeSeries::new(vec![1, 2, 3, 4, 5], vec![5.0, 4.0, 3.0, 2.0, 1.0]);
+        assert!((a.spearman_correlation(&reversed).unwrap() + 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_pearson_correlation() {
+        let a = TimeSeries::new(vec![1, 2, 3, 4, 5], vec![1.0, 3.0, 2.0, 5.0, 4.0]);
+        assert!((a.pearson_correlation(&a).unwrap() - 1.0).abs() < 1e-12);
+
+        let mirrored = TimeSeries::new(vec![1, 2, 3, 4, 5], a.values.iter().map(|v| -v).collect());
+        assert!((a.pearson_correlation(&mirrored).unwrap() + 1.0).abs() < 1e-12);
+
+        // Unlike Spearman, Pearson measures a linear relationship.
+        let b = TimeSeries::new(vec![1, 2, 3, 4, 5], vec![1.0, 4.0, 9.0, 16.0, 1000.0]);
+        let linear = TimeSeries::new(vec![1, 2, 3, 4, 5], vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+        assert!(linear.pearson_correlation(&b).unwrap() < 0.9);
+        assert_eq!(
+            linear.pearson_correlation(&TimeSeries::new(vec![1], vec![1.0])),
+            Err(TimeSeriesError::LengthMismatch {
+                expected: 5,
+                actual: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_correlation_public_values() {
+        let portfolio = TimeSeries::new(vec![1, 2, 3, 4], vec![100.0, 102.0, 101.0, 104.0]);
+        let index = TimeSeries::new(vec![1, 2, 3, 4], vec![50.0, 51.2, 50.4, 52.1]);
+        let public_values = portfolio.to_correlation_public_values(&index, 0.9).unwrap();
+        assert!(public_values.above_threshold);
+        assert_eq!(public_values.threshold, f64_to_i256(0.9));
+        assert_eq!(
+            public_values.other_values_hash,
+            alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(index.compute_hash())
+        );
+
+        let inverse = TimeSeries::new(vec![1, 2, 3, 4], vec![4.0, 3.0, 2.0, 1.0]);
+        assert!(
+            !portfolio
+                .to_correlation_public_values(&inverse, 0.0)
+                .unwrap()
+                .above_threshold
+        );
+    }
+
+    #[test]
+    fn test_spearman_with_ties() {
+        let a = TimeSeries::new(vec![1, 2, 3, 4], vec![1.0, 2.0, 2.0, 3.0]);
+        let b = TimeSeries::new(vec![1, 2, 3, 4], vec![1.0, 3.0, 2.0, 4.0]);
+        // Ranks are [1, 2.5, 2.5, 4] and [1, 3, 2, 4].
+        let expected = 4.5 / (4.5f64 * 5.0).sqrt();
+        assert!((a.spearman_correlation(&b).unwrap() - expected).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_covariance() {
+        let x = TimeSeries::new(vec![1, 2, 3, 4, 5], vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+        let y = TimeSeries::new(vec![1, 2, 3, 4, 5], vec![2.0, 4.0, 5.0, 4.0, 5.0]);
+        // The sum of products of deviations is 6.
+        assert!((x.covariance(&y, 0).unwrap() - 1.2).abs() < 1e-10);
+        assert!((x.covariance(&y, 1).unwrap() - 1.5).abs() < 1e-10);
+        assert!((x.covariance(&x, 0).unwrap() - x.std_dev().powi(2)).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_beta() {
+        let benchmark = TimeSeries::new(vec![1, 2, 3, 4, 5], vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+        let asset = TimeSeries::new(vec![1, 2, 3, 4, 5], vec![2.0, 4.0, 5.0, 4.0, 5.0]);
+        // Covariance 6/(n - ddof) over benchmark variance 10/(n - ddof).
+        assert!((asset.beta(&benchmark, 0).unwrap() - 0.6).abs() < 1e-10);
+        assert!((asset.beta(&benchmark, 1).unwrap() - 0.6).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_beta_errors() {
+        let asset = TimeSeries::new(vec![1, 2, 3], vec![1.0, 2.0, 3.0]);
+        let constant = TimeSeries::new(vec![1, 2, 3], vec![4.0, 4.0, 4.0]);
+        let short = TimeSeries::new(vec![1], vec![1.0]);
+        assert_eq!(asset.beta(&constant, 1), Err(TimeSeriesError::ZeroVariance));
+        assert_eq!(
+            short.covariance(&short, 1),
+            Err(TimeSeriesError::InsufficientData {
+                required: 2,
+                actual: 1
+            })
+        );
+        assert!(matches!(
+            asset.covariance(&short, 0),
+            Err(TimeSeriesError::LengthMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_spearman_errors() {
+        let a = TimeSeries::new(vec![1, 2, 3], vec![1.0, 2.0, 3.0]);
+        let short = TimeSeries::new(vec![1, 2], vec![1.0, 2.0]);
+        let constant = TimeSeries::new(vec![1, 2, 3], vec![7.0, 7.0, 7.0]);
+        assert_eq!(
+            a.spearman_correlation(&short),
+            Err(TimeSeriesError::LengthMismatch {
+                expected: 3,
+                actual: 2
+            })
+        );
+        assert_eq!(
+            a.spearman_correlation(&constant),
+            Err(TimeSeriesError::ZeroVariance)
+        );
+    }
+}
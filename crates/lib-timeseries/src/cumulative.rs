@@ -0,0 +1,80 @@
+#[cfg(not(any(feature = "std", test)))]
+use crate::prelude::*;
+use crate::TimeSeries;
+
+impl TimeSeries {
+    /// Computes the running sum of the values.
+    ///
+    /// The output has one value per input point at the same timestamp, so unlike
+    /// [`TimeSeries::diff`] the length does not change.
+    ///
+    /// Uses Kahan-Babuška (Neumaier) compensated summation, so long series of small increments do
+    /// not accumulate rounding drift.
+    pub fn cumsum(&self) -> TimeSeries {
+        let mut sum = 0.0;
+        let mut compensation = 0.0;
+        let values = self
+            .values
+            .iter()
+            .map(|&value| {
+                let total = sum + value;
+                // Recover the low-order bits lost when adding the smaller operand.
+                if f64::abs(sum) >= f64::abs(value) {
+                    compensation += (sum - total) + value;
+                } else {
+                    compensation += (value - total) + sum;
+                }
+                sum = total;
+                sum + compensation
+            })
+            .collect();
+        TimeSeries::new(self.timestamps.clone(), values)
+    }
+
+    /// Computes the running mean of the values, using the compensated sums of
+    /// [`TimeSeries::cumsum`].
+    pub fn cummean(&self) -> TimeSeries {
+        let values = self
+            .cumsum()
+            .values
+            .iter()
+            .enumerate()
+            .map(|(i, sum)| sum / (i + 1) as f64)
+            .collect();
+        TimeSeries::new(self.timestamps.clone(), values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cumsum_and_cummean() {
+        let ts = TimeSeries::new(vec![1, 2, 3, 4], vec![1.5, -0.5, 4.0, 3.0]);
+        let cumsum = ts.cumsum();
+        assert_eq!(cumsum.timestamps, ts.timestamps);
+        assert_eq!(cumsum.values, vec![1.5, 1.0, 5.0, 8.0]);
+        assert_eq!(cumsum.values[3], ts.values.iter().sum::<f64>());
+
+        let cummean = ts.cummean();
+        assert_eq!(cummean.values, vec![1.5, 0.5, 5.0 / 3.0, 2.0]);
+        assert_eq!(cummean.values[3], ts.mean());
+    }
+
+    #[test]
+    fn test_cumsum_of_increasing_integers() {
+        let ts = TimeSeries::new(vec![1, 2, 3], vec![1.0, 2.0, 3.0]);
+        assert_eq!(ts.cumsum().values, vec![1.0, 3.0, 6.0]);
+    }
+
+    #[test]
+    fn test_cumsum_does_not_drift() {
+        let n = 1_000_000;
+        let ts = TimeSeries::new((0..n).collect(), vec![0.1; n as usize]);
+        let total = *ts.cumsum().values.last().unwrap();
+        let naive: f64 = ts.values.iter().sum();
+        assert!((total - 100_000.0).abs() < 1e-9);
+        assert!((naive - 100_000.0).abs() > (total - 100_000.0).abs());
+    }
+}
@@ -0,0 +1,210 @@
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+use crate::{f64_to_i256, f64_to_u256, CusumPublicValuesStruct, TimeSeries, TimeSeriesError};
+
+/// The upper and lower cumulative sums of the tabular CUSUM, as computed by
+/// [`TimeSeries::cusum`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct CusumResult {
+    /// The upper sum after each point, `max(0, upper + value - target - slack)`, which grows
+    /// while the values sit above the target.
+    pub upper: Vec<f64>,
+    /// The lower sum after each point, `max(0, lower + target - slack - value)`, which grows
+    /// while the values sit below the target.
+    pub lower: Vec<f64>,
+}
+
+/// Advances both CUSUM sums by one value. A NaN value makes both sums NaN, and they stay NaN.
+fn cusum_step(upper: f64, lower: f64, value: f64, target: f64, slack: f64) -> (f64, f64) {
+    // Unlike `f64::max(sum, 0.0)`, this keeps a NaN sum NaN instead of resetting it to zero.
+    let floor_at_zero = |sum: f64| if sum < 0.0 { 0.0 } else { sum };
+    (
+        floor_at_zero(upper + value - target - slack),
+        floor_at_zero(lower + target - slack - value),
+    )
+}
+
+impl TimeSeries {
+    /// Computes the tabular CUSUM of the values around `target`.
+    ///
+    /// Each sum accumulates the deviations from `target` in one direction, less `slack` per
+    /// point, and never drops below zero, so noise within `slack` of the target keeps both sums
+    /// near zero while a sustained shift makes one of them climb steadily.
+    ///
+    /// # Arguments
+    /// * `target` - The expected level of the series
+    /// * `slack` - The deviation per point that is tolerated as noise, often half the shift to
+    ///   detect (slack >= 0)
+    ///
+    /// A NaN value makes both sums NaN from that point on.
+    ///
+    /// # Panics
+    /// Panics if `slack` is negative.
+    pub fn cusum(&self, target: f64, slack: f64) -> CusumResult {
+        assert!(slack >= 0.0, "Slack must be non-negative");
+        let mut upper = Vec::with_capacity(self.values.len());
+        let mut lower = Vec::with_capacity(self.values.len());
+        let (mut u, mut l) = (0.0, 0.0);
+        for &value in &self.values {
+            (u, l) = cusum_step(u, l, value, target, slack);
+            upper.push(u);
+            lower.push(l);
+        }
+        CusumResult { upper, lower }
+    }
+
+    /// Finds the timestamps at which either [`TimeSeries::cusum`] sum exceeds `threshold`.
+    ///
+    /// Both sums are reset to zero after each detection, so a shift that persists is reported
+    /// again each time the sums climb back over the threshold. An empty result means no regime
+    /// change away from `target` was detected. After a NaN value the sums stay NaN and nothing
+    /// more is detected, so [`TimeSeries::to_cusum_public_values`] rejects such series.
+    ///
+    /// # Panics
+    /// Panics if `slack` is negative or `threshold` is not positive.
+    pub fn change_points(&self, target: f64, slack: f64, threshold: f64) -> Vec<u64> {
+        assert!(slack >= 0.0, "Slack must be non-negative");
+        assert!(threshold > 0.0, "Threshold must be positive");
+        let mut change_points = Vec::new();
+        let (mut upper, mut lower) = (0.0, 0.0);
+        for (&timestamp, &value) in self.timestamps.iter().zip(self.values.iter()) {
+            (upper, lower) = cusum_step(upper, lower, value, target, slack);
+            if upper > threshold || lower > threshold {
+                change_points.push(timestamp);
+                (upper, lower) = (0.0, 0.0);
+            }
+        }
+        change_points
+    }
+
+    /// Builds the public values of the CUSUM program, committing the number of change points
+    /// found by [`TimeSeries::change_points`] together with the values hash. A count of zero
+    /// attests that no regime change occurred during the series.
+    ///
+    /// # Errors
+    /// Returns [`TimeSeriesError::NonFiniteValue`] for a NaN or infinite value, and
+    /// [`TimeSeriesError::InvalidParameter`] unless `target` is finite, `slack` is finite and
+    /// non-negative, and `threshold` is finite and positive.
+    pub fn to_cusum_public_values(
+        &self,
+        target: f64,
+        slack: f64,
+        threshold: f64,
+    ) -> Result<CusumPublicValuesStruct, TimeSeriesError> {
+        self.validate_finite()?;
+        if !target.is_finite() {
+            return Err(TimeSeriesError::InvalidParameter { name: "target" });
+        }
+        if !slack.is_finite() || slack < 0.0 {
+            return Err(TimeSeriesError::InvalidParameter { name: "slack" });
+        }
+        if !threshold.is_finite() || threshold <= 0.0 {
+            return Err(TimeSeriesError::InvalidParameter { name: "threshold" });
+        }
+        let change_points = self.change_points(target, slack, threshold);
+
+        Ok(CusumPublicValuesStruct {
+            values_hash: alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(
+                self.compute_hash(),
+            ),
+            target: f64_to_i256(target),
+            slack: f64_to_u256(slack),
+            threshold: f64_to_u256(threshold),
+            change_point_count: alloy_sol_types::private::Uint::<256, 4>::from(change_points.len()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A level of 10 for 30 minutes that steps up to 13 at minute 30, with noise of up to 0.5.
+    fn step_series() -> TimeSeries {
+        let noise = [0.3, -0.5, 0.1, 0.4, -0.2, -0.4, 0.5, 0.0, -0.1, 0.2];
+        let timestamps = (0..60).map(|i| i * 60).collect();
+        let values = (0..60)
+            .map(|i| if i < 30 { 10.0 } else { 13.0 } + noise[i % 10])
+            .collect();
+        TimeSeries::new(timestamps, values)
+    }
+
+    #[test]
+    fn test_cusum_sums_track_the_shift() {
+        let result = step_series().cusum(10.0, 1.0);
+        assert_eq!(result.upper.len(), 60);
+        // Noise within the slack keeps the upper sum at zero before the shift.
+        assert!(result.upper[..30].iter().all(|&u| u == 0.0));
+        // After it, each point adds about 3 - 1 = 2.
+        assert!(result.upper[59] > 50.0);
+        assert!(result.lower.iter().all(|&l| l == 0.0));
+    }
+
+    #[test]
+    fn test_change_points_detect_step_within_two_samples() {
+        let ts = step_series();
+        let change_points = ts.change_points(10.0, 1.0, 4.0);
+        let first = change_points[0];
+        assert!((30 * 60..=32 * 60).contains(&first), "detected at {first}");
+
+        // A downward step is caught by the lower sum.
+        let falling = TimeSeries::new(
+            ts.timestamps.clone(),
+            ts.values.iter().map(|v| 23.0 - v).collect(),
+        );
+        assert_eq!(falling.change_points(13.0, 1.0, 4.0)[0], first);
+
+        // Before the shift the series is stable.
+        let stable = TimeSeries::new(ts.timestamps[..30].to_vec(), ts.values[..30].to_vec());
+        assert!(stable.change_points(10.0, 1.0, 4.0).is_empty());
+    }
+
+    #[test]
+    fn test_cusum_public_values() {
+        let ts = step_series();
+        let public_values = ts.to_cusum_public_values(10.0, 1.0, 4.0).unwrap();
+        assert_eq!(
+            public_values.change_point_count,
+            alloy_sol_types::private::Uint::<256, 4>::from(ts.change_points(10.0, 1.0, 4.0).len())
+        );
+        assert_eq!(public_values.target, f64_to_i256(10.0));
+    }
+
+    #[test]
+    fn test_nan_value_poisons_the_sums() {
+        let ts = TimeSeries::new(vec![1, 2, 3, 4], vec![10.0, f64::NAN, 20.0, 20.0]);
+        let result = ts.cusum(10.0, 1.0);
+        assert_eq!(result.upper[0], 0.0);
+        assert!(result.upper[1..].iter().all(|u| u.is_nan()));
+        assert!(result.lower[1..].iter().all(|l| l.is_nan()));
+        // The NaN sums never exceed the threshold, so the jump to 20 goes undetected and the
+        // public values reject the series instead.
+        assert!(ts.change_points(10.0, 1.0, 4.0).is_empty());
+        assert_eq!(
+            ts.to_cusum_public_values(10.0, 1.0, 4.0).err(),
+            Some(TimeSeriesError::NonFiniteValue { index: 1 })
+        );
+    }
+
+    #[test]
+    fn test_cusum_public_values_reject_invalid_parameters() {
+        let ts = step_series();
+        for (target, slack, threshold, name) in [
+            (f64::NAN, 1.0, 4.0, "target"),
+            (f64::INFINITY, 1.0, 4.0, "target"),
+            (10.0, f64::NAN, 4.0, "slack"),
+            (10.0, -1.0, 4.0, "slack"),
+            (10.0, 1.0, f64::NAN, "threshold"),
+            (10.0, 1.0, f64::INFINITY, "threshold"),
+            (10.0, 1.0, 0.0, "threshold"),
+        ] {
+            assert_eq!(
+                ts.to_cusum_public_values(target, slack, threshold).err(),
+                Some(TimeSeriesError::InvalidParameter { name })
+            );
+        }
+    }
+}
@@ -0,0 +1,47 @@
+//! Deterministic floating-point functions.
+//!
+//! The standard library's transcendental functions defer to the platform's math library, whose
+//! results may differ in the last bits between the host and the zkVM. These wrappers use `libm`'s
+//! pure-Rust implementations instead, so a value computed on the host matches the committed one
+//! bit for bit.
+
+/// Computes `base` raised to the power `exponent`.
+pub fn pow(base: f64, exponent: f64) -> f64 {
+    libm::pow(base, exponent)
+}
+
+/// Computes `e` raised to the power `x`.
+pub fn exp(x: f64) -> f64 {
+    libm::exp(x)
+}
+
+/// Computes the natural logarithm of `x`.
+pub fn ln(x: f64) -> f64 {
+    libm::log(x)
+}
+
+/// Computes the sine of `x` (in radians).
+pub fn sin(x: f64) -> f64 {
+    libm::sin(x)
+}
+
+/// Computes the cosine of `x` (in radians).
+pub fn cos(x: f64) -> f64 {
+    libm::cos(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detmath_exact_cases() {
+        assert_eq!(pow(2.0, 10.0), 1024.0);
+        assert_eq!(pow(9.0, 0.5), 3.0);
+        assert_eq!(exp(0.0), 1.0);
+        assert_eq!(ln(1.0), 0.0);
+        assert!((ln(exp(2.5)) - 2.5).abs() < 1e-15);
+        assert_eq!(sin(0.0), 0.0);
+        assert_eq!(cos(0.0), 1.0);
+    }
+}
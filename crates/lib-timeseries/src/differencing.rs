@@ -0,0 +1,81 @@
+use crate::TimeSeries;
+
+impl TimeSeries {
+    /// Differences the series `order` times.
+    ///
+    /// Each pass replaces every pair of consecutive points with `values[i] - values[i - 1]` at the
+    /// later timestamp `timestamps[i]`, so the output is `order` points shorter. Differencing
+    /// away every point returns an empty series; `order == 0` returns a copy.
+    pub fn diff(&self, order: usize) -> TimeSeries {
+        let mut differenced = self.clone();
+        for _ in 0..order {
+            if differenced.values.is_empty() {
+                break;
+            }
+            let values = differenced.values.windows(2).map(|w| w[1] - w[0]).collect();
+            differenced.timestamps.remove(0);
+            differenced.values = values;
+        }
+        differenced
+    }
+
+    /// Inverts a first-order [`TimeSeries::diff`], treating the values as differences.
+    ///
+    /// Each output value is `initial` plus the running sum of the differences up to that point,
+    /// at the same timestamp. For a series `ts`, `ts.diff(1).cumulative_undiff(ts.values[0])`
+    /// reconstructs every point of `ts` but the first.
+    ///
+    /// # Arguments
+    /// * `initial` - The value immediately before the first difference
+    pub fn cumulative_undiff(&self, initial: f64) -> TimeSeries {
+        let values = self
+            .cumsum()
+            .values
+            .iter()
+            .map(|sum| initial + sum)
+            .collect();
+        TimeSeries::new(self.timestamps.clone(), values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_first_and_second_order() {
+        let ts = TimeSeries::new(vec![1, 2, 3, 4, 5], vec![1.0, 4.0, 9.0, 16.0, 25.0]);
+        let first = ts.diff(1);
+        assert_eq!(first.timestamps, vec![2, 3, 4, 5]);
+        assert_eq!(first.values, vec![3.0, 5.0, 7.0, 9.0]);
+        let second = ts.diff(2);
+        assert_eq!(second.timestamps, vec![3, 4, 5]);
+        assert_eq!(second.values, vec![2.0, 2.0, 2.0]);
+        assert_eq!(ts.diff(0).values, ts.values);
+    }
+
+    #[test]
+    fn test_diff_of_triangular_numbers() {
+        let ts = TimeSeries::new(vec![1, 2, 3, 4], vec![1.0, 3.0, 6.0, 10.0]);
+        let first = ts.diff(1);
+        assert_eq!(first.timestamps, vec![2, 3, 4]);
+        assert_eq!(first.values, vec![2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn test_diff_short_series() {
+        let single = TimeSeries::new(vec![7], vec![1.0]);
+        assert!(single.diff(1).values.is_empty());
+        assert!(single.diff(1).timestamps.is_empty());
+        assert!(single.diff(3).values.is_empty());
+        assert!(TimeSeries::new(vec![], vec![]).diff(1).values.is_empty());
+    }
+
+    #[test]
+    fn test_cumulative_undiff_round_trip() {
+        let ts = TimeSeries::new(vec![10, 20, 30, 40], vec![2.5, -1.0, 3.0, 3.5]);
+        let restored = ts.diff(1).cumulative_undiff(ts.values[0]);
+        assert_eq!(restored.timestamps, ts.timestamps[1..]);
+        assert_eq!(restored.values, ts.values[1..]);
+    }
+}
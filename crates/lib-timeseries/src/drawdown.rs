@@ -0,0 +1,135 @@
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::prelude::*;
+use crate::TimeSeries;
+
+/// A decline from a running peak to a later trough, as found by [`TimeSeries::max_drawdown`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Drawdown {
+    /// The decline as a fraction of the peak's magnitude, `(peak - trough) / |peak|`.
+    pub fraction: f64,
+    pub peak_timestamp: u64,
+    pub peak_value: f64,
+    pub trough_timestamp: u64,
+    pub trough_value: f64,
+}
+
+/// Returns `(peak - value) / |peak|`, with no decline counting as 0 even at a zero peak, and
+/// any decline from a zero peak as infinite.
+fn drawdown_fraction(peak: f64, value: f64) -> f64 {
+    let decline = peak - value;
+    if decline <= 0.0 {
+        0.0
+    } else {
+        decline / peak.abs()
+    }
+}
+
+impl TimeSeries {
+    /// Finds the largest decline from a running peak to a later trough, in one pass.
+    ///
+    /// Declines are measured as fractions of the peak's magnitude, so with negative values a
+    /// fall from -10 to -15 is a drawdown of 0.5. A decline from a peak of exactly zero is
+    /// infinite. Among equal drawdowns the earliest is returned. For a series that never falls
+    /// the drawdown is 0, with the first point as both peak and trough. Points are taken in
+    /// stored order.
+    ///
+    /// # Returns
+    /// The largest drawdown, or `None` if the series has fewer than two points.
+    pub fn max_drawdown(&self) -> Option<Drawdown> {
+        if self.values.len() < 2 {
+            return None;
+        }
+        let mut peak = (self.timestamps[0], self.values[0]);
+        let mut worst = Drawdown {
+            fraction: 0.0,
+            peak_timestamp: peak.0,
+            peak_value: peak.1,
+            trough_timestamp: peak.0,
+            trough_value: peak.1,
+        };
+        for (&timestamp, &value) in self.timestamps.iter().zip(self.values.iter()).skip(1) {
+            if value > peak.1 {
+                peak = (timestamp, value);
+                continue;
+            }
+            let fraction = drawdown_fraction(peak.1, value);
+            if fraction > worst.fraction {
+                worst = Drawdown {
+                    fraction,
+                    peak_timestamp: peak.0,
+                    peak_value: peak.1,
+                    trough_timestamp: timestamp,
+                    trough_value: value,
+                };
+            }
+        }
+        Some(worst)
+    }
+
+    /// Computes the drawdown at each point: its decline below the running peak up to and
+    /// including it, as a fraction of the peak's magnitude (see [`TimeSeries::max_drawdown`]).
+    ///
+    /// The output has one value per input point at the same timestamp, and is 0 at every new
+    /// peak.
+    pub fn drawdown_series(&self) -> TimeSeries {
+        let mut peak = f64::NEG_INFINITY;
+        let values = self
+            .values
+            .iter()
+            .map(|&value| {
+                peak = peak.max(value);
+                drawdown_fraction(peak, value)
+            })
+            .collect();
+        TimeSeries::new(self.timestamps.clone(), values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_max_drawdown() {
+        let ts = TimeSeries::new(
+            vec![1, 2, 3, 4, 5, 6, 7],
+            vec![100.0, 120.0, 90.0, 110.0, 60.0, 130.0, 110.0],
+        );
+        assert_eq!(
+            ts.max_drawdown(),
+            Some(Drawdown {
+                fraction: 0.5,
+                peak_timestamp: 2,
+                peak_value: 120.0,
+                trough_timestamp: 5,
+                trough_value: 60.0,
+            })
+        );
+        assert_eq!(
+            ts.drawdown_series().values[..5],
+            [0.0, 0.0, 0.25, 10.0 / 120.0, 0.5]
+        );
+        assert_eq!(ts.drawdown_series().timestamps, ts.timestamps);
+    }
+
+    #[test]
+    fn test_max_drawdown_edge_cases() {
+        let rising = TimeSeries::new(vec![1, 2, 3], vec![1.0, 2.0, 3.0]);
+        let drawdown = rising.max_drawdown().unwrap();
+        assert_eq!(drawdown.fraction, 0.0);
+        assert_eq!(drawdown.trough_timestamp, 1);
+        assert_eq!(rising.drawdown_series().values, vec![0.0; 3]);
+
+        let negative = TimeSeries::new(vec![1, 2, 3], vec![-10.0, -15.0, -12.0]);
+        let drawdown = negative.max_drawdown().unwrap();
+        assert_eq!(drawdown.fraction, 0.5);
+        assert_eq!(drawdown.trough_value, -15.0);
+
+        assert_eq!(TimeSeries::new(vec![1], vec![5.0]).max_drawdown(), None);
+        assert_eq!(TimeSeries::new(vec![], vec![]).max_drawdown(), None);
+    }
+}
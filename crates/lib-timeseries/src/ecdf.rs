@@ -0,0 +1,157 @@
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+use crate::TimeSeries;
+
+/// The empirical CDF of a series, built once by [`TimeSeries::empirical_cdf`].
+///
+/// The values are sorted when it is built, so each query is a binary search rather than a sort.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Ecdf {
+    /// The values other than NaN, in ascending order.
+    sorted: Vec<f64>,
+    /// The length of the series, NaN values included.
+    len: usize,
+}
+
+impl Ecdf {
+    /// Evaluates the empirical CDF: the fraction of values less than or equal to `x`.
+    ///
+    /// NaN values are never `<= x` but still count towards the total. Returns NaN for an empty
+    /// series.
+    pub fn eval(&self, x: f64) -> f64 {
+        self.sorted.partition_point(|&v| v <= x) as f64 / self.len as f64
+    }
+
+    /// Returns the fraction of values strictly below `threshold`.
+    ///
+    /// Unlike [`Ecdf::eval`], a value equal to the threshold does not count. Returns NaN for an
+    /// empty series.
+    pub fn fraction_below(&self, threshold: f64) -> f64 {
+        self.sorted.partition_point(|&v| v < threshold) as f64 / self.len as f64
+    }
+
+    /// Returns the empirical CDF as a step function: each distinct value in ascending order,
+    /// paired with the fraction of values less than or equal to it.
+    ///
+    /// Duplicate values collapse into one step. NaN values get no step but count towards the
+    /// total, so the last fraction is below 1 when the series holds NaN.
+    pub fn curve(&self) -> Vec<(f64, f64)> {
+        let n = self.len as f64;
+        let mut curve: Vec<(f64, f64)> = Vec::new();
+        for (i, &value) in self.sorted.iter().enumerate() {
+            let fraction = (i + 1) as f64 / n;
+            match curve.last_mut() {
+                Some(last) if last.0 == value => last.1 = fraction,
+                _ => curve.push((value, fraction)),
+            }
+        }
+        curve
+    }
+}
+
+impl TimeSeries {
+    /// Sorts the values once into an [`Ecdf`] that answers any number of queries by binary
+    /// search.
+    pub fn empirical_cdf(&self) -> Ecdf {
+        let mut sorted: Vec<f64> = self
+            .values
+            .iter()
+            .copied()
+            .filter(|v| !v.is_nan())
+            .collect();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        Ecdf {
+            sorted,
+            len: self.values.len(),
+        }
+    }
+
+    /// Evaluates the empirical CDF at `x`; see [`Ecdf::eval`].
+    ///
+    /// This sorts the values on every call. Build an [`Ecdf`] with
+    /// [`TimeSeries::empirical_cdf`] to query many points.
+    pub fn ecdf(&self, x: f64) -> f64 {
+        self.empirical_cdf().eval(x)
+    }
+
+    /// Returns the fraction of values strictly below `threshold`; see [`Ecdf::fraction_below`].
+    ///
+    /// This is the "at least 95% of response times were under 200ms" statistic. It sorts the
+    /// values on every call, like [`TimeSeries::ecdf`].
+    pub fn fraction_below(&self, threshold: f64) -> f64 {
+        self.empirical_cdf().fraction_below(threshold)
+    }
+
+    /// Returns the empirical CDF as a step function; see [`Ecdf::curve`].
+    pub fn ecdf_curve(&self) -> Vec<(f64, f64)> {
+        self.empirical_cdf().curve()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response_times() -> TimeSeries {
+        TimeSeries::new(
+            (0..8).collect(),
+            vec![120.0, 80.0, 200.0, 95.0, 200.0, 310.0, 80.0, 150.0],
+        )
+    }
+
+    #[test]
+    fn test_ecdf_counts_ties_at_threshold() {
+        let ts = response_times();
+        assert_eq!(ts.ecdf(79.0), 0.0);
+        assert_eq!(ts.ecdf(80.0), 0.25);
+        // Both 200s are <= 200, but neither is below it.
+        assert_eq!(ts.ecdf(200.0), 0.875);
+        assert_eq!(ts.fraction_below(200.0), 0.625);
+        assert_eq!(ts.ecdf(1000.0), 1.0);
+        assert_eq!(ts.fraction_below(80.0), 0.0);
+    }
+
+    #[test]
+    fn test_ecdf_answers_repeated_queries() {
+        let ts = TimeSeries::new((0..5).collect(), vec![3.0, f64::NAN, 1.0, 2.0, 2.0]);
+        let ecdf = ts.empirical_cdf();
+        assert_eq!(ecdf.eval(0.5), 0.0);
+        assert_eq!(ecdf.eval(2.0), 0.6);
+        assert_eq!(ecdf.fraction_below(2.0), 0.2);
+        // The NaN counts towards the total but is never below anything.
+        assert_eq!(ecdf.eval(10.0), 0.8);
+        assert_eq!(ecdf.curve(), vec![(1.0, 0.2), (2.0, 0.6), (3.0, 0.8)]);
+    }
+
+    #[test]
+    fn test_ecdf_curve_collapses_duplicates() {
+        let curve = response_times().ecdf_curve();
+        assert_eq!(
+            curve,
+            vec![
+                (80.0, 0.25),
+                (95.0, 0.375),
+                (120.0, 0.5),
+                (150.0, 0.625),
+                (200.0, 0.875),
+                (310.0, 1.0),
+            ]
+        );
+        let ecdf = response_times().empirical_cdf();
+        for &(value, fraction) in &curve {
+            assert_eq!(ecdf.eval(value), fraction);
+        }
+    }
+
+    #[test]
+    fn test_ecdf_of_empty_series() {
+        let empty = TimeSeries::new(vec![], vec![]);
+        assert!(empty.ecdf(0.0).is_nan());
+        assert!(empty.fraction_below(0.0).is_nan());
+        assert!(empty.ecdf_curve().is_empty());
+        assert!(empty.empirical_cdf().eval(0.0).is_nan());
+    }
+}
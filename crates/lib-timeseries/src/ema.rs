@@ -0,0 +1,137 @@
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+use crate::{f64_to_u256, vec_f64_to_i256, EmaPublicValuesStruct, TimeSeries};
+
+/// A convention for deriving an EMA smoothing factor from a period.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum AlphaConvention {
+    /// `alpha = 2 / (period + 1)`, the usual convention for an "N-period EMA".
+    Span,
+    /// `alpha = 1 / period`, used by Wilder's smoothing (e.g. RSI and ATR).
+    Wilder,
+}
+
+impl AlphaConvention {
+    /// Returns the identifier committed for this convention.
+    ///
+    /// Identifier 0 is reserved for an alpha given directly rather than derived from a period.
+    pub fn id(&self) -> u8 {
+        match self {
+            AlphaConvention::Span => 1,
+            AlphaConvention::Wilder => 2,
+        }
+    }
+}
+
+/// How the smoothing factor of an exponential moving average is specified.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum EmaSmoothing {
+    /// The smoothing factor itself (0 <= alpha <= 1).
+    Alpha(f64),
+    /// A period, converted to a smoothing factor with the given convention.
+    Period {
+        period: usize,
+        convention: AlphaConvention,
+    },
+}
+
+impl EmaSmoothing {
+    /// Returns the smoothing factor.
+    pub fn alpha(&self) -> f64 {
+        match *self {
+            EmaSmoothing::Alpha(alpha) => alpha,
+            EmaSmoothing::Period { period, convention } => alpha_from_period(period, convention),
+        }
+    }
+}
+
+/// Derives the smoothing factor of an exponential moving average from its period.
+///
+/// # Panics
+/// Panics if `period` is zero.
+pub fn alpha_from_period(period: usize, convention: AlphaConvention) -> f64 {
+    assert!(period > 0, "Period must be positive");
+    match convention {
+        AlphaConvention::Span => 2.0 / (period as f64 + 1.0),
+        AlphaConvention::Wilder => 1.0 / period as f64,
+    }
+}
+
+/// Converts an EMA period into its smoothing factor, `2 / (period + 1)`.
+///
+/// This is [`alpha_from_period`] with [`AlphaConvention::Span`].
+///
+/// # Panics
+/// Panics if `period` is zero.
+pub fn period_to_alpha(period: usize) -> f64 {
+    alpha_from_period(period, AlphaConvention::Span)
+}
+
+impl TimeSeries {
+    /// Commits the exponential moving average together with how its smoothing factor was chosen.
+    ///
+    /// For a period-derived alpha the period and convention identifier are committed alongside
+    /// the alpha; for a raw alpha both are zero.
+    pub fn to_ema_public_values(&self, smoothing: EmaSmoothing) -> EmaPublicValuesStruct {
+        let start_timestamp = *self.timestamps.first().unwrap_or(&0);
+        let end_timestamp = *self.timestamps.last().unwrap_or(&0);
+        let values_hash = self.compute_hash();
+        let alpha = smoothing.alpha();
+        let ema = self.exponential_moving_average(alpha);
+        let (period, convention) = match smoothing {
+            EmaSmoothing::Alpha(_) => (0, 0),
+            EmaSmoothing::Period { period, convention } => (period, convention.id()),
+        };
+
+        EmaPublicValuesStruct {
+            start_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(start_timestamp),
+            end_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(end_timestamp),
+            values_hash: alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(values_hash),
+            period: alloy_sol_types::private::Uint::<256, 4>::from(period),
+            convention,
+            alpha: f64_to_u256(alpha),
+            ema: vec_f64_to_i256(&ema.values),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alpha_from_period() {
+        assert_eq!(alpha_from_period(9, AlphaConvention::Span), 0.2);
+        assert_eq!(alpha_from_period(20, AlphaConvention::Span), 2.0 / 21.0);
+        assert_eq!(alpha_from_period(14, AlphaConvention::Wilder), 1.0 / 14.0);
+        assert_eq!(alpha_from_period(1, AlphaConvention::Span), 1.0);
+        assert_eq!(alpha_from_period(1, AlphaConvention::Wilder), 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Period must be positive")]
+    fn test_alpha_from_period_rejects_zero() {
+        alpha_from_period(0, AlphaConvention::Span);
+    }
+
+    #[test]
+    fn test_period_ema_matches_direct_alpha() {
+        let ts = TimeSeries::new(vec![1, 2, 3, 4, 5], vec![3.0, -1.0, 4.0, 1.0, 5.0]);
+        let by_period = ts.to_ema_public_values(EmaSmoothing::Period {
+            period: 20,
+            convention: AlphaConvention::Span,
+        });
+        let by_alpha = ts.to_ema_public_values(EmaSmoothing::Alpha(2.0 / 21.0));
+        assert_eq!(by_period.ema, by_alpha.ema);
+        assert_eq!(by_period.alpha, by_alpha.alpha);
+        assert_eq!(by_period.convention, 1);
+        assert_eq!(by_alpha.convention, 0);
+        assert_eq!(
+            by_period.ema,
+            vec_f64_to_i256(&ts.exponential_moving_average(2.0 / 21.0).values)
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use crate::prelude::*;
+use core::fmt;
+
+/// Errors returned by the fallible [`TimeSeries`](crate::TimeSeries) operations.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TimeSeriesError {
+    /// Two series that must be paired point-by-point have different lengths.
+    LengthMismatch { expected: usize, actual: usize },
+    /// The series has fewer points than the operation requires.
+    InsufficientData { required: usize, actual: usize },
+    /// The statistic is undefined because a series has zero variance.
+    ZeroVariance,
+    /// The median absolute deviation is zero, so robust z-scores are undefined.
+    ZeroMad,
+    /// A weight is negative or not finite.
+    InvalidWeight { index: usize },
+    /// The weights sum to zero, so the weighted statistic is undefined.
+    ZeroTotalWeight,
+    /// A parameter is outside its valid range.
+    InvalidParameter { name: &'static str },
+    /// A linear system the operation must solve is singular.
+    SingularMatrix,
+    /// The last timestamp is not after the first, so no time has elapsed.
+    ZeroElapsedTime,
+    /// A value is NaN or infinite.
+    NonFiniteValue { index: usize },
+    /// A value's fixed-point form does not fit in the integer type it is committed as.
+    ValueOutOfRange { index: usize },
+    /// A value that must be positive is zero, negative or NaN.
+    NonPositiveValue { index: usize },
+    /// A requested timestamp lies outside the range the series covers.
+    TimestampOutOfRange { timestamp: u64 },
+    /// A timestamp is earlier than the one before it.
+    NonMonotonicTimestamp { index: usize },
+    /// Two series that must be paired point-by-point have different timestamps at `index`.
+    TimestampMismatch { index: usize },
+    /// Offsetting or rescaling the timestamp at `index` would take it below 0 or above
+    /// `u64::MAX`.
+    TimestampOverflow { index: usize },
+    /// Input data could not be parsed. `line` is 1-based, or 0 when the position is unknown.
+    Parse { line: usize, message: String },
+}
+
+impl fmt::Display for TimeSeriesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TimeSeriesError::LengthMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "length mismatch: expected {expected} points, got {actual}"
+                )
+            }
+            TimeSeriesError::InsufficientData { required, actual } => {
+                write!(
+                    f,
+                    "insufficient data: at least {required} points required, got {actual}"
+                )
+            }
+            TimeSeriesError::ZeroVariance => write!(f, "series has zero variance"),
+            TimeSeriesError::ZeroMad => {
+                write!(
+                    f,
+                    "median absolute deviation is zero; more than half the values are equal"
+                )
+            }
+            TimeSeriesError::InvalidWeight { index } => {
+                write!(
+                    f,
+                    "invalid weight at index {index}: must be finite and non-negative"
+                )
+            }
+            TimeSeriesError::ZeroTotalWeight => write!(f, "weights sum to zero"),
+            TimeSeriesError::InvalidParameter { name } => {
+                write!(f, "invalid parameter: {name} is out of range")
+            }
+            TimeSeriesError::SingularMatrix => write!(f, "linear system is singular"),
+            TimeSeriesError::ZeroElapsedTime => write!(f, "no time elapsed across the series"),
+            TimeSeriesError::NonFiniteValue { index } => {
+                write!(f, "non-finite value at index {index}")
+            }
+            TimeSeriesError::ValueOutOfRange { index } => {
+                write!(f, "value at index {index} is out of the fixed-point range")
+            }
+            TimeSeriesError::NonPositiveValue { index } => {
+                write!(f, "non-positive value at index {index}")
+            }
+            TimeSeriesError::TimestampOutOfRange { timestamp } => {
+                write!(f, "timestamp {timestamp} is outside the series")
+            }
+            TimeSeriesError::NonMonotonicTimestamp { index } => {
+                write!(
+                    f,
+                    "timestamp at index {index} is earlier than the previous one"
+                )
+            }
+            TimeSeriesError::TimestampMismatch { index } => {
+                write!(f, "timestamps of the paired series differ at index {index}")
+            }
+            TimeSeriesError::TimestampOverflow { index } => {
+                write!(
+                    f,
+                    "timestamp at index {index} overflows when shifted or rescaled"
+                )
+            }
+            TimeSeriesError::Parse { line, message } => {
+                write!(f, "parse error on line {line}: {message}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TimeSeriesError {}
@@ -0,0 +1,153 @@
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+use crate::TimeSeries;
+
+/// How [`TimeSeries::fill_nan`] replaces NaN values.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum FillMethod {
+    /// The last value before the NaN. Leading NaNs have no such value and are left as NaN.
+    Forward,
+    /// The first value after the NaN. Trailing NaNs have no such value and are left as NaN.
+    Backward,
+    /// Linear interpolation by timestamp between the values on either side of the NaN. Leading
+    /// and trailing NaNs lie outside that range and are left as NaN.
+    Linear,
+    /// The given value, everywhere.
+    Constant(f64),
+}
+
+impl TimeSeries {
+    /// Replaces NaN values, which ingestion often uses to mark missing readings, so the series
+    /// passes [`TimeSeries::validate_finite`] before proving.
+    ///
+    /// Only NaN counts as missing. Timestamps are kept as they are and should be sorted in
+    /// non-decreasing order for [`FillMethod::Linear`]. NaNs that the method cannot fill, such as
+    /// leading NaNs with [`FillMethod::Forward`], stay NaN; follow up with
+    /// [`FillMethod::Constant`] or drop those points if the series must be fully finite.
+    pub fn fill_nan(&self, method: FillMethod) -> TimeSeries {
+        let values = match method {
+            FillMethod::Forward => {
+                let mut last = f64::NAN;
+                self.values
+                    .iter()
+                    .map(|&v| {
+                        if !v.is_nan() {
+                            last = v;
+                        }
+                        last
+                    })
+                    .collect()
+            }
+            FillMethod::Backward => {
+                let mut next = f64::NAN;
+                let mut values: Vec<f64> = self
+                    .values
+                    .iter()
+                    .rev()
+                    .map(|&v| {
+                        if !v.is_nan() {
+                            next = v;
+                        }
+                        next
+                    })
+                    .collect();
+                values.reverse();
+                values
+            }
+            FillMethod::Linear => self.fill_nan_linear(),
+            FillMethod::Constant(c) => self
+                .values
+                .iter()
+                .map(|&v| if v.is_nan() { c } else { v })
+                .collect(),
+        };
+        TimeSeries::new(self.timestamps.clone(), values)
+    }
+
+    /// Interpolates each interior run of NaNs between the values on either side of it.
+    fn fill_nan_linear(&self) -> Vec<f64> {
+        let mut values = self.values.clone();
+        let mut previous: Option<usize> = None;
+        for i in 0..values.len() {
+            if values[i].is_nan() {
+                continue;
+            }
+            if let Some(p) = previous.filter(|&p| i - p > 1) {
+                let (t0, t1) = (self.timestamps[p], self.timestamps[i]);
+                let (v0, v1) = (values[p], values[i]);
+                let span = t1.saturating_sub(t0);
+                for (k, value) in values.iter_mut().enumerate().take(i).skip(p + 1) {
+                    let elapsed = self.timestamps[k].saturating_sub(t0).min(span);
+                    *value = if span == 0 {
+                        v0
+                    } else {
+                        v0 + (v1 - v0) * (elapsed as f64 / span as f64)
+                    };
+                }
+            }
+            previous = Some(i);
+        }
+        values
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A leading NaN, then an interior gap of two readings on uneven timestamps.
+    fn gappy() -> TimeSeries {
+        TimeSeries::new(
+            vec![0, 10, 20, 25, 40],
+            vec![f64::NAN, 2.0, f64::NAN, f64::NAN, 8.0],
+        )
+    }
+
+    fn assert_values(ts: &TimeSeries, expected: &[f64]) {
+        assert_eq!(ts.values.len(), expected.len());
+        for (actual, expected) in ts.values.iter().zip(expected) {
+            assert!(
+                actual == expected || (actual.is_nan() && expected.is_nan()),
+                "{:?} != {:?}",
+                ts.values,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_fill_nan_forward_and_backward() {
+        let forward = gappy().fill_nan(FillMethod::Forward);
+        assert_values(&forward, &[f64::NAN, 2.0, 2.0, 2.0, 8.0]);
+        assert_eq!(forward.timestamps, gappy().timestamps);
+
+        let backward = gappy().fill_nan(FillMethod::Backward);
+        assert_values(&backward, &[2.0, 2.0, 8.0, 8.0, 8.0]);
+
+        let trailing = TimeSeries::new(vec![0, 1], vec![1.0, f64::NAN]);
+        assert_values(&trailing.fill_nan(FillMethod::Backward), &[1.0, f64::NAN]);
+    }
+
+    #[test]
+    fn test_fill_nan_linear_uses_timestamps() {
+        // 2 at t = 10 to 8 at t = 40 rises 0.2 per second.
+        let linear = gappy().fill_nan(FillMethod::Linear);
+        assert_values(&linear, &[f64::NAN, 2.0, 4.0, 5.0, 8.0]);
+
+        let trailing = TimeSeries::new(vec![0, 1, 2], vec![1.0, 3.0, f64::NAN]);
+        assert_values(
+            &trailing.fill_nan(FillMethod::Linear),
+            &[1.0, 3.0, f64::NAN],
+        );
+    }
+
+    #[test]
+    fn test_fill_nan_constant() {
+        let filled = gappy().fill_nan(FillMethod::Constant(0.0));
+        assert_values(&filled, &[0.0, 2.0, 0.0, 0.0, 8.0]);
+        assert!(filled.validate_finite().is_ok());
+    }
+}
@@ -0,0 +1,87 @@
+#[cfg(not(any(feature = "std", test)))]
+use crate::prelude::*;
+use crate::{f64_to_u256, FlatlineStruct, TimeSeries};
+
+impl TimeSeries {
+    /// Finds the longest run of consecutive values that stay within `epsilon` of each other.
+    ///
+    /// A run continues while every value differs from the previous one by at most `epsilon`, so
+    /// with `epsilon == 0.0` only exactly repeated values form a run. This is the signature of a
+    /// stuck sensor.
+    ///
+    /// # Arguments
+    /// * `epsilon` - The largest change between consecutive values that still counts as flat
+    ///   (epsilon >= 0)
+    ///
+    /// # Returns
+    /// The number of points in the longest run and the time it spans in timestamp units (seconds
+    /// for Unix timestamps). A single point is a run of one spanning zero seconds; an empty
+    /// series returns `(0, 0)`. Among runs of equal length the earliest is returned.
+    pub fn longest_flatline(&self, epsilon: f64) -> (usize, u64) {
+        assert!(epsilon >= 0.0, "Epsilon must be non-negative");
+        if self.values.is_empty() {
+            return (0, 0);
+        }
+        let mut longest = (1, 0);
+        let mut run_start = 0;
+        for i in 1..self.values.len() {
+            if (self.values[i] - self.values[i - 1]).abs() > epsilon {
+                run_start = i;
+            }
+            let count = i - run_start + 1;
+            if count > longest.0 {
+                let seconds = self.timestamps[i].saturating_sub(self.timestamps[run_start]);
+                longest = (count, seconds);
+            }
+        }
+        longest
+    }
+
+    pub fn to_flatline_public_values(&self, epsilon: f64) -> FlatlineStruct {
+        let values_hash = self.compute_hash();
+        let (longest_run_count, longest_run_seconds) = self.longest_flatline(epsilon);
+
+        FlatlineStruct {
+            values_hash: alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(values_hash),
+            epsilon: f64_to_u256(epsilon),
+            longest_run_count: alloy_sol_types::private::Uint::<256, 4>::from(longest_run_count),
+            longest_run_seconds: alloy_sol_types::private::Uint::<256, 4>::from(
+                longest_run_seconds,
+            ),
+            n: alloy_sol_types::private::Uint::<256, 4>::from(self.values.len()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_longest_flatline_finds_stuck_run() {
+        let ts = TimeSeries::new(
+            vec![0, 60, 120, 180, 240, 300, 360],
+            vec![1.0, 2.0, 2.0, 2.05, 2.0, 3.0, 3.0],
+        );
+        assert_eq!(ts.longest_flatline(0.1), (4, 180));
+        assert_eq!(ts.longest_flatline(0.0), (2, 60));
+    }
+
+    #[test]
+    fn test_longest_flatline_edge_cases() {
+        let flat = TimeSeries::new(vec![10, 20, 30], vec![5.0, 5.0, 5.0]);
+        assert_eq!(flat.longest_flatline(0.0), (3, 20));
+
+        let single = TimeSeries::new(vec![10], vec![5.0]);
+        assert_eq!(single.longest_flatline(0.0), (1, 0));
+
+        let empty = TimeSeries::new(vec![], vec![]);
+        assert_eq!(empty.longest_flatline(1.0), (0, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Epsilon must be non-negative")]
+    fn test_longest_flatline_rejects_negative_epsilon() {
+        TimeSeries::new(vec![1], vec![1.0]).longest_flatline(-1.0);
+    }
+}
@@ -0,0 +1,119 @@
+//! The `f64` methods that `core` lacks, for builds without the `std` feature.
+//!
+//! Without the standard library `f64` has no `sqrt`, `ln` and similar methods. [`Float`] provides
+//! them under the same names through `libm`, so the statistics are written once and compile
+//! either way. With `std` the inherent methods exist and this module is only built for its tests,
+//! which check it against them.
+
+/// `f64` methods from `std`, implemented with `libm`.
+pub(crate) trait Float {
+    fn abs(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn ln(self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+}
+
+impl Float for f64 {
+    fn abs(self) -> f64 {
+        libm::fabs(self)
+    }
+
+    fn sqrt(self) -> f64 {
+        libm::sqrt(self)
+    }
+
+    /// Multiplies by squaring exactly as `std`'s `powi` does, so results match bit for bit.
+    fn powi(self, n: i32) -> f64 {
+        let mut base = self;
+        let mut exponent = n;
+        let mut result = 1.0;
+        loop {
+            if exponent & 1 != 0 {
+                result *= base;
+            }
+            exponent /= 2;
+            if exponent == 0 {
+                break;
+            }
+            base *= base;
+        }
+        if n < 0 {
+            1.0 / result
+        } else {
+            result
+        }
+    }
+
+    fn ln(self) -> f64 {
+        libm::log(self)
+    }
+
+    fn floor(self) -> f64 {
+        libm::floor(self)
+    }
+
+    fn ceil(self) -> f64 {
+        libm::ceil(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Float;
+    use core::hint::black_box;
+
+    const INPUTS: [f64; 14] = [
+        0.0,
+        -0.0,
+        0.5,
+        1.0,
+        -1.5,
+        2.0,
+        core::f64::consts::E,
+        1e-300,
+        123_456.789,
+        -9.87e12,
+        f64::MIN_POSITIVE,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NAN,
+    ];
+
+    /// Checks that both results are NaN or have the same bits, so -0.0 and 0.0 differ.
+    fn assert_same(method: &str, x: f64, ours: f64, std: f64) {
+        assert!(
+            (ours.is_nan() && std.is_nan()) || ours.to_bits() == std.to_bits(),
+            "{method}({x}): {ours} != {std}"
+        );
+    }
+
+    #[test]
+    fn test_unary_methods_match_std() {
+        for x in INPUTS {
+            let x = black_box(x);
+            assert_same("abs", x, <f64 as Float>::abs(x), f64::abs(x));
+            assert_same("sqrt", x, <f64 as Float>::sqrt(x), f64::sqrt(x));
+            assert_same("ln", x, <f64 as Float>::ln(x), f64::ln(x));
+            assert_same("floor", x, <f64 as Float>::floor(x), f64::floor(x));
+            assert_same("ceil", x, <f64 as Float>::ceil(x), f64::ceil(x));
+        }
+    }
+
+    #[test]
+    fn test_powi_matches_std() {
+        let exponents = [0, 1, 2, 3, 7, 10, -1, -2, -7, 100, -100, i32::MAX, i32::MIN];
+        for x in INPUTS {
+            for n in exponents {
+                let (x, n) = (black_box(x), black_box(n));
+                assert_same(
+                    &format!("powi[{n}]"),
+                    x,
+                    <f64 as Float>::powi(x, n),
+                    f64::powi(x, n),
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use crate::prelude::*;
+use crate::{f64_to_u256, vec_f64_to_i256, ForecastIntervalPublicValuesStruct, TimeSeries};
+
+impl TimeSeries {
+    /// Forecasts with simple exponential smoothing and returns prediction bands.
+    ///
+    /// The residual standard deviation `σ` is estimated from the one-step-ahead errors over the
+    /// history, and the `h`-step forecast variance is `σ² (1 + (h - 1) α²)`, so the bands widen
+    /// with the horizon. With fewer than two points there are no residuals and the bands collapse
+    /// onto the point forecast, and an empty series has no level to forecast from, so all three
+    /// results are empty.
+    ///
+    /// # Arguments
+    /// * `alpha` - The smoothing factor (0 <= alpha <= 1)
+    /// * `horizon` - The number of time steps to forecast
+    /// * `z` - The number of standard deviations each band lies from the point forecast, e.g.
+    ///   1.96 for a 95% interval
+    ///
+    /// # Returns
+    /// The point forecast and the lower and upper bands, each covering only the `horizon` future
+    /// timestamps.
+    pub fn ses_forecast_with_interval(
+        &self,
+        alpha: f64,
+        horizon: usize,
+        z: f64,
+    ) -> (TimeSeries, TimeSeries, TimeSeries) {
+        if self.values.is_empty() {
+            return (self.clone(), self.clone(), self.clone());
+        }
+        let smoothed = self.simple_exponential_smoothing(alpha, horizon);
+        let n = self.values.len();
+        let squared_errors: f64 = self.values[1..]
+            .iter()
+            .zip(smoothed.values.iter())
+            .map(|(value, previous_level)| (value - previous_level).powi(2))
+            .sum();
+        let sigma = if n > 1 {
+            (squared_errors / (n - 1) as f64).sqrt()
+        } else {
+            0.0
+        };
+
+        let timestamps = smoothed.timestamps[n..].to_vec();
+        let point = smoothed.values[n..].to_vec();
+        let half_widths: Vec<f64> = (1..=horizon)
+            .map(|h| z * sigma * (1.0 + (h - 1) as f64 * alpha * alpha).sqrt())
+            .collect();
+        let lower = point.iter().zip(&half_widths).map(|(p, w)| p - w).collect();
+        let upper = point.iter().zip(&half_widths).map(|(p, w)| p + w).collect();
+        (
+            TimeSeries::new(timestamps.clone(), point),
+            TimeSeries::new(timestamps.clone(), lower),
+            TimeSeries::new(timestamps, upper),
+        )
+    }
+
+    pub fn to_forecast_interval_public_values(
+        &self,
+        alpha: f64,
+        horizon: usize,
+        z: f64,
+    ) -> ForecastIntervalPublicValuesStruct {
+        let start_timestamp = *self.timestamps.first().unwrap_or(&0);
+        let end_timestamp = *self.timestamps.last().unwrap_or(&0);
+        let values_hash = self.compute_hash();
+        let (point, lower, upper) = self.ses_forecast_with_interval(alpha, horizon, z);
+
+        ForecastIntervalPublicValuesStruct {
+            start_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(start_timestamp),
+            end_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(end_timestamp),
+            values_hash: alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(values_hash),
+            alpha: f64_to_u256(alpha),
+            horizon: alloy_sol_types::private::Uint::<256, 4>::from(horizon),
+            z: f64_to_u256(z),
+            point: vec_f64_to_i256(&point.values),
+            lower: vec_f64_to_i256(&lower.values),
+            upper: vec_f64_to_i256(&upper.values),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_forecast_bands_widen_with_horizon() {
+        let ts = TimeSeries::new(
+            vec![0, 10, 20, 30, 40, 50],
+            vec![10.0, 12.0, 9.0, 11.0, 13.0, 10.0],
+        );
+        let (point, lower, upper) = ts.ses_forecast_with_interval(0.5, 4, 1.96);
+        assert_eq!(point.timestamps, vec![60, 70, 80, 90]);
+        assert_eq!(lower.timestamps, point.timestamps);
+        let widths: Vec<f64> = lower
+            .values
+            .iter()
+            .zip(upper.values.iter())
+            .map(|(l, u)| u - l)
+            .collect();
+        assert!(widths[0] > 0.0);
+        assert!(widths.windows(2).all(|w| w[1] > w[0]));
+        for ((p, l), u) in point.values.iter().zip(&lower.values).zip(&upper.values) {
+            assert!((p - (l + u) / 2.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_forecast_interval_single_point() {
+        let ts = TimeSeries::new(vec![5], vec![3.0]);
+        let (point, lower, upper) = ts.ses_forecast_with_interval(0.5, 2, 1.96);
+        assert_eq!(point.values, vec![3.0, 3.0]);
+        assert_eq!(lower.values, point.values);
+        assert_eq!(upper.values, point.values);
+    }
+
+    #[test]
+    fn test_forecast_interval_empty_series() {
+        let ts = TimeSeries::new(vec![], vec![]);
+        let (point, lower, upper) = ts.ses_forecast_with_interval(0.5, 3, 1.96);
+        assert!(point.values.is_empty() && point.timestamps.is_empty());
+        assert!(lower.values.is_empty());
+        assert!(upper.values.is_empty());
+
+        let public_values = ts.to_forecast_interval_public_values(0.5, 3, 1.96);
+        assert!(public_values.point.is_empty());
+    }
+
+    #[test]
+    fn test_forecast_interval_public_values() {
+        let ts = TimeSeries::new(vec![1, 2, 3], vec![1.0, 3.0, 2.0]);
+        let public_values = ts.to_forecast_interval_public_values(0.3, 3, 2.0);
+        assert_eq!(public_values.point.len(), 3);
+        assert_eq!(public_values.lower.len(), 3);
+        assert_eq!(public_values.upper.len(), 3);
+    }
+}
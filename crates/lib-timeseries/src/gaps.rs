@@ -0,0 +1,161 @@
+use crate::prelude::*;
+use crate::TimeSeries;
+
+impl TimeSeries {
+    /// Returns the typical spacing of the timestamps: the median of the differences between
+    /// consecutive timestamps, averaging the middle two (rounded down) for an even count.
+    ///
+    /// Unlike the first difference alone, a single irregular gap does not change it, which makes
+    /// it a reasonable `interval` for [`TimeSeries::gaps`] when none is known. Timestamps that go
+    /// backwards count as a step of 0. The result is at least 1: a series with fewer than two
+    /// points has no spacing, and one whose median step is 0 because most timestamps repeat
+    /// would stack forecasts on its last timestamp, so both give 1.
+    pub fn median_time_step(&self) -> u64 {
+        let mut steps: Vec<u64> = self
+            .timestamps
+            .windows(2)
+            .map(|pair| pair[1].saturating_sub(pair[0]))
+            .collect();
+        if steps.is_empty() {
+            return 1;
+        }
+        steps.sort_unstable();
+        let mid = steps.len() / 2;
+        let median = if steps.len() % 2 == 0 {
+            steps[mid - 1] + (steps[mid] - steps[mid - 1]) / 2
+        } else {
+            steps[mid]
+        };
+        median.max(1)
+    }
+
+    /// Finds the gaps in a series expected to have a point every `interval`.
+    ///
+    /// A gap is any pair of consecutive points whose timestamps differ by more than `interval`.
+    /// Points are taken in stored order, so the timestamps should be non-decreasing.
+    ///
+    /// # Returns
+    /// The `(before, after)` timestamps of the observed points on either side of each gap, in
+    /// order.
+    ///
+    /// # Panics
+    /// Panics if `interval` is zero.
+    pub fn gaps(&self, interval: u64) -> Vec<(u64, u64)> {
+        assert!(interval > 0, "Interval must be positive");
+        self.timestamps
+            .windows(2)
+            .filter(|pair| pair[1].saturating_sub(pair[0]) > interval)
+            .map(|pair| (pair[0], pair[1]))
+            .collect()
+    }
+
+    /// Fills every gap found by [`TimeSeries::gaps`] with points that repeat the last observed
+    /// value.
+    ///
+    /// A gap after the point at `t` gets points at `t + interval`, `t + 2 * interval`, ... up to
+    /// but excluding the next observed timestamp. Nothing is added before the first point.
+    ///
+    /// # Panics
+    /// Panics if `interval` is zero.
+    pub fn fill_forward(&self, interval: u64) -> TimeSeries {
+        self.fill_gaps(interval, |previous| previous)
+    }
+
+    /// Fills every gap found by [`TimeSeries::gaps`] with points holding `value`, at the same
+    /// timestamps as [`TimeSeries::fill_forward`].
+    ///
+    /// # Panics
+    /// Panics if `interval` is zero.
+    pub fn fill_value(&self, interval: u64, value: f64) -> TimeSeries {
+        self.fill_gaps(interval, |_| value)
+    }
+
+    /// Inserts grid points into each gap, valued by `fill` applied to the value before the gap.
+    fn fill_gaps(&self, interval: u64, fill: impl Fn(f64) -> f64) -> TimeSeries {
+        assert!(interval > 0, "Interval must be positive");
+        let mut timestamps = Vec::with_capacity(self.timestamps.len());
+        let mut values = Vec::with_capacity(self.values.len());
+        for (i, (&timestamp, &value)) in self.timestamps.iter().zip(self.values.iter()).enumerate()
+        {
+            timestamps.push(timestamp);
+            values.push(value);
+            let Some(&next) = self.timestamps.get(i + 1) else {
+                break;
+            };
+            let mut t = timestamp;
+            while let Some(grid) = t.checked_add(interval).filter(|&grid| grid < next) {
+                timestamps.push(grid);
+                values.push(fill(value));
+                t = grid;
+            }
+        }
+        TimeSeries::new(timestamps, values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_median_time_step_ignores_outlier_gap() {
+        let ts = TimeSeries::new(vec![0, 300, 360, 420, 480], vec![1.0; 5]);
+        assert_eq!(ts.median_time_step(), 60);
+        let even = TimeSeries::new(vec![0, 10, 30, 60, 100], vec![1.0; 5]);
+        assert_eq!(even.median_time_step(), 25);
+        assert_eq!(TimeSeries::new(vec![5], vec![1.0]).median_time_step(), 1);
+        // Most steps are duplicates, so the median step is 0.
+        let repeated = TimeSeries::new(vec![10, 10, 10, 20], vec![1.0; 4]);
+        assert_eq!(repeated.median_time_step(), 1);
+    }
+
+    #[test]
+    fn test_back_to_back_gaps() {
+        // Gaps after t = 10 and after t = 40, with no observed point between them but t = 40.
+        let ts = TimeSeries::new(vec![0, 10, 40, 70, 80], vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+        assert_eq!(ts.gaps(10), vec![(10, 40), (40, 70)]);
+
+        let forward = ts.fill_forward(10);
+        assert_eq!(
+            forward.timestamps,
+            (0..=80).step_by(10).collect::<Vec<u64>>()
+        );
+        assert_eq!(
+            forward.values,
+            vec![1.0, 2.0, 2.0, 2.0, 3.0, 3.0, 3.0, 4.0, 5.0]
+        );
+
+        let constant = ts.fill_value(10, 0.0);
+        assert_eq!(constant.timestamps, forward.timestamps);
+        assert_eq!(
+            constant.values,
+            vec![1.0, 2.0, 0.0, 0.0, 3.0, 0.0, 0.0, 4.0, 5.0]
+        );
+    }
+
+    #[test]
+    fn test_gap_at_end_and_off_grid() {
+        let ts = TimeSeries::new(vec![100, 160, 185], vec![1.0, 2.0, 3.0]);
+        assert!(ts.gaps(60).is_empty());
+        assert_eq!(ts.gaps(10), vec![(100, 160), (160, 185)]);
+
+        // The last gap is not a whole number of intervals, so its grid stops short of t = 185.
+        let filled = ts.fill_forward(10);
+        assert_eq!(
+            filled.timestamps,
+            vec![100, 110, 120, 130, 140, 150, 160, 170, 180, 185]
+        );
+        assert_eq!(filled.values[7..], [2.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_fill_without_gaps() {
+        let ts = TimeSeries::new(vec![0, 10, 20], vec![1.0, 2.0, 3.0]);
+        assert!(ts.gaps(10).is_empty());
+        assert_eq!(ts.fill_forward(10).timestamps, ts.timestamps);
+        assert!(TimeSeries::new(vec![], vec![])
+            .fill_value(10, 0.0)
+            .values
+            .is_empty());
+    }
+}
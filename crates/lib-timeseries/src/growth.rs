@@ -0,0 +1,210 @@
+use crate::{
+    detmath, f64_to_i256, f64_to_u256, ExponentialGrowthPublicValuesStruct,
+    ExtendedPublicValuesStruct, LinRegResult, TimeSeries, TimeSeriesError,
+};
+
+/// The number of seconds in a Julian year (365.25 days), used to annualize growth.
+pub const SECONDS_PER_YEAR: f64 = 31_557_600.0;
+
+impl TimeSeries {
+    /// Computes the annualized (compound) growth rate between the first and last observation.
+    ///
+    /// The rate is `(last / first)^(SECONDS_PER_YEAR / elapsed) - 1`, with the elapsed time taken
+    /// from the timestamps in seconds, so irregular spacing in between does not matter. A
+    /// shrinking series has a negative rate (down to -1), so commit it as a signed value. The
+    /// power uses [`detmath::pow`] so the host and the zkVM agree exactly.
+    ///
+    /// # Errors
+    /// Returns [`TimeSeriesError::InsufficientData`] if the series has fewer than two points,
+    /// [`TimeSeriesError::InvalidParameter`] if the first or last value is not positive, and
+    /// [`TimeSeriesError::ZeroElapsedTime`] if the last timestamp is not after the first.
+    pub fn annualized_growth(&self) -> Result<f64, TimeSeriesError> {
+        if self.values.len() < 2 {
+            return Err(TimeSeriesError::InsufficientData {
+                required: 2,
+                actual: self.values.len(),
+            });
+        }
+        let first = self.values[0];
+        let last = self.values[self.values.len() - 1];
+        if first.is_nan() || first <= 0.0 {
+            return Err(TimeSeriesError::InvalidParameter {
+                name: "first value",
+            });
+        }
+        if last.is_nan() || last <= 0.0 {
+            return Err(TimeSeriesError::InvalidParameter { name: "last value" });
+        }
+        let start = self.timestamps[0];
+        let end = self.timestamps[self.timestamps.len() - 1];
+        if end <= start {
+            return Err(TimeSeriesError::ZeroElapsedTime);
+        }
+        let elapsed = (end - start) as f64;
+        Ok(detmath::pow(last / first, SECONDS_PER_YEAR / elapsed) - 1.0)
+    }
+
+    /// Fits the exponential trend `value = exp(a + b * t)` and returns the growth rate `b`.
+    ///
+    /// The fit is a least-squares line through the logarithms of the values against the
+    /// timestamps, so `b` is the continuous growth rate per unit of timestamp (per second for
+    /// Unix timestamps); the series grows by a factor `exp(b * dt)` over `dt`. A shrinking series
+    /// has a negative rate.
+    ///
+    /// # Errors
+    /// Returns [`TimeSeriesError::InsufficientData`] if the series has fewer than two points,
+    /// [`TimeSeriesError::NonPositiveValue`] if a value is not positive, and
+    /// [`TimeSeriesError::ZeroElapsedTime`] if every timestamp is the same.
+    pub fn exponential_growth_rate(&self) -> Result<f64, TimeSeriesError> {
+        Ok(self.exponential_fit()?.slope)
+    }
+
+    /// Fits a line through the logarithms of the values; see
+    /// [`TimeSeries::exponential_growth_rate`].
+    fn exponential_fit(&self) -> Result<LinRegResult, TimeSeriesError> {
+        if self.values.len() < 2 {
+            return Err(TimeSeriesError::InsufficientData {
+                required: 2,
+                actual: self.values.len(),
+            });
+        }
+        if self.timestamps.iter().all(|&t| t == self.timestamps[0]) {
+            return Err(TimeSeriesError::ZeroElapsedTime);
+        }
+        if let Some(index) = self.values.iter().position(|&v| v.is_nan() || v <= 0.0) {
+            return Err(TimeSeriesError::NonPositiveValue { index });
+        }
+        let log_values = self.values.iter().map(|&v| detmath::ln(v)).collect();
+        Ok(TimeSeries::new(self.timestamps.clone(), log_values).linear_regression())
+    }
+
+    /// Commits the exponential growth rate and the R² of the log-linear fit.
+    ///
+    /// # Errors
+    /// Returns the errors of [`TimeSeries::exponential_growth_rate`].
+    pub fn to_exponential_growth_public_values(
+        &self,
+    ) -> Result<ExponentialGrowthPublicValuesStruct, TimeSeriesError> {
+        let start_timestamp = *self.timestamps.first().unwrap_or(&0);
+        let end_timestamp = *self.timestamps.last().unwrap_or(&0);
+        let values_hash = self.compute_hash();
+        let fit = self.exponential_fit()?;
+
+        Ok(ExponentialGrowthPublicValuesStruct {
+            start_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(start_timestamp),
+            end_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(end_timestamp),
+            values_hash: alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(values_hash),
+            growth_rate: f64_to_i256(fit.slope),
+            r_squared: f64_to_u256(fit.r_squared),
+        })
+    }
+
+    /// Commits the summary statistics together with the annualized growth rate.
+    ///
+    /// # Errors
+    /// Returns the errors of [`TimeSeries::annualized_growth`].
+    pub fn to_extended_public_values(&self) -> Result<ExtendedPublicValuesStruct, TimeSeriesError> {
+        let start_timestamp = *self.timestamps.first().unwrap_or(&0);
+        let end_timestamp = *self.timestamps.last().unwrap_or(&0);
+        let values_hash = self.compute_hash();
+        let annualized_growth = self.annualized_growth()?;
+
+        Ok(ExtendedPublicValuesStruct {
+            start_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(start_timestamp),
+            end_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(end_timestamp),
+            values_hash: alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(values_hash),
+            mean: f64_to_u256(self.mean()),
+            median: f64_to_u256(self.median()),
+            std_dev: f64_to_u256(self.std_dev()),
+            annualized_growth: f64_to_i256(annualized_growth),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i256_to_f64;
+
+    const START: u64 = 1_700_000_000;
+
+    #[test]
+    fn test_annualized_growth_doubling_over_one_year() {
+        let year = SECONDS_PER_YEAR as u64;
+        let ts = TimeSeries::new(
+            vec![START, START + 1000, START + year],
+            vec![100.0, 5.0, 200.0],
+        );
+        assert_eq!(ts.annualized_growth(), Ok(1.0));
+    }
+
+    #[test]
+    fn test_annualized_growth_doubling_over_half_a_year() {
+        let half_year = SECONDS_PER_YEAR as u64 / 2;
+        let ts = TimeSeries::new(vec![START, START + half_year], vec![50.0, 100.0]);
+        assert_eq!(ts.annualized_growth(), Ok(3.0));
+
+        let shrinking = TimeSeries::new(vec![START, START + half_year], vec![100.0, 50.0]);
+        let public_values = shrinking.to_extended_public_values().unwrap();
+        assert!(public_values.annualized_growth.is_negative());
+        assert_eq!(i256_to_f64(public_values.annualized_growth), -0.75);
+    }
+
+    #[test]
+    fn test_annualized_growth_errors() {
+        let zero_start = TimeSeries::new(vec![0, 100], vec![0.0, 1.0]);
+        assert_eq!(
+            zero_start.annualized_growth(),
+            Err(TimeSeriesError::InvalidParameter {
+                name: "first value"
+            })
+        );
+        let no_time = TimeSeries::new(vec![100, 100], vec![1.0, 2.0]);
+        assert_eq!(
+            no_time.annualized_growth(),
+            Err(TimeSeriesError::ZeroElapsedTime)
+        );
+        let single = TimeSeries::new(vec![100], vec![1.0]);
+        assert_eq!(
+            single.annualized_growth(),
+            Err(TimeSeriesError::InsufficientData {
+                required: 2,
+                actual: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_exponential_growth_rate_recovers_rate() {
+        let rate = 0.05;
+        let timestamps = vec![0_u64, 1, 2, 5, 6, 10, 11];
+        let values = timestamps
+            .iter()
+            .map(|&t| 3.0 * (rate * t as f64).exp())
+            .collect();
+        let ts = TimeSeries::new(timestamps, values);
+        assert!((ts.exponential_growth_rate().unwrap() - rate).abs() < 1e-12);
+
+        let public_values = ts.to_exponential_growth_public_values().unwrap();
+        assert!((i256_to_f64(public_values.growth_rate) - rate).abs() < 1e-12);
+        assert!((crate::u256_to_f64(public_values.r_squared) - 1.0).abs() < 1e-12);
+
+        let halving = TimeSeries::new(vec![0, 1, 2], vec![8.0, 4.0, 2.0]);
+        let rate = halving.exponential_growth_rate().unwrap();
+        assert!((rate + core::f64::consts::LN_2).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_exponential_growth_rate_errors() {
+        let ts = TimeSeries::new(vec![1, 2, 3], vec![1.0, 0.0, 2.0]);
+        assert_eq!(
+            ts.exponential_growth_rate(),
+            Err(TimeSeriesError::NonPositiveValue { index: 1 })
+        );
+        let ts = TimeSeries::new(vec![5, 5], vec![1.0, 2.0]);
+        assert_eq!(
+            ts.exponential_growth_rate(),
+            Err(TimeSeriesError::ZeroElapsedTime)
+        );
+    }
+}
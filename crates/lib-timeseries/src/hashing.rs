@@ -0,0 +1,192 @@
+use sha2::Sha256;
+use sha3::{Digest, Keccak256};
+
+use crate::prelude::*;
+use crate::{
+    f64_to_u256, f64_to_u256_scaled, DualHashPublicValuesStruct, TimeSeries, TimeSeriesError,
+};
+
+impl TimeSeries {
+    /// Returns the byte string committed to by the values hash.
+    ///
+    /// Every point contributes its timestamp followed by its value, both as big-endian bytes, in
+    /// stored order. The same points stored in a different order therefore hash differently; use
+    /// [`TimeSeries::sorted_by_time`] to canonicalize the order before committing.
+    ///
+    /// The value bytes are the raw IEEE-754 bits, so numerically equal data can hash differently:
+    /// `-0.0` and `+0.0` differ, as do NaNs with different payloads. See
+    /// [`TimeSeries::canonical_hash`] for a hash over the logical values.
+    pub fn hash_preimage(&self) -> Vec<u8> {
+        let mut preimage = Vec::with_capacity(self.values.len() * 16);
+        for (timestamp, value) in self.timestamps.iter().zip(self.values.iter()) {
+            preimage.extend_from_slice(&timestamp.to_be_bytes());
+            preimage.extend_from_slice(&value.to_be_bytes());
+        }
+        preimage
+    }
+
+    /// Computes a Keccak256 hash of the series that depends only on the numeric values.
+    ///
+    /// [`TimeSeries::hash_preimage`] commits the raw IEEE-754 bits, so `-0.0` and `+0.0` hash
+    /// differently, and a verifier must reproduce the exact f64 layout. Here every point instead
+    /// contributes its big-endian timestamp followed by its value as a 32-byte two's-complement
+    /// `int256`, scaled by 1e18 and rounded to the nearest integer (see [`f64_to_u256_scaled`]).
+    /// Both zeros therefore map to the same integer, and an on-chain decoder can rebuild the
+    /// preimage from the committed values.
+    ///
+    /// Unlike [`crate::f64_to_i256`], which saturates at about 3.4e20, the conversion uses the
+    /// full `int256` range, so distinct large values never share an encoding.
+    ///
+    /// # Errors
+    /// Returns [`TimeSeriesError::NonFiniteValue`] for the first NaN or infinite value, since it
+    /// has no fixed-point representation, and [`TimeSeriesError::ValueOutOfRange`] for a value
+    /// whose magnitude is about 5.8e58 or more, which does not fit in an `int256` once scaled.
+    pub fn canonical_hash(&self) -> Result<[u8; 32], TimeSeriesError> {
+        type I256 = alloy_sol_types::private::Signed<256, 4>;
+        self.validate_finite()?;
+        let mut hasher = Keccak256::new();
+        for (index, (timestamp, &value)) in
+            self.timestamps.iter().zip(self.values.iter()).enumerate()
+        {
+            let magnitude = f64_to_u256_scaled(value.abs(), 18)
+                .map(I256::from_raw)
+                .filter(|magnitude| !magnitude.is_negative())
+                .ok_or(TimeSeriesError::ValueOutOfRange { index })?;
+            let scaled = if value < 0.0 { -magnitude } else { magnitude };
+            hasher.update(timestamp.to_be_bytes());
+            hasher.update(scaled.into_raw().to_be_bytes::<32>());
+        }
+        Ok(hasher.finalize().into())
+    }
+
+    /// Computes the Keccak256 and SHA-256 hashes of the series.
+    ///
+    /// Both hashes are computed over the same [`TimeSeries::hash_preimage`], so consumers on
+    /// Keccak-native and SHA-256-native chains commit to byte-identical data.
+    ///
+    /// # Returns
+    /// The Keccak256 hash followed by the SHA-256 hash.
+    pub fn dual_hash(&self) -> ([u8; 32], [u8; 32]) {
+        let preimage = self.hash_preimage();
+        (
+            Keccak256::digest(&preimage).into(),
+            Sha256::digest(&preimage).into(),
+        )
+    }
+
+    pub fn to_dual_hash_public_values(&self) -> DualHashPublicValuesStruct {
+        let start_timestamp = *self.timestamps.first().unwrap_or(&0);
+        let end_timestamp = *self.timestamps.last().unwrap_or(&0);
+        let (values_hash_keccak, values_hash_sha256) = self.dual_hash();
+
+        DualHashPublicValuesStruct {
+            start_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(start_timestamp),
+            end_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(end_timestamp),
+            values_hash_keccak: alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(
+                values_hash_keccak,
+            ),
+            values_hash_sha256: alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(
+                values_hash_sha256,
+            ),
+            mean: f64_to_u256(self.mean()),
+            median: f64_to_u256(self.median()),
+            std_dev: f64_to_u256(self.std_dev()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_preimage_layout() {
+        let ts = TimeSeries::new(vec![1, 2], vec![1.5, -2.0]);
+        let preimage = ts.hash_preimage();
+        assert_eq!(preimage.len(), 32);
+        assert_eq!(&preimage[..8], &1u64.to_be_bytes());
+        assert_eq!(&preimage[8..16], &1.5f64.to_be_bytes());
+        assert_eq!(&preimage[16..24], &2u64.to_be_bytes());
+        assert_eq!(&preimage[24..], &(-2.0f64).to_be_bytes());
+    }
+
+    #[test]
+    fn test_dual_hash_matches_host_computation() {
+        let ts = TimeSeries::new(vec![0, 86400, 172800], vec![0.0, 1.5, 3.0]);
+        let (keccak, sha256) = ts.dual_hash();
+        assert_eq!(keccak, ts.compute_hash());
+        let expected_sha256: [u8; 32] = Sha256::digest(ts.hash_preimage()).into();
+        assert_eq!(sha256, expected_sha256);
+        assert_ne!(keccak, sha256);
+    }
+
+    #[test]
+    fn test_canonical_hash_ignores_sign_of_zero() {
+        let positive = TimeSeries::new(vec![1, 2], vec![0.0, 1.5]);
+        let negative = TimeSeries::new(vec![1, 2], vec![-0.0, 1.5]);
+        assert_ne!(positive.compute_hash(), negative.compute_hash());
+        assert_eq!(positive.canonical_hash(), negative.canonical_hash());
+
+        let other = TimeSeries::new(vec![1, 2], vec![0.0, -1.5]);
+        assert_ne!(positive.canonical_hash(), other.canonical_hash());
+    }
+
+    #[test]
+    fn test_canonical_hash_preimage_is_fixed_point() {
+        let ts = TimeSeries::new(vec![7], vec![-2.5]);
+        let mut preimage = 7u64.to_be_bytes().to_vec();
+        // -2.5e18 as a 32-byte two's-complement integer.
+        preimage.extend_from_slice(&[0xff; 24]);
+        preimage.extend_from_slice(&(-2_500_000_000_000_000_000i64).to_be_bytes());
+        let expected: [u8; 32] = Keccak256::digest(&preimage).into();
+        assert_eq!(ts.canonical_hash(), Ok(expected));
+    }
+
+    #[test]
+    fn test_canonical_hash_distinguishes_large_values() {
+        // Both saturate f64_to_i256, so an encoding through it would collide.
+        let small = TimeSeries::new(vec![1], vec![1e21]);
+        let large = TimeSeries::new(vec![1], vec![1e22]);
+        assert_ne!(small.canonical_hash(), large.canonical_hash());
+
+        let negative = TimeSeries::new(vec![1], vec![-1e22]);
+        assert_ne!(large.canonical_hash(), negative.canonical_hash());
+    }
+
+    #[test]
+    fn test_canonical_hash_rejects_out_of_range_values() {
+        let ts = TimeSeries::new(vec![1, 2, 3], vec![1.0, -1e59, 1e300]);
+        assert_eq!(
+            ts.canonical_hash(),
+            Err(TimeSeriesError::ValueOutOfRange { index: 1 })
+        );
+        assert!(TimeSeries::new(vec![1], vec![-5.7e58])
+            .canonical_hash()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_canonical_hash_rejects_non_finite_values() {
+        let ts = TimeSeries::new(vec![1, 2, 3], vec![1.0, f64::NAN, f64::INFINITY]);
+        assert_eq!(
+            ts.canonical_hash(),
+            Err(TimeSeriesError::NonFiniteValue { index: 1 })
+        );
+    }
+
+    #[test]
+    fn test_dual_hash_public_values() {
+        let ts = TimeSeries::new(vec![0, 86400, 172800], vec![0.0, 1.5, 3.0]);
+        let public_values = ts.to_dual_hash_public_values();
+        let stats = ts.to_public_values().unwrap();
+        let (_, sha256) = ts.dual_hash();
+        assert_eq!(public_values.values_hash_keccak, stats.values_hash);
+        assert_eq!(
+            public_values.values_hash_sha256,
+            alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(sha256)
+        );
+        assert_eq!(public_values.mean, stats.mean);
+        assert_eq!(public_values.median, stats.median);
+        assert_eq!(public_values.std_dev, stats.std_dev);
+    }
+}
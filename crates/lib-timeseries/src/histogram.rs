@@ -0,0 +1,366 @@
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+use crate::{vec_f64_to_i256, HistogramPublicValuesStruct, TimeSeries, TimeSeriesError};
+
+/// Counts of values in bins, computed by [`TimeSeries::histogram`] or
+/// [`TimeSeries::histogram_with_edges`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Histogram {
+    /// The `bins + 1` bin edges, in ascending order.
+    pub edges: Vec<f64>,
+    /// The number of values in each bin.
+    pub counts: Vec<usize>,
+}
+
+impl Histogram {
+    /// Returns the midpoint of the bin holding the most values, the first such bin on a tie, or
+    /// `None` if the histogram counted no values.
+    pub fn mode(&self) -> Option<f64> {
+        let (index, &count) = self
+            .counts
+            .iter()
+            .enumerate()
+            .rev()
+            .max_by_key(|&(_, count)| count)?;
+        (count > 0).then(|| (self.edges[index] + self.edges[index + 1]) / 2.0)
+    }
+
+    /// Returns the Shannon entropy of the bin counts in nats, or `None` if the histogram counted
+    /// no values. Empty bins contribute nothing.
+    pub fn entropy(&self) -> Option<f64> {
+        let total: usize = self.counts.iter().sum();
+        if total == 0 {
+            return None;
+        }
+        let entropy = self
+            .counts
+            .iter()
+            .filter(|&&count| count > 0)
+            .map(|&count| {
+                let p = count as f64 / total as f64;
+                -p * p.ln()
+            })
+            .sum();
+        Some(entropy)
+    }
+}
+
+impl TimeSeries {
+    /// Counts the values in `bins` equal-width bins spanning the smallest to the largest value.
+    ///
+    /// Every bin is half-open, `[edges[i], edges[i + 1])`, except the last, which also holds the
+    /// largest value. When all values are equal the bins have zero width and every value lands in
+    /// the first one. NaN values are not counted; a series with no other values gives empty
+    /// edges and counts.
+    ///
+    /// # Panics
+    /// Panics if `bins` is 0.
+    pub fn histogram(&self, bins: usize) -> Histogram {
+        assert!(bins > 0, "Number of bins must be positive");
+        let values = || self.values.iter().copied().filter(|v| !v.is_nan());
+        let Some(min) = values().reduce(f64::min) else {
+            return Histogram {
+                edges: Vec::new(),
+                counts: Vec::new(),
+            };
+        };
+        let max = values().fold(min, f64::max);
+        let width = max - min;
+
+        let mut edges: Vec<f64> = (0..bins)
+            .map(|i| min + width * i as f64 / bins as f64)
+            .collect();
+        edges.push(max);
+
+        let mut counts = vec![0; bins];
+        for value in values() {
+            let index = if width > 0.0 {
+                (((value - min) / width * bins as f64) as usize).min(bins - 1)
+            } else {
+                0
+            };
+            counts[index] += 1;
+        }
+        Histogram { edges, counts }
+    }
+
+    /// Counts the values in the bins between consecutive `edges`, which the caller fixes in
+    /// advance.
+    ///
+    /// Bins are half-open like those of [`TimeSeries::histogram`], and the last also holds values
+    /// equal to the final edge. Values outside the edges and NaN values are not counted.
+    ///
+    /// # Panics
+    /// Panics if there are fewer than two edges or they are not strictly increasing.
+    pub fn histogram_with_edges(&self, edges: &[f64]) -> Histogram {
+        assert!(edges.len() >= 2, "At least two bin edges are required");
+        assert!(
+            edges.windows(2).all(|pair| pair[0] < pair[1]),
+            "Bin edges must be strictly increasing"
+        );
+        let bins = edges.len() - 1;
+        let mut counts = vec![0; bins];
+        for &value in &self.values {
+            // The number of edges at or below the value; 0 for NaN and values below the first.
+            let index = match edges.partition_point(|&edge| edge <= value) {
+                0 => continue,
+                above if above <= bins => above - 1,
+                _ if value == edges[bins] => bins - 1,
+                _ => continue,
+            };
+            counts[index] += 1;
+        }
+        Histogram {
+            edges: edges.to_vec(),
+            counts,
+        }
+    }
+
+    /// Estimates the mode as the midpoint of the most populated histogram bin.
+    ///
+    /// The number of bins follows Sturges' rule, `ceil(log2(n)) + 1`, computed on integers so
+    /// the host and the zkVM agree. Returns `None` if the series has no values other than NaN.
+    pub fn mode(&self) -> Option<f64> {
+        let n = self.values.iter().filter(|v| !v.is_nan()).count();
+        if n == 0 {
+            return None;
+        }
+        let bins = (usize::BITS - (n - 1).leading_zeros()) as usize + 1;
+        self.histogram(bins).mode()
+    }
+
+    /// Computes the Shannon entropy, in nats, of the values binned by [`TimeSeries::histogram`].
+    ///
+    /// The result ranges from 0, when every value lands in one bin, to `ln(bins)`, when the
+    /// values spread evenly across all bins. Returns `None` if the series has no values other
+    /// than NaN.
+    ///
+    /// # Panics
+    /// Panics if `bins` is 0.
+    pub fn entropy(&self, bins: usize) -> Option<f64> {
+        self.histogram(bins).entropy()
+    }
+
+    /// Computes [`TimeSeries::entropy`] divided by `ln(bins)`, so the result lies in `[0, 1]`
+    /// whatever the number of bins. A single bin always gives 0.
+    ///
+    /// # Panics
+    /// Panics if `bins` is 0.
+    pub fn normalized_entropy(&self, bins: usize) -> Option<f64> {
+        let entropy = self.entropy(bins)?;
+        Some(if bins == 1 {
+            0.0
+        } else {
+            entropy / (bins as f64).ln()
+        })
+    }
+
+    /// Builds the public values of the histogram program, counting the values in the bins
+    /// between the caller's fixed `edges` (see [`TimeSeries::histogram_with_edges`]).
+    ///
+    /// The edges come from the caller rather than the data, since the edges of
+    /// [`TimeSeries::histogram`] start and end at the exact smallest and largest readings. A
+    /// verifier learns the shape of the distribution but not the individual readings. Values
+    /// below the first edge or above the last are committed as the `underflow` and `overflow`
+    /// counts, so the bin counts and those two always add up to the committed `total`.
+    ///
+    /// # Errors
+    /// Returns [`TimeSeriesError::NonFiniteValue`] for a NaN or infinite value, and
+    /// [`TimeSeriesError::InvalidParameter`] unless there are at least two finite, strictly
+    /// increasing edges.
+    pub fn to_histogram_public_values(
+        &self,
+        edges: &[f64],
+    ) -> Result<HistogramPublicValuesStruct, TimeSeriesError> {
+        self.validate_finite()?;
+        if edges.len() < 2
+            || !edges.iter().all(|edge| edge.is_finite())
+            || !edges.windows(2).all(|pair| pair[0] < pair[1])
+        {
+            return Err(TimeSeriesError::InvalidParameter { name: "edges" });
+        }
+        let histogram = self.histogram_with_edges(edges);
+        let (first, last) = (edges[0], edges[edges.len() - 1]);
+        let underflow = self.values.iter().filter(|&&v| v < first).count();
+        let overflow = self.values.iter().filter(|&&v| v > last).count();
+
+        Ok(HistogramPublicValuesStruct {
+            values_hash: alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(
+                self.compute_hash(),
+            ),
+            bin_edges: vec_f64_to_i256(&histogram.edges),
+            counts: histogram
+                .counts
+                .iter()
+                .map(|&count| alloy_sol_types::private::Uint::<256, 4>::from(count))
+                .collect(),
+            total: alloy_sol_types::private::Uint::<256, 4>::from(self.values.len()),
+            underflow: alloy_sol_types::private::Uint::<256, 4>::from(underflow),
+            overflow: alloy_sol_types::private::Uint::<256, 4>::from(overflow),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_histogram_counts_and_edges() {
+        let ts = TimeSeries::new((0..6).collect(), vec![0.0, 1.0, 2.5, 5.0, 9.0, 10.0]);
+        let histogram = ts.histogram(4);
+        assert_eq!(histogram.edges, vec![0.0, 2.5, 5.0, 7.5, 10.0]);
+        // 2.5 and 5.0 sit on inner edges and go to the bin they open; 10.0 is the max edge and
+        // goes to the last bin.
+        assert_eq!(histogram.counts, vec![2, 1, 1, 2]);
+        assert_eq!(histogram.counts.iter().sum::<usize>(), 6);
+    }
+
+    #[test]
+    fn test_histogram_of_constant_series() {
+        let ts = TimeSeries::new(vec![1, 2, 3], vec![4.0, 4.0, 4.0]);
+        let histogram = ts.histogram(3);
+        assert_eq!(histogram.edges, vec![4.0; 4]);
+        assert_eq!(histogram.counts, vec![3, 0, 0]);
+        assert_eq!(ts.mode(), Some(4.0));
+    }
+
+    #[test]
+    fn test_mode_is_midpoint_of_fullest_bin() {
+        // Seven values give ceil(log2(7)) + 1 = 4 bins of width 2 over [0, 8].
+        let ts = TimeSeries::new((0..7).collect(), vec![0.0, 4.5, 5.0, 5.5, 3.0, 7.0, 8.0]);
+        assert_eq!(ts.histogram(4).counts, vec![1, 1, 3, 2]);
+        assert_eq!(ts.mode(), Some(5.0));
+
+        assert_eq!(TimeSeries::new(vec![], vec![]).mode(), None);
+        assert!(TimeSeries::new(vec![1], vec![f64::NAN])
+            .histogram(2)
+            .counts
+            .is_empty());
+    }
+
+    #[test]
+    fn test_entropy_of_constant_and_uniform_series() {
+        let constant = TimeSeries::new(vec![1, 2, 3, 4], vec![4.0; 4]);
+        assert_eq!(constant.entropy(4), Some(0.0));
+        assert_eq!(constant.normalized_entropy(4), Some(0.0));
+
+        // Two values in each of four bins.
+        let uniform = TimeSeries::new(
+            (0..8).collect(),
+            vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 8.0],
+        );
+        assert_eq!(uniform.histogram(4).counts, vec![2, 2, 2, 2]);
+        assert!((uniform.entropy(4).unwrap() - 4f64.ln()).abs() < 1e-12);
+        assert!((uniform.normalized_entropy(4).unwrap() - 1.0).abs() < 1e-12);
+        assert_eq!(uniform.normalized_entropy(1), Some(0.0));
+
+        // Empty bins contribute nothing rather than NaN.
+        let skewed = TimeSeries::new((0..4).collect(), vec![0.0, 0.0, 0.0, 9.0]);
+        assert_eq!(skewed.histogram(3).counts, vec![3, 0, 1]);
+        let expected = -(0.75 * 0.75f64.ln() + 0.25 * 0.25f64.ln());
+        assert!((skewed.entropy(3).unwrap() - expected).abs() < 1e-12);
+
+        assert_eq!(TimeSeries::new(vec![], vec![]).entropy(3), None);
+    }
+
+    #[test]
+    fn test_histogram_with_fixed_edges() {
+        let ts = TimeSeries::new(
+            (0..7).collect(),
+            vec![-3.0, 0.0, 2.5, 5.0, 9.9, 10.0, f64::NAN],
+        );
+        let histogram = ts.histogram_with_edges(&[0.0, 5.0, 10.0]);
+        assert_eq!(histogram.edges, vec![0.0, 5.0, 10.0]);
+        // -3.0 is below the first edge and NaN is never counted; 10.0 is the last edge and goes
+        // to the last bin.
+        assert_eq!(histogram.counts, vec![2, 3]);
+
+        let above = TimeSeries::new(vec![1], vec![10.5]);
+        assert_eq!(above.histogram_with_edges(&[0.0, 10.0]).counts, vec![0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Bin edges must be strictly increasing")]
+    fn test_histogram_with_edges_rejects_unsorted_edges() {
+        TimeSeries::new(vec![1], vec![1.0]).histogram_with_edges(&[0.0, 2.0, 1.0]);
+    }
+
+    #[test]
+    fn test_histogram_public_values() {
+        // The edges are the caller's, so neither -1.0 nor 1.0 ends up in the commitment.
+        let ts = TimeSeries::new((0..4).collect(), vec![-1.0, 0.0, 0.5, 1.0]);
+        let public_values = ts.to_histogram_public_values(&[-2.0, 0.0, 2.0]).unwrap();
+        assert_eq!(public_values.bin_edges, vec_f64_to_i256(&[-2.0, 0.0, 2.0]));
+        assert_eq!(
+            public_values.counts,
+            vec![
+                alloy_sol_types::private::Uint::<256, 4>::from(1u64),
+                alloy_sol_types::private::Uint::<256, 4>::from(3u64),
+            ]
+        );
+        assert_eq!(
+            public_values.total,
+            alloy_sol_types::private::Uint::<256, 4>::from(4u64)
+        );
+        assert_eq!(
+            public_values.underflow,
+            alloy_sol_types::private::Uint::ZERO
+        );
+        assert_eq!(public_values.overflow, alloy_sol_types::private::Uint::ZERO);
+    }
+
+    #[test]
+    fn test_histogram_public_values_count_out_of_range_values() {
+        let ts = TimeSeries::new((0..5).collect(), vec![-3.0, 1.0, 5.0, 10.0, 12.0]);
+        let public_values = ts.to_histogram_public_values(&[0.0, 5.0, 10.0]).unwrap();
+        assert_eq!(
+            public_values.counts,
+            vec![
+                alloy_sol_types::private::Uint::<256, 4>::from(1u64),
+                alloy_sol_types::private::Uint::<256, 4>::from(2u64),
+            ]
+        );
+        // -3.0 falls below the first edge and 12.0 above the last.
+        assert_eq!(
+            public_values.total,
+            alloy_sol_types::private::Uint::<256, 4>::from(5u64)
+        );
+        assert_eq!(
+            public_values.underflow,
+            alloy_sol_types::private::Uint::<256, 4>::from(1u64)
+        );
+        assert_eq!(
+            public_values.overflow,
+            alloy_sol_types::private::Uint::<256, 4>::from(1u64)
+        );
+    }
+
+    #[test]
+    fn test_histogram_public_values_errors() {
+        let edges = [0.0, 5.0, 10.0];
+        for (index, value) in [(1, f64::NAN), (1, f64::INFINITY), (1, f64::NEG_INFINITY)] {
+            let ts = TimeSeries::new(vec![1, 2, 3], vec![1.0, value, 3.0]);
+            assert_eq!(
+                ts.to_histogram_public_values(&edges).err(),
+                Some(TimeSeriesError::NonFiniteValue { index })
+            );
+        }
+
+        let ts = TimeSeries::new(vec![1, 2], vec![1.0, 2.0]);
+        for edges in [
+            &[0.0][..],
+            &[0.0, 2.0, 1.0],
+            &[0.0, f64::NAN],
+            &[0.0, f64::INFINITY],
+        ] {
+            assert_eq!(
+                ts.to_histogram_public_values(edges).err(),
+                Some(TimeSeriesError::InvalidParameter { name: "edges" })
+            );
+        }
+    }
+}
@@ -0,0 +1,92 @@
+#[cfg(not(any(feature = "std", test)))]
+use crate::prelude::*;
+use crate::{f64_to_i256, TimeSeries, WelchTPublicValuesStruct};
+
+impl TimeSeries {
+    /// Computes Welch's t statistic between the values before and after a split point.
+    ///
+    /// The statistic is `(mean_after - mean_before) / sqrt(var_before / n_before + var_after /
+    /// n_after)` with sample variances, so it does not assume the two segments share a variance.
+    /// A positive statistic means the level rose after the split. If both segments are constant
+    /// the statistic is infinite (or NaN if their means are also equal).
+    ///
+    /// # Arguments
+    /// * `split_index` - The index of the first value after the split
+    ///
+    /// # Panics
+    /// Panics if either segment has fewer than two points.
+    pub fn welch_t_statistic(&self, split_index: usize) -> f64 {
+        assert!(
+            split_index >= 2 && split_index + 2 <= self.values.len(),
+            "Each segment must have at least two points"
+        );
+        let (before, after) = self.values.split_at(split_index);
+        let (mean_before, var_before) = mean_and_sample_variance(before);
+        let (mean_after, var_after) = mean_and_sample_variance(after);
+        let standard_error =
+            (var_before / before.len() as f64 + var_after / after.len() as f64).sqrt();
+        (mean_after - mean_before) / standard_error
+    }
+
+    pub fn to_welch_t_public_values(&self, split_index: usize) -> WelchTPublicValuesStruct {
+        let start_timestamp = *self.timestamps.first().unwrap_or(&0);
+        let end_timestamp = *self.timestamps.last().unwrap_or(&0);
+        let values_hash = self.compute_hash();
+        let t_statistic = self.welch_t_statistic(split_index);
+
+        WelchTPublicValuesStruct {
+            start_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(start_timestamp),
+            end_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(end_timestamp),
+            values_hash: alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(values_hash),
+            split_index: alloy_sol_types::private::Uint::<256, 4>::from(split_index),
+            split_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(
+                self.timestamps[split_index],
+            ),
+            t_statistic: f64_to_i256(t_statistic),
+        }
+    }
+}
+
+/// Returns the mean and the sample (n - 1) variance of a slice.
+fn mean_and_sample_variance(values: &[f64]) -> (f64, f64) {
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    (mean, variance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_welch_t_statistic_detects_shift() {
+        let ts = TimeSeries::new(
+            (0..8).collect(),
+            vec![1.0, 2.0, 3.0, 2.0, 11.0, 12.0, 10.0, 11.0],
+        );
+        // Means 2 and 11, sample variances 2/3 and 2/3.
+        let expected = 9.0 / (2.0f64 / 3.0 / 4.0 * 2.0).sqrt();
+        let t = ts.welch_t_statistic(4);
+        assert!((t - expected).abs() < 1e-10);
+        assert!(t > 10.0);
+    }
+
+    #[test]
+    fn test_welch_t_public_values() {
+        let ts = TimeSeries::new(vec![10, 20, 30, 40], vec![5.0, 6.0, 2.0, 1.0]);
+        let public_values = ts.to_welch_t_public_values(2);
+        assert!(public_values.t_statistic.is_negative());
+        assert_eq!(
+            public_values.split_timestamp,
+            alloy_sol_types::private::Uint::<256, 4>::from(30u64)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Each segment must have at least two points")]
+    fn test_welch_t_rejects_short_segment() {
+        let ts = TimeSeries::new(vec![1, 2, 3], vec![1.0, 2.0, 3.0]);
+        ts.welch_t_statistic(1);
+    }
+}
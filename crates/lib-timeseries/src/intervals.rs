@@ -0,0 +1,242 @@
+use sha3::{Digest, Keccak256};
+
+use crate::prelude::*;
+use crate::{f64_to_u256, ClaimStruct, ExclusionPublicValuesStruct, TimeSeries};
+
+/// Normalizes a list of half-open time ranges `[start, end)`.
+///
+/// The result is sorted by start, with overlapping and touching ranges merged and empty ranges
+/// (`start >= end`) dropped, so equivalent exclusion lists always normalize to the same ranges.
+pub fn normalize_ranges(ranges: &[(u64, u64)]) -> Vec<(u64, u64)> {
+    let mut sorted: Vec<(u64, u64)> = ranges
+        .iter()
+        .copied()
+        .filter(|(start, end)| start < end)
+        .collect();
+    sorted.sort_unstable();
+
+    let mut normalized: Vec<(u64, u64)> = Vec::with_capacity(sorted.len());
+    for (start, end) in sorted {
+        match normalized.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => normalized.push((start, end)),
+        }
+    }
+    normalized
+}
+
+/// Computes a Keccak256 hash of the normalized ranges.
+///
+/// Every range contributes its start followed by its end, both as big-endian bytes.
+pub fn hash_ranges(ranges: &[(u64, u64)]) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    for (start, end) in normalize_ranges(ranges) {
+        hasher.update(start.to_be_bytes());
+        hasher.update(end.to_be_bytes());
+    }
+    hasher.finalize().into()
+}
+
+/// Returns the total duration covered by the ranges, counting overlaps once.
+pub fn total_duration(ranges: &[(u64, u64)]) -> u64 {
+    normalize_ranges(ranges)
+        .iter()
+        .map(|(start, end)| end - start)
+        .sum()
+}
+
+/// Subtracts one set of half-open time ranges from another.
+///
+/// Returns the normalized parts of `ranges` not covered by any range in `remove`. Ranges that
+/// only touch, such as `[10, 20)` and `[20, 30)`, do not overlap, so nothing is removed.
+pub fn subtract_ranges(ranges: &[(u64, u64)], remove: &[(u64, u64)]) -> Vec<(u64, u64)> {
+    let remove = normalize_ranges(remove);
+    let mut remaining = Vec::new();
+    for (mut start, end) in normalize_ranges(ranges) {
+        // Skip the removed ranges that end before this one starts.
+        let first = remove.partition_point(|&(_, removed_end)| removed_end <= start);
+        for &(removed_start, removed_end) in &remove[first..] {
+            if removed_start >= end {
+                break;
+            }
+            if removed_start > start {
+                remaining.push((start, removed_start));
+            }
+            start = start.max(removed_end);
+        }
+        if start < end {
+            remaining.push((start, end));
+        }
+    }
+    remaining
+}
+
+impl TimeSeries {
+    /// Computes how much of the series' time range is not yet covered by claimed ranges.
+    ///
+    /// The series covers the half-open range from its earliest to its latest timestamp, so a
+    /// provider whose data starts exactly where a claimed range ends overlaps nothing. The
+    /// committed duration is the length of that range minus its overlap with the claimed
+    /// ranges; it equals `new_end - new_start` exactly when the claim is disjoint.
+    ///
+    /// # Arguments
+    /// * `claimed` - Previously claimed half-open time ranges `[start, end)`
+    pub fn to_claim_public_values(&self, claimed: &[(u64, u64)]) -> ClaimStruct {
+        let new_start = self.timestamps.iter().copied().min().unwrap_or(0);
+        let new_end = self.timestamps.iter().copied().max().unwrap_or(0);
+        let values_hash = self.compute_hash();
+        let unclaimed = subtract_ranges(&[(new_start, new_end)], claimed);
+
+        ClaimStruct {
+            values_hash: alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(values_hash),
+            new_start: alloy_sol_types::private::Uint::<256, 4>::from(new_start),
+            new_end: alloy_sol_types::private::Uint::<256, 4>::from(new_end),
+            claimed_ranges_hash: alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(
+                hash_ranges(claimed),
+            ),
+            non_overlapping_seconds: alloy_sol_types::private::Uint::<256, 4>::from(
+                total_duration(&unclaimed),
+            ),
+            n: alloy_sol_types::private::Uint::<256, 4>::from(self.values.len()),
+        }
+    }
+
+    /// Returns the points whose timestamps fall outside every range.
+    ///
+    /// # Arguments
+    /// * `ranges` - Half-open time ranges `[start, end)` to exclude, in any order and possibly
+    ///   overlapping
+    pub fn exclude_ranges(&self, ranges: &[(u64, u64)]) -> TimeSeries {
+        let ranges = normalize_ranges(ranges);
+        let (timestamps, values) = self
+            .timestamps
+            .iter()
+            .zip(self.values.iter())
+            .filter(|(&t, _)| {
+                // The last range starting at or before `t` is the only one that can contain it.
+                let candidate = ranges.partition_point(|&(start, _)| start <= t);
+                candidate == 0 || t >= ranges[candidate - 1].1
+            })
+            .map(|(&t, &v)| (t, v))
+            .unzip();
+        TimeSeries::new(timestamps, values)
+    }
+
+    pub fn to_exclusion_public_values(&self, ranges: &[(u64, u64)]) -> ExclusionPublicValuesStruct {
+        let start_timestamp = *self.timestamps.first().unwrap_or(&0);
+        let end_timestamp = *self.timestamps.last().unwrap_or(&0);
+        let values_hash = self.compute_hash();
+        let filtered = self.exclude_ranges(ranges);
+
+        ExclusionPublicValuesStruct {
+            start_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(start_timestamp),
+            end_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(end_timestamp),
+            values_hash: alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(values_hash),
+            filtered_values_hash: alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(
+                filtered.compute_hash(),
+            ),
+            exclusions_hash: alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(hash_ranges(
+                ranges,
+            )),
+            excluded_duration: alloy_sol_types::private::Uint::<256, 4>::from(total_duration(
+                ranges,
+            )),
+            mean: f64_to_u256(filtered.mean()),
+            median: f64_to_u256(filtered.median()),
+            std_dev: f64_to_u256(filtered.std_dev()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_ranges() {
+        let ranges = [(50, 60), (10, 20), (15, 30), (30, 35), (40, 40), (70, 65)];
+        assert_eq!(normalize_ranges(&ranges), vec![(10, 35), (50, 60)]);
+        assert_eq!(total_duration(&ranges), 35);
+        assert_eq!(hash_ranges(&ranges), hash_ranges(&[(10, 35), (50, 60)]));
+    }
+
+    #[test]
+    fn test_exclude_ranges_is_half_open() {
+        let ts = TimeSeries::new(vec![0, 10, 20, 30, 40], vec![1.0, 100.0, 100.0, 2.0, 3.0]);
+        let filtered = ts.exclude_ranges(&[(20, 30), (10, 15)]);
+        assert_eq!(filtered.timestamps, vec![0, 30, 40]);
+        assert_eq!(filtered.mean(), 2.0);
+    }
+
+    #[test]
+    fn test_exclusion_public_values() {
+        let ts = TimeSeries::new(vec![0, 10, 20, 30], vec![1.0, 50.0, 3.0, 5.0]);
+        let public_values = ts.to_exclusion_public_values(&[(5, 15)]);
+        assert_eq!(public_values.mean, f64_to_u256(3.0));
+        assert_eq!(
+            public_values.excluded_duration,
+            alloy_sol_types::private::Uint::<256, 4>::from(10u64)
+        );
+        assert_eq!(
+            public_values.values_hash,
+            ts.to_public_values().unwrap().values_hash
+        );
+        assert_ne!(
+            public_values.filtered_values_hash,
+            public_values.values_hash
+        );
+    }
+
+    #[test]
+    fn test_subtract_adjacent_ranges() {
+        assert_eq!(subtract_ranges(&[(10, 20)], &[(20, 30)]), vec![(10, 20)]);
+        assert_eq!(subtract_ranges(&[(10, 20)], &[(0, 10)]), vec![(10, 20)]);
+    }
+
+    #[test]
+    fn test_subtract_nested_ranges() {
+        assert_eq!(
+            subtract_ranges(&[(0, 100)], &[(20, 30), (50, 60)]),
+            vec![(0, 20), (30, 50), (60, 100)]
+        );
+        assert_eq!(subtract_ranges(&[(20, 30)], &[(0, 100)]), vec![]);
+        assert_eq!(subtract_ranges(&[(0, 100)], &[(0, 100)]), vec![]);
+    }
+
+    #[test]
+    fn test_subtract_partially_overlapping_ranges() {
+        assert_eq!(
+            subtract_ranges(&[(10, 30), (25, 50)], &[(40, 60), (0, 15)]),
+            vec![(15, 40)]
+        );
+        assert_eq!(subtract_ranges(&[(10, 30)], &[]), vec![(10, 30)]);
+    }
+
+    #[test]
+    fn test_claim_public_values() {
+        let ts = TimeSeries::new(vec![100, 150, 200], vec![1.0, 2.0, 3.0]);
+
+        let disjoint = ts.to_claim_public_values(&[(0, 100), (200, 300)]);
+        assert_eq!(
+            disjoint.non_overlapping_seconds,
+            alloy_sol_types::private::Uint::<256, 4>::from(100u64)
+        );
+        assert_eq!(
+            disjoint.n,
+            alloy_sol_types::private::Uint::<256, 4>::from(3u64)
+        );
+
+        let overlapping = ts.to_claim_public_values(&[(120, 140), (180, 250)]);
+        assert_eq!(
+            overlapping.non_overlapping_seconds,
+            alloy_sol_types::private::Uint::<256, 4>::from(60u64)
+        );
+        assert_eq!(
+            overlapping.claimed_ranges_hash,
+            alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(hash_ranges(&[
+                (180, 250),
+                (120, 140)
+            ]))
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use std::io::{BufRead, BufReader, Read};
+
+use crate::{TimeSeries, TimeSeriesError};
+
+/// Options for [`TimeSeries::from_csv_reader_with_options`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CsvOptions {
+    /// The character separating the timestamp from the value.
+    pub delimiter: char,
+    /// Whether the first non-blank line is a header to skip.
+    pub has_header: bool,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        CsvOptions {
+            delimiter: ',',
+            has_header: false,
+        }
+    }
+}
+
+impl TimeSeries {
+    /// Serializes the series as `{"timestamps": [...], "values": [...]}`.
+    ///
+    /// JSON has no NaN or infinity, so such values are written as `null` and
+    /// [`TimeSeries::from_json`] rejects them.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("a time series always serializes")
+    }
+
+    /// Parses a series written by [`TimeSeries::to_json`].
+    ///
+    /// # Errors
+    /// Returns [`TimeSeriesError::Parse`] if the JSON is malformed, a field is missing or has the
+    /// wrong type, or the two arrays differ in length.
+    pub fn from_json(json: &str) -> Result<TimeSeries, TimeSeriesError> {
+        serde_json::from_str(json).map_err(|e| TimeSeriesError::Parse {
+            line: e.line(),
+            message: e.to_string(),
+        })
+    }
+
+    /// Reads a two-column `timestamp,value` CSV with no header.
+    ///
+    /// See [`TimeSeries::from_csv_reader_with_options`] for the format.
+    pub fn from_csv_reader<R: Read>(reader: R) -> Result<TimeSeries, TimeSeriesError> {
+        TimeSeries::from_csv_reader_with_options(reader, CsvOptions::default())
+    }
+
+    /// Reads a two-column CSV of Unix timestamps and values.
+    ///
+    /// Every non-blank line must hold exactly two fields: an unsigned integer timestamp and a
+    /// floating-point value. Fields are trimmed, blank lines are skipped, and rows are kept in
+    /// file order.
+    ///
+    /// # Errors
+    /// Returns [`TimeSeriesError::Parse`] with the 1-based line number of the first row that
+    /// cannot be read or parsed.
+    pub fn from_csv_reader_with_options<R: Read>(
+        reader: R,
+        options: CsvOptions,
+    ) -> Result<TimeSeries, TimeSeriesError> {
+        let mut timestamps = Vec::new();
+        let mut values = Vec::new();
+        let mut header_pending = options.has_header;
+        for (index, line) in BufReader::new(reader).lines().enumerate() {
+            let line_number = index + 1;
+            let parse_error = |message: String| TimeSeriesError::Parse {
+                line: line_number,
+                message,
+            };
+            let line = line.map_err(|e| parse_error(e.to_string()))?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            if header_pending {
+                header_pending = false;
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split(options.delimiter).map(str::trim).collect();
+            let [timestamp, value] = fields[..] else {
+                return Err(parse_error(format!(
+                    "expected 2 fields, found {}",
+                    fields.len()
+                )));
+            };
+            timestamps.push(
+                timestamp
+                    .parse()
+                    .map_err(|e| parse_error(format!("invalid timestamp `{timestamp}`: {e}")))?,
+            );
+            values.push(
+                value
+                    .parse()
+                    .map_err(|e| parse_error(format!("invalid value `{value}`: {e}")))?,
+            );
+        }
+        Ok(TimeSeries::new(timestamps, values))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_csv_with_header_and_blank_lines() {
+        let csv = "timestamp,value\n1700000000, 1.5\n\n1700000060,-2\n  \n1700000120,3e2\n";
+        let options = CsvOptions {
+            has_header: true,
+            ..CsvOptions::default()
+        };
+        let ts = TimeSeries::from_csv_reader_with_options(csv.as_bytes(), options).unwrap();
+        assert_eq!(ts.timestamps, vec![1700000000, 1700000060, 1700000120]);
+        assert_eq!(ts.values, vec![1.5, -2.0, 300.0]);
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let ts = TimeSeries::new(vec![1, 2, 3], vec![1.5, -2.0, 0.1]);
+        let json = ts.to_json();
+        assert_eq!(json, r#"{"timestamps":[1,2,3],"values":[1.5,-2.0,0.1]}"#);
+        let parsed = TimeSeries::from_json(&json).unwrap();
+        assert_eq!(parsed.timestamps, ts.timestamps);
+        assert_eq!(parsed.values, ts.values);
+    }
+
+    #[test]
+    fn test_from_json_rejects_mismatched_lengths() {
+        let error = TimeSeries::from_json(r#"{"timestamps": [1, 2, 3], "values": [1.0, 2.0]}"#)
+            .unwrap_err();
+        let TimeSeriesError::Parse { message, .. } = error else {
+            panic!("expected a parse error, got {error:?}");
+        };
+        assert!(message.starts_with("length mismatch"), "{message}");
+
+        assert!(TimeSeries::from_json(r#"{"timestamps": [1]}"#).is_err());
+        assert!(TimeSeries::from_json(r#"{"timestamps": [1], "values": [null]}"#).is_err());
+    }
+
+    #[test]
+    fn test_from_csv_with_delimiter() {
+        let options = CsvOptions {
+            delimiter: ';',
+            has_header: false,
+        };
+        let ts =
+            TimeSeries::from_csv_reader_with_options("1;2.5\n2;3.5".as_bytes(), options).unwrap();
+        assert_eq!(ts.timestamps, vec![1, 2]);
+        assert_eq!(ts.values, vec![2.5, 3.5]);
+    }
+
+    #[test]
+    fn test_from_csv_reports_malformed_row() {
+        let csv = "1,1.0\n2,2.0\n3,abc\n4,4.0\n";
+        let error = TimeSeries::from_csv_reader(csv.as_bytes()).unwrap_err();
+        assert!(matches!(error, TimeSeriesError::Parse { line: 3, .. }));
+        assert_eq!(
+            error.to_string(),
+            "parse error on line 3: invalid value `abc`: invalid float literal"
+        );
+
+        let error = TimeSeries::from_csv_reader("1,1.0\n2\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            error,
+            TimeSeriesError::Parse {
+                line: 2,
+                message: "expected 2 fields, found 1".to_string()
+            }
+        );
+
+        // Without a header option, a header row is a parse error on line 1.
+        let error = TimeSeries::from_csv_reader("timestamp,value\n1,1.0\n".as_bytes()).unwrap_err();
+        assert!(matches!(error, TimeSeriesError::Parse { line: 1, .. }));
+    }
+}
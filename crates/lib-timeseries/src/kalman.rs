@@ -0,0 +1,160 @@
+use crate::prelude::*;
+use crate::{f64_to_u256, KalmanPublicValuesStruct, TimeSeries};
+
+impl TimeSeries {
+    /// Runs a local-level Kalman filter forward over the values.
+    ///
+    /// The model is a hidden level that moves by a random walk with variance `process_var` per
+    /// step, observed with noise of variance `measurement_var`. Each output is the estimate of
+    /// the level given the values up to that point, so it lags behind turns in the series; see
+    /// [`TimeSeries::kalman_smooth`] for estimates that use the whole series.
+    ///
+    /// The first value seeds the level with variance `measurement_var`. A NaN value is treated as
+    /// a missing measurement: the estimate carries over with its variance grown by
+    /// `process_var`.
+    ///
+    /// # Panics
+    /// Panics if either variance is not positive.
+    pub fn kalman_filter(&self, process_var: f64, measurement_var: f64) -> TimeSeries {
+        let (level, _) = self.kalman_forward(process_var, measurement_var);
+        TimeSeries::new(self.timestamps.clone(), level)
+    }
+
+    /// Smooths the values with a local-level Kalman filter followed by a Rauch-Tung-Striebel
+    /// backward pass, so each estimate draws on the values after it as well as before.
+    ///
+    /// The ratio of the variances sets the smoothing: as `measurement_var` approaches 0 the
+    /// output follows the input exactly, and as `process_var` approaches 0 it flattens to the
+    /// mean of the series. The model is described in [`TimeSeries::kalman_filter`].
+    ///
+    /// # Panics
+    /// Panics if either variance is not positive.
+    pub fn kalman_smooth(&self, process_var: f64, measurement_var: f64) -> TimeSeries {
+        let (mut level, variance) = self.kalman_forward(process_var, measurement_var);
+        for t in (0..level.len().saturating_sub(1)).rev() {
+            let gain = variance[t] / (variance[t] + process_var);
+            level[t] += gain * (level[t + 1] - level[t]);
+        }
+        TimeSeries::new(self.timestamps.clone(), level)
+    }
+
+    /// Returns the filtered level after each point and its variance.
+    fn kalman_forward(&self, process_var: f64, measurement_var: f64) -> (Vec<f64>, Vec<f64>) {
+        assert!(process_var > 0.0, "Process variance must be positive");
+        assert!(
+            measurement_var > 0.0,
+            "Measurement variance must be positive"
+        );
+        let n = self.values.len();
+        let mut level = Vec::with_capacity(n);
+        let mut variance = Vec::with_capacity(n);
+        let mut x = f64::NAN;
+        let mut p = f64::INFINITY;
+        for &value in &self.values {
+            if x.is_nan() {
+                // Until the first measurement there is no level to carry over.
+                if !value.is_nan() {
+                    x = value;
+                    p = measurement_var;
+                }
+            } else {
+                p += process_var;
+                if !value.is_nan() {
+                    let gain = p / (p + measurement_var);
+                    x += gain * (value - x);
+                    p *= 1.0 - gain;
+                }
+            }
+            level.push(x);
+            variance.push(p);
+        }
+        (level, variance)
+    }
+
+    /// Builds the public values of the Kalman smoothing program, committing the hash of the
+    /// smoothed series alongside the hash of the input.
+    ///
+    /// # Panics
+    /// Panics if either variance is not positive.
+    pub fn to_kalman_public_values(
+        &self,
+        process_var: f64,
+        measurement_var: f64,
+    ) -> KalmanPublicValuesStruct {
+        let start_timestamp = *self.timestamps.first().unwrap_or(&0);
+        let end_timestamp = *self.timestamps.last().unwrap_or(&0);
+        let smoothed = self.kalman_smooth(process_var, measurement_var);
+
+        KalmanPublicValuesStruct {
+            start_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(start_timestamp),
+            end_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(end_timestamp),
+            values_hash: alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(
+                self.compute_hash(),
+            ),
+            process_var: f64_to_u256(process_var),
+            measurement_var: f64_to_u256(measurement_var),
+            smoothed_hash: alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(
+                smoothed.compute_hash(),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn altitude() -> TimeSeries {
+        TimeSeries::new(
+            (0..8).map(|i| i * 5).collect(),
+            vec![102.0, 98.5, 101.0, 105.5, 103.0, 99.0, 104.0, 100.5],
+        )
+    }
+
+    #[test]
+    fn test_kalman_follows_input_without_measurement_noise() {
+        let ts = altitude();
+        let smoothed = ts.kalman_smooth(1.0, 1e-12);
+        assert_eq!(smoothed.timestamps, ts.timestamps);
+        for (s, v) in smoothed.values.iter().zip(&ts.values) {
+            assert!((s - v).abs() < 1e-6);
+        }
+        for (f, v) in ts.kalman_filter(1.0, 1e-12).values.iter().zip(&ts.values) {
+            assert!((f - v).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_kalman_flattens_without_process_noise() {
+        let ts = altitude();
+        let mean = ts.mean();
+        for s in ts.kalman_smooth(1e-12, 1.0).values {
+            assert!((s - mean).abs() < 1e-6);
+        }
+        // The filter alone gives the running mean, which only reaches the mean at the end.
+        let filtered = ts.kalman_filter(1e-12, 1.0);
+        assert!((filtered.values[1] - 100.25).abs() < 1e-6);
+        assert!((filtered.values[7] - mean).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_kalman_smooth_reduces_noise_and_skips_nan() {
+        let ts = altitude();
+        let smoothed = ts.kalman_smooth(0.5, 4.0);
+        assert!(smoothed.std_dev() < ts.std_dev());
+
+        let gappy = TimeSeries::new(vec![0, 1, 2], vec![f64::NAN, 1.0, f64::NAN]);
+        assert_eq!(gappy.kalman_smooth(1.0, 1.0).values[1..], [1.0, 1.0]);
+        assert!(gappy.kalman_filter(1.0, 1.0).values[0].is_nan());
+        assert!(TimeSeries::new(vec![], vec![])
+            .kalman_smooth(1.0, 1.0)
+            .values
+            .is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Measurement variance must be positive")]
+    fn test_kalman_rejects_zero_variance() {
+        altitude().kalman_smooth(1.0, 0.0);
+    }
+}
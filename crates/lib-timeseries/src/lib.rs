@@ -1,14 +1,177 @@
+//! Time series statistics whose results can be committed as the public values of SP1 proofs.
+//!
+//! Each `to_*_public_values` method on [`TimeSeries`] builds the ABI struct a guest program
+//! commits, so a host can recompute and check what a proof claims.
+//!
+//! # Features
+//! * `std` (default) - The standard library and serde support for the public types. Without
+//!   it the crate is `no_std` + `alloc`, using `libm` for the float functions, which keeps the
+//!   guest programs that only pass numbers in lean. `io`, `rayon`, `verify-client` and `wasm`
+//!   all enable it.
+//! * `io` - Loaders for CSV and JSON time series files, for host-side tools.
+//! * `primitive-types` - Conversions between committed `uint256` values and `U256`.
+//! * `rayon` - Multi-threaded statistics such as `TimeSeries::mean_par` and `batch_summary`,
+//!   for large host-side datasets. Guests never enable it.
+//! * `verify-client` - Helpers for services that verify proofs and decode their public values.
+//! * `wasm` - `wasm-bindgen` bindings in the `wasm` module, for previewing statistics in a
+//!   browser before proving them. The crate builds for `wasm32-unknown-unknown` with or without
+//!   this feature; CI checks
+//!   `cargo build -p lib-timeseries --target wasm32-unknown-unknown --features wasm`.
+#![cfg_attr(not(feature = "std"), no_std)]
+#![warn(clippy::unwrap_used)]
+
+#[macro_use]
+extern crate alloc;
+
 use alloy_sol_types::sol;
+#[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 use sha3::{Digest, Keccak256};
 
+use crate::prelude::*;
+
+mod align;
+mod anomaly;
+mod ar;
+mod autocorrelation;
+mod backtest;
+mod bollinger;
+mod builder;
+mod checks;
+mod chunks;
+mod combine;
+mod compliance;
+#[cfg(feature = "io")]
+pub mod conformance;
+mod correlation;
+mod cumulative;
+mod cusum;
+pub mod detmath;
+mod differencing;
+mod drawdown;
+mod ecdf;
+mod ema;
+mod error;
+mod fill_nan;
+mod flatline;
+#[cfg(any(not(feature = "std"), test))]
+mod float;
+mod forecast;
+mod gaps;
+mod growth;
+mod hashing;
+mod histogram;
+mod hypothesis;
+mod intervals;
+#[cfg(feature = "io")]
+mod io;
+mod kalman;
+mod macd;
+mod means;
+mod merkle;
+mod moments;
+mod order_stats;
+mod ordering;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod peaks;
+mod piecewise;
+mod prelude;
+mod regression;
+mod resample;
+mod returns;
+mod robust;
+mod rolling;
+mod runs;
+mod scaling;
+mod seasonal;
+mod shift;
+mod slicing;
+mod spectral;
+mod summary;
+mod time_unit;
+mod transform;
+mod twap;
+#[cfg(feature = "verify-client")]
+pub mod verify_client;
+mod vwap;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+mod weighted;
+
+pub use align::AlignMode;
+pub use ar::ArModel;
+pub use backtest::{ForecastErrors, ForecastMethod};
+pub use builder::{
+    MonotonicTimeSeriesBuilder, MovingAveragePublicValues, MovingAveragePublicValuesBuilder,
+    TimeSeriesBuilder,
+};
+pub use chunks::stitch_moving_averages;
+pub use combine::ConflictPolicy;
+pub use compliance::{pack_bits, Predicate};
+pub use cusum::CusumResult;
+pub use drawdown::Drawdown;
+pub use ecdf::Ecdf;
+pub use ema::{alpha_from_period, period_to_alpha, AlphaConvention, EmaSmoothing};
+pub use error::TimeSeriesError;
+pub use fill_nan::FillMethod;
+pub use growth::SECONDS_PER_YEAR;
+pub use histogram::Histogram;
+pub use intervals::{hash_ranges, normalize_ranges, subtract_ranges, total_duration};
+#[cfg(feature = "io")]
+pub use io::CsvOptions;
+pub use macd::MacdResult;
+pub use merkle::{merkle_leaf, verify_merkle_proof};
+pub use order_stats::OrderStats;
+#[cfg(feature = "rayon")]
+pub use parallel::batch_summary;
+pub use regression::{Detrended, LinRegResult};
+pub use resample::{Aggregation, Extrapolation, FillPolicy, ResampleMethod};
+pub use rolling::MovingAverageMode;
+pub use seasonal::Decomposition;
+pub use summary::Summary;
+pub use time_unit::TimeUnit;
+pub use transform::Transform;
+
 /// Represents a time series with timestamps and corresponding values.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+///
+/// The serialized form is `{"timestamps": [...], "values": [...]}`. Deserializing checks that
+/// both arrays have the same length, like [`TimeSeries::new`], but returns an error instead of
+/// panicking.
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "std",
+    derive(Serialize, Deserialize),
+    serde(try_from = "RawTimeSeries")
+)]
 pub struct TimeSeries {
     pub timestamps: Vec<u64>,
     pub values: Vec<f64>,
 }
 
+/// The unchecked serialized form of a [`TimeSeries`].
+#[cfg(feature = "std")]
+#[derive(Deserialize)]
+struct RawTimeSeries {
+    timestamps: Vec<u64>,
+    values: Vec<f64>,
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<RawTimeSeries> for TimeSeries {
+    type Error = TimeSeriesError;
+
+    fn try_from(raw: RawTimeSeries) -> Result<Self, Self::Error> {
+        if raw.timestamps.len() != raw.values.len() {
+            return Err(TimeSeriesError::LengthMismatch {
+                expected: raw.timestamps.len(),
+                actual: raw.values.len(),
+            });
+        }
+        Ok(TimeSeries::new(raw.timestamps, raw.values))
+    }
+}
+
 impl TimeSeries {
     /// Creates a new TimeSeries instance.
     ///
@@ -34,15 +197,46 @@ impl TimeSeries {
     }
 
     /// Calculates the median of the time series values.
+    ///
+    /// This is the 50th percentile, see [`TimeSeries::percentile`].
     pub fn median(&self) -> f64 {
-        let mut sorted_values = self.values.clone();
-        sorted_values.sort_by(|a, b| a.partial_cmp(b).unwrap());
-        let mid = sorted_values.len() / 2;
-        if sorted_values.len() % 2 == 0 {
-            (sorted_values[mid - 1] + sorted_values[mid]) / 2.0
-        } else {
-            sorted_values[mid]
+        self.percentile(50.0)
+    }
+
+    /// Calculates the `p`-th percentile of the time series values.
+    ///
+    /// # Arguments
+    /// * `p` - The percentile to compute (0 <= p <= 100)
+    ///
+    /// # Panics
+    /// Panics if `p` is outside of `0..=100`.
+    pub fn percentile(&self, p: f64) -> f64 {
+        assert!(
+            (0.0..=100.0).contains(&p),
+            "Percentile must be between 0 and 100"
+        );
+        self.quantile(p / 100.0)
+    }
+
+    /// Calculates the `q`-th quantile of the time series values.
+    ///
+    /// Uses linear interpolation between the closest order statistics (the "type 7" definition
+    /// used by R, NumPy and Excel's `PERCENTILE.INC`). Returns NaN for an empty series or if any
+    /// value is NaN, matching how [`TimeSeries::mean`] propagates NaN.
+    ///
+    /// # Arguments
+    /// * `q` - The quantile to compute (0 <= q <= 1)
+    ///
+    /// # Panics
+    /// Panics if `q` is outside of `0..=1`.
+    pub fn quantile(&self, q: f64) -> f64 {
+        assert!((0.0..=1.0).contains(&q), "Quantile must be between 0 and 1");
+        if self.values.is_empty() || self.values.iter().any(|v| v.is_nan()) {
+            return f64::NAN;
         }
+        let mut sorted_values = self.values.clone();
+        sorted_values.sort_by(|a, b| a.total_cmp(b));
+        sorted_quantile(&sorted_values, q)
     }
 
     /// Calculates the standard deviation of the time series values.
@@ -59,25 +253,19 @@ impl TimeSeries {
 
     /// Computes the moving average of the time series.
     ///
+    /// The first `window_size - 1` points average every value so far; see
+    /// [`MovingAverageMode::ShrinkingWindow`].
+    ///
     /// # Arguments
     /// * `window_size` - The size of the moving window
     pub fn moving_average(&self, window_size: usize) -> TimeSeries {
-        let mut ma_values = Vec::with_capacity(self.values.len());
-        for i in 0..self.values.len() {
-            let start = if i < window_size {
-                0
-            } else {
-                i - window_size + 1
-            };
-            let window = &self.values[start..=i];
-            let avg = window.iter().sum::<f64>() / window.len() as f64;
-            ma_values.push(avg);
-        }
-        TimeSeries::new(self.timestamps.clone(), ma_values)
+        self.moving_average_with_mode(window_size, MovingAverageMode::ShrinkingWindow)
     }
 
     /// Computes the exponential moving average of the time series.
     ///
+    /// An empty series gives an empty result.
+    ///
     /// # Arguments
     /// * `alpha` - The smoothing factor (0 < alpha <= 1)
     pub fn exponential_moving_average(&self, alpha: f64) -> TimeSeries {
@@ -85,6 +273,9 @@ impl TimeSeries {
             (0.0..=1.0).contains(&alpha),
             "Alpha must be between 0 and 1"
         );
+        if self.values.is_empty() {
+            return self.clone();
+        }
         let mut ema_values = Vec::with_capacity(self.values.len());
         ema_values.push(self.values[0]);
         for i in 1..self.values.len() {
@@ -96,6 +287,8 @@ impl TimeSeries {
 
     /// Performs simple exponential smoothing for forecasting.
     ///
+    /// An empty series has no level to forecast from, so the result is empty.
+    ///
     /// # Arguments
     /// * `alpha` - The smoothing factor (0 < alpha <= 1)
     /// * `horizon` - The number of time steps to forecast
@@ -104,29 +297,98 @@ impl TimeSeries {
             (0.0..=1.0).contains(&alpha),
             "Alpha must be between 0 and 1"
         );
+        if self.values.is_empty() {
+            return self.clone();
+        }
         let mut forecast = Vec::with_capacity(self.values.len() + horizon);
         forecast.push(self.values[0]);
-        for i in 1..self.values.len() {
-            let smooth = alpha * self.values[i] + (1.0 - alpha) * forecast[i - 1];
-            forecast.push(smooth);
+        let mut level = self.values[0];
+        for &value in &self.values[1..] {
+            level = alpha * value + (1.0 - alpha) * level;
+            forecast.push(level);
+        }
+        forecast.extend(core::iter::repeat(level).take(horizon));
+        TimeSeries::new(self.extended_timestamps(horizon), forecast)
+    }
+
+    /// Performs Holt's linear trend method (double exponential smoothing) for forecasting.
+    ///
+    /// The returned series holds the smoothed level for every historical point followed by
+    /// `horizon` forecasts, where the `h`-th forecast is `level + h * trend`. An empty series
+    /// gives an empty result.
+    ///
+    /// # Arguments
+    /// * `alpha` - The level smoothing factor (0 < alpha <= 1)
+    /// * `beta` - The trend smoothing factor (0 < beta <= 1)
+    /// * `horizon` - The number of time steps to forecast
+    pub fn holt_linear(&self, alpha: f64, beta: f64, horizon: usize) -> TimeSeries {
+        assert!(alpha > 0.0 && alpha <= 1.0, "Alpha must be in (0, 1]");
+        assert!(beta > 0.0 && beta <= 1.0, "Beta must be in (0, 1]");
+        if self.values.is_empty() {
+            return self.clone();
+        }
+        let mut forecast = Vec::with_capacity(self.values.len() + horizon);
+        let mut level = self.values[0];
+        let mut trend = if self.values.len() > 1 {
+            self.values[1] - self.values[0]
+        } else {
+            0.0
+        };
+        forecast.push(level);
+        for &value in &self.values[1..] {
+            let previous_level = level;
+            level = alpha * value + (1.0 - alpha) * (level + trend);
+            trend = beta * (level - previous_level) + (1.0 - beta) * trend;
+            forecast.push(level);
         }
-        for _ in 0..horizon {
-            forecast.push(*forecast.last().unwrap());
+        for h in 1..=horizon {
+            forecast.push(level + h as f64 * trend);
         }
+        TimeSeries::new(self.extended_timestamps(horizon), forecast)
+    }
+
+    /// Performs double exponential smoothing for forecasting.
+    ///
+    /// This is an alias for [`TimeSeries::holt_linear`]: unlike
+    /// [`TimeSeries::simple_exponential_smoothing`], the forecast follows the smoothed trend
+    /// instead of staying flat. A single-point series has no trend, so its forecast is flat.
+    ///
+    /// # Arguments
+    /// * `alpha` - The level smoothing factor (0 < alpha <= 1)
+    /// * `beta` - The trend smoothing factor (0 < beta <= 1)
+    /// * `horizon` - The number of time steps to forecast
+    pub fn double_exponential_smoothing(
+        &self,
+        alpha: f64,
+        beta: f64,
+        horizon: usize,
+    ) -> TimeSeries {
+        self.holt_linear(alpha, beta, horizon)
+    }
+
+    /// Returns the timestamps extended by `horizon` future steps.
+    ///
+    /// The time step is [`TimeSeries::median_time_step`], so one irregular gap does not set the
+    /// spacing of the forecast.
+    fn extended_timestamps(&self, horizon: usize) -> Vec<u64> {
         let mut timestamps = self.timestamps.clone();
-        let last_timestamp = *timestamps.last().unwrap();
-        let time_step = if timestamps.len() > 1 {
-            timestamps[1] - timestamps[0]
-        } else {
-            1
+        let Some(&last_timestamp) = timestamps.last() else {
+            return timestamps;
         };
+        let time_step = self.median_time_step();
         for i in 1..=horizon {
             timestamps.push(last_timestamp + i as u64 * time_step);
         }
-        TimeSeries::new(timestamps, forecast)
+        timestamps
     }
 
-    pub fn to_public_values(&self) -> PublicValuesStruct {
+    /// Builds the public values of the statistics program.
+    ///
+    /// # Errors
+    /// Returns [`TimeSeriesError::NonFiniteValue`] if a value is NaN or infinite (see
+    /// [`TimeSeries::validate_finite`]).
+    pub fn to_public_values(&self) -> Result<PublicValuesStruct, TimeSeriesError> {
+        self.validate_finite()?;
         let start_timestamp = *self.timestamps.first().unwrap_or(&0);
         let end_timestamp = *self.timestamps.last().unwrap_or(&0);
         let values_hash = self.compute_hash();
@@ -134,16 +396,20 @@ impl TimeSeries {
         let median = self.median();
         let std_dev = self.std_dev();
 
-        PublicValuesStruct {
+        Ok(PublicValuesStruct {
             start_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(start_timestamp),
             end_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(end_timestamp),
             values_hash: alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(values_hash),
             mean: f64_to_u256(mean),
             median: f64_to_u256(median),
             std_dev: f64_to_u256(std_dev),
-        }
+        })
     }
 
+    /// Computes the Keccak256 hash of [`TimeSeries::hash_preimage`], in stored order.
+    ///
+    /// This is the hash committed by the public values. It hashes raw f64 bits, with the pitfalls
+    /// described on [`TimeSeries::hash_preimage`].
     fn compute_hash(&self) -> [u8; 32] {
         let mut hasher = Keccak256::new();
         for (timestamp, value) in self.timestamps.iter().zip(self.values.iter()) {
@@ -153,22 +419,28 @@ impl TimeSeries {
         hasher.finalize().into()
     }
 
+    /// Builds the public values of the moving average program.
+    ///
+    /// # Errors
+    /// Returns [`TimeSeriesError::NonFiniteValue`] if a value is NaN or infinite (see
+    /// [`TimeSeries::validate_finite`]).
     pub fn to_moving_average_public_values(
         &self,
         window_size: usize,
-    ) -> MovingAveragePublicValuesStruct {
+    ) -> Result<MovingAveragePublicValuesStruct, TimeSeriesError> {
+        self.validate_finite()?;
         let start_timestamp = *self.timestamps.first().unwrap_or(&0);
         let end_timestamp = *self.timestamps.last().unwrap_or(&0);
         let values_hash = self.compute_hash();
         let ma = self.moving_average(window_size);
 
-        MovingAveragePublicValuesStruct {
-            start_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(start_timestamp),
-            end_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(end_timestamp),
-            values_hash: alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(values_hash),
-            window_size: alloy_sol_types::private::Uint::<256, 4>::from(window_size),
-            moving_averages: vec_f64_to_u256(&ma.values),
-        }
+        MovingAveragePublicValues::builder()
+            .start_timestamp(start_timestamp)
+            .end_timestamp(end_timestamp)
+            .values_hash(values_hash)
+            .window(window_size)
+            .moving_averages(&ma.values)
+            .build()
     }
 }
 
@@ -196,10 +468,327 @@ sol! {
     }
 }
 
+sol! {
+    /// Defines the structure for public values output by the transform link ZK proof.
+    struct TransformLinkStruct {
+        uint256 raw_hash;
+        uint8 transform_id;
+        uint256 params_hash;
+        uint256 output_hash;
+        uint256 tolerance;
+        uint256 max_abs_error;
+        uint256 n;
+    }
+}
+
+sol! {
+    /// Defines the structure for public values output by the rolling compliance ZK proof.
+    struct CompliancePublicValuesStruct {
+        uint256 start_timestamp;
+        uint256 end_timestamp;
+        uint256 values_hash;
+        uint256 window_size;
+        uint8 predicate;
+        int256 limit;
+        uint256 n;
+        uint256[] compliance_bitmap;
+    }
+}
+
+sol! {
+    /// Defines the structure for public values output by the autocorrelation ZK proof.
+    struct AcfPublicValuesStruct {
+        uint256 start_timestamp;
+        uint256 end_timestamp;
+        uint256 values_hash;
+        uint256 max_lag;
+        uint256 confidence;
+        uint256 bound;
+        int256[] acf;
+    }
+}
+
+sol! {
+    /// Defines the structure for public values output by the dual-hash ZK proof.
+    struct DualHashPublicValuesStruct {
+        uint256 start_timestamp;
+        uint256 end_timestamp;
+        uint256 values_hash_keccak;
+        uint256 values_hash_sha256;
+        uint256 mean;
+        uint256 median;
+        uint256 std_dev;
+    }
+}
+
+sol! {
+    /// Defines the structure for public values output by the weighted average ZK proof.
+    struct WeightedAveragePublicValuesStruct {
+        uint256 start_timestamp;
+        uint256 end_timestamp;
+        uint256 weighted_values_hash;
+        uint256[] weights;
+        int256 weighted_mean;
+    }
+}
+
+sol! {
+    /// Defines the structure for public values output by the piecewise-linear ZK proof.
+    struct PiecewiseLinearPublicValuesStruct {
+        uint256 start_timestamp;
+        uint256 end_timestamp;
+        uint256 values_hash;
+        uint256 max_error;
+        uint256[] breakpoint_timestamps;
+        int256[] breakpoint_values;
+    }
+}
+
+sol! {
+    /// Defines the structure for public values output by the Holt-Winters ZK proof.
+    struct HoltWintersPublicValuesStruct {
+        uint256 start_timestamp;
+        uint256 end_timestamp;
+        uint256 values_hash;
+        uint256 alpha;
+        uint256 beta;
+        uint256 gamma;
+        uint256 season_length;
+        uint256 horizon;
+        int256[] forecast;
+    }
+}
+
+sol! {
+    /// Defines the structure for public values output by the exclusion-window ZK proof.
+    struct ExclusionPublicValuesStruct {
+        uint256 start_timestamp;
+        uint256 end_timestamp;
+        uint256 values_hash;
+        uint256 filtered_values_hash;
+        uint256 exclusions_hash;
+        uint256 excluded_duration;
+        uint256 mean;
+        uint256 median;
+        uint256 std_dev;
+    }
+}
+
+sol! {
+    /// Defines the structure for public values output by the order statistics ZK proof.
+    struct OrderStatsPublicValuesStruct {
+        uint256 start_timestamp;
+        uint256 end_timestamp;
+        uint256 values_hash;
+        int256 min;
+        int256 max;
+        int256 median;
+    }
+}
+
+sol! {
+    /// Defines the structure for public values output by the flatline detection ZK proof.
+    struct FlatlineStruct {
+        uint256 values_hash;
+        uint256 epsilon;
+        uint256 longest_run_count;
+        uint256 longest_run_seconds;
+        uint256 n;
+    }
+}
+
+sol! {
+    /// Defines the structure for public values output by the Welch's t-test ZK proof.
+    struct WelchTPublicValuesStruct {
+        uint256 start_timestamp;
+        uint256 end_timestamp;
+        uint256 values_hash;
+        uint256 split_index;
+        uint256 split_timestamp;
+        int256 t_statistic;
+    }
+}
+
+sol! {
+    /// Defines the structure for public values output by the exponential moving average ZK proof.
+    struct EmaPublicValuesStruct {
+        uint256 start_timestamp;
+        uint256 end_timestamp;
+        uint256 values_hash;
+        uint256 period;
+        uint8 convention;
+        uint256 alpha;
+        int256[] ema;
+    }
+}
+
+sol! {
+    /// Defines the structure for public values output by the forecast interval ZK proof.
+    struct ForecastIntervalPublicValuesStruct {
+        uint256 start_timestamp;
+        uint256 end_timestamp;
+        uint256 values_hash;
+        uint256 alpha;
+        uint256 horizon;
+        uint256 z;
+        int256[] point;
+        int256[] lower;
+        int256[] upper;
+    }
+}
+
+sol! {
+    /// Defines the structure for public values output by the non-negativity ZK proof.
+    struct NonNegativityPublicValuesStruct {
+        uint256 start_timestamp;
+        uint256 end_timestamp;
+        uint256 values_hash;
+        uint256 n;
+        bool all_non_negative;
+    }
+}
+
+sol! {
+    /// Defines the structure for public values output by the extended statistics ZK proof.
+    struct ExtendedPublicValuesStruct {
+        uint256 start_timestamp;
+        uint256 end_timestamp;
+        uint256 values_hash;
+        uint256 mean;
+        uint256 median;
+        uint256 std_dev;
+        int256 annualized_growth;
+    }
+}
+
+sol! {
+    /// Defines the structure for public values output by the range count ZK proof.
+    struct RangeCountPublicValuesStruct {
+        uint256 start_timestamp;
+        uint256 end_timestamp;
+        uint256 values_hash;
+        uint256 range_start;
+        uint256 range_end;
+        uint256 count;
+    }
+}
+
+sol! {
+    /// Defines the structure for public values output by the exponential growth ZK proof.
+    struct ExponentialGrowthPublicValuesStruct {
+        uint256 start_timestamp;
+        uint256 end_timestamp;
+        uint256 values_hash;
+        int256 growth_rate;
+        uint256 r_squared;
+    }
+}
+
+sol! {
+    /// Defines the structure for public values output by the disjoint claim ZK proof.
+    struct ClaimStruct {
+        uint256 values_hash;
+        uint256 new_start;
+        uint256 new_end;
+        uint256 claimed_ranges_hash;
+        uint256 non_overlapping_seconds;
+        uint256 n;
+    }
+}
+
+sol! {
+    /// Defines the structure for public values output by the anomaly ZK proof.
+    struct AnomalyPublicValuesStruct {
+        uint256 start_timestamp;
+        uint256 end_timestamp;
+        uint256 values_hash;
+        uint256 z_threshold;
+        uint256 anomaly_count;
+        uint256 first_anomaly_timestamp;
+    }
+}
+
+sol! {
+    /// Defines the structure for public values output by the band breach ZK proof.
+    struct BandBreachPublicValuesStruct {
+        uint256 start_timestamp;
+        uint256 end_timestamp;
+        uint256 values_hash;
+        uint256 window_size;
+        uint256 k;
+        uint256 breach_count;
+    }
+}
+
+sol! {
+    /// Defines the structure for public values output by the VWAP ZK proof.
+    struct VwapPublicValuesStruct {
+        uint256 values_hash;
+        uint256 volumes_hash;
+        int256 vwap;
+    }
+}
+
+sol! {
+    /// Defines the structure for public values output by the histogram ZK proof.
+    struct HistogramPublicValuesStruct {
+        uint256 values_hash;
+        int256[] bin_edges;
+        uint256[] counts;
+        uint256 total;
+        uint256 underflow;
+        uint256 overflow;
+    }
+}
+
+sol! {
+    /// Defines the structure for public values output by the CUSUM change-point ZK proof.
+    struct CusumPublicValuesStruct {
+        uint256 values_hash;
+        int256 target;
+        uint256 slack;
+        uint256 threshold;
+        uint256 change_point_count;
+    }
+}
+
+sol! {
+    /// Defines the structure for public values output by the correlation threshold ZK proof.
+    struct CorrelationPublicValuesStruct {
+        uint256 values_hash;
+        uint256 other_values_hash;
+        int256 threshold;
+        bool above_threshold;
+    }
+}
+
+sol! {
+    /// Defines the structure for public values output by the Kalman smoothing ZK proof.
+    struct KalmanPublicValuesStruct {
+        uint256 start_timestamp;
+        uint256 end_timestamp;
+        uint256 values_hash;
+        uint256 process_var;
+        uint256 measurement_var;
+        uint256 smoothed_hash;
+    }
+}
+
+/// Interpolates the `q`-th quantile of a non-empty, sorted slice (see [`TimeSeries::quantile`]).
+pub(crate) fn sorted_quantile(sorted_values: &[f64], q: f64) -> f64 {
+    let h = (sorted_values.len() - 1) as f64 * q;
+    let lower = h.floor() as usize;
+    let upper = h.ceil() as usize;
+    sorted_values[lower] + (h - lower as f64) * (sorted_values[upper] - sorted_values[lower])
+}
+
 /// Converts an f64 to a U256 for Solidity compatibility.
 ///
 /// This function multiplies the f64 by 1e18 and converts it to a U256.
 /// This allows for 18 decimal places of precision in Solidity.
+///
+/// The sign is dropped: -30.35 and 30.35 both convert to the same value. Use [`f64_to_i256`] for
+/// quantities that can be negative.
 pub fn f64_to_u256(value: f64) -> alloy_sol_types::private::Uint<256, 4> {
     let scaled_value = (value.abs() * 1e18) as u128;
     let bytes = scaled_value.to_be_bytes();
@@ -218,18 +807,224 @@ pub fn vec_f64_to_u256(values: &[f64]) -> Vec<alloy_sol_types::private::Uint<256
 /// This function is the inverse of f64_to_u256.
 pub fn u256_to_f64(value: alloy_sol_types::private::Uint<256, 4>) -> f64 {
     let bytes: [u8; 32] = value.to_be_bytes();
-    let u128_value = u128::from_be_bytes(bytes[16..].try_into().unwrap());
+    let mut low_bytes = [0u8; 16];
+    low_bytes.copy_from_slice(&bytes[16..]);
+    let u128_value = u128::from_be_bytes(low_bytes);
     (u128_value as f64) / 1e18
 }
 
+/// Converts a non-negative f64 to a U256 fixed-point number with `decimals` decimal places.
+///
+/// Unlike [`f64_to_u256`], which truncates through a `u128`, this decomposes the f64 exactly and
+/// scales it by `10^decimals` in wide integer arithmetic, so the full 256 bits are usable. The
+/// result is rounded to the nearest integer, ties to even, so 1234.56 with 6 decimals is
+/// 1_234_560_000 even though the f64 is slightly below 1234.56.
+///
+/// Returns `None` if `value` is negative, NaN or infinite, or if the scaled value does not fit
+/// in 256 bits.
+pub fn f64_to_u256_scaled(
+    value: f64,
+    decimals: u32,
+) -> Option<alloy_sol_types::private::Uint<256, 4>> {
+    type Wide = alloy_sol_types::private::Uint<512, 8>;
+    if !value.is_finite() || value < 0.0 {
+        return None;
+    }
+    // value = mantissa * 2^exponent exactly.
+    let bits = value.to_bits();
+    let biased_exponent = ((bits >> 52) & 0x7ff) as i64;
+    let fraction = bits & ((1 << 52) - 1);
+    let (mantissa, exponent) = if biased_exponent == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | (1 << 52), biased_exponent - 1075)
+    };
+
+    let multiplier = Wide::from(10u64).checked_pow(Wide::from(decimals))?;
+    let product = Wide::from(mantissa).checked_mul(multiplier)?;
+    let scaled = if exponent >= 0 {
+        product.checked_shl(exponent as usize)?
+    } else {
+        let shift = exponent.unsigned_abs() as usize;
+        if shift > Wide::BITS {
+            // The product is below 2^512, so less than half a unit.
+            return Some(alloy_sol_types::private::Uint::<256, 4>::ZERO);
+        }
+        // Keep one bit below the unit to round on, and note whether anything below it is set.
+        let halves = product.wrapping_shr(shift - 1);
+        let truncated = halves.wrapping_shr(1);
+        let sticky = halves.wrapping_shl(shift - 1) != product;
+        if halves.bit(0) && (sticky || truncated.bit(0)) {
+            truncated + Wide::from(1u64)
+        } else {
+            truncated
+        }
+    };
+    let limbs = scaled.as_limbs();
+    if limbs[4..].iter().any(|&limb| limb != 0) {
+        return None;
+    }
+    Some(alloy_sol_types::private::Uint::<256, 4>::from_limbs([
+        limbs[0], limbs[1], limbs[2], limbs[3],
+    ]))
+}
+
+/// Converts a U256 fixed-point number with `decimals` decimal places back to the nearest f64.
+///
+/// This is the inverse of [`f64_to_u256_scaled`] up to f64 precision.
+pub fn u256_to_f64_scaled(value: alloy_sol_types::private::Uint<256, 4>, decimals: u32) -> f64 {
+    u256_to_f64_rounded(value) / detmath::pow(10.0, f64::from(decimals))
+}
+
+/// Converts a U256 to the nearest f64, rounding ties to even.
+///
+/// `ruint` only provides this conversion with `std`, so it is done here on the top 64 bits.
+fn u256_to_f64_rounded(value: alloy_sol_types::private::Uint<256, 4>) -> f64 {
+    let bits = value.bit_len();
+    if bits <= 64 {
+        return value.as_limbs()[0] as f64;
+    }
+    let shift = bits - 64;
+    let top = (value >> shift).as_limbs()[0];
+    // Fold the dropped bits into the lowest bit, below the rounding position, so the u64
+    // conversion still rounds correctly.
+    let sticky = u64::from(value.trailing_zeros() < shift);
+    (top | sticky) as f64 * f64::from_bits((1023 + shift as u64) << 52)
+}
+
 /// Converts a Vec<U256> back to a Vec<f64>.
 pub fn vec_u256_to_f64(values: &[alloy_sol_types::private::Uint<256, 4>]) -> Vec<f64> {
     values.iter().map(|&v| u256_to_f64(v)).collect()
 }
 
+/// Converts a committed `uint256` to a [`primitive_types::U256`].
+#[cfg(feature = "primitive-types")]
+pub fn u256_to_primitive(value: alloy_sol_types::private::Uint<256, 4>) -> primitive_types::U256 {
+    primitive_types::U256(value.into_limbs())
+}
+
+/// Converts a [`primitive_types::U256`] to the `uint256` type used in the public values.
+#[cfg(feature = "primitive-types")]
+pub fn primitive_to_u256(value: primitive_types::U256) -> alloy_sol_types::private::Uint<256, 4> {
+    alloy_sol_types::private::Uint::<256, 4>::from_limbs(value.0)
+}
+
+/// Converts an f64 to an I256 for Solidity compatibility.
+///
+/// This is the signed counterpart of f64_to_u256: the magnitude is scaled by 1e18 and the sign is
+/// preserved, so negative values can be committed as `int256`.
+pub fn f64_to_i256(value: f64) -> alloy_sol_types::private::Signed<256, 4> {
+    let magnitude = alloy_sol_types::private::Signed::<256, 4>::from_raw(f64_to_u256(value));
+    if value < 0.0 {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
+/// Converts a Vec<f64> to a Vec<I256> for Solidity compatibility.
+pub fn vec_f64_to_i256(values: &[f64]) -> Vec<alloy_sol_types::private::Signed<256, 4>> {
+    values.iter().map(|&v| f64_to_i256(v)).collect()
+}
+
+/// Converts an I256 back to an f64.
+///
+/// This function is the inverse of f64_to_i256.
+pub fn i256_to_f64(value: alloy_sol_types::private::Signed<256, 4>) -> f64 {
+    let (_, magnitude) = value.into_sign_and_abs();
+    let magnitude = u256_to_f64(magnitude);
+    if value.is_negative() {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloy_sol_types::SolStruct;
+
+    #[test]
+    fn test_f64_to_u256_scaled_usdc_decimals() {
+        let scaled = f64_to_u256_scaled(1234.56, 6).unwrap();
+        assert_eq!(
+            scaled,
+            alloy_sol_types::private::Uint::<256, 4>::from(1_234_560_000u64)
+        );
+        // Exact halves round to even.
+        assert_eq!(
+            f64_to_u256_scaled(2.5, 0).unwrap(),
+            alloy_sol_types::private::Uint::<256, 4>::from(2u64)
+        );
+        assert_eq!(
+            f64_to_u256_scaled(3.5, 0).unwrap(),
+            alloy_sol_types::private::Uint::<256, 4>::from(4u64)
+        );
+        assert_eq!(
+            f64_to_u256_scaled(0.25, 1).unwrap(),
+            alloy_sol_types::private::Uint::<256, 4>::from(2u64)
+        );
+        assert_eq!(
+            f64_to_u256_scaled(f64::from_bits(1), 18),
+            Some(alloy_sol_types::private::Uint::<256, 4>::ZERO)
+        );
+        assert_eq!(
+            f64_to_u256_scaled(0.5, 6).unwrap(),
+            alloy_sol_types::private::Uint::<256, 4>::from(500_000u64)
+        );
+        assert!((u256_to_f64_scaled(scaled, 6) - 1234.56).abs() < 1e-5);
+        assert_eq!(
+            f64_to_u256_scaled(0.0, 6),
+            Some(alloy_sol_types::private::Uint::<256, 4>::ZERO)
+        );
+    }
+
+    #[test]
+    fn test_f64_to_u256_scaled_beyond_u128() {
+        // 1e30 scaled by 1e18 is far above u128::MAX (about 3.4e38).
+        let value = 1e30;
+        let scaled = f64_to_u256_scaled(value, 18).unwrap();
+        assert!(scaled > alloy_sol_types::private::Uint::<256, 4>::from(u128::MAX));
+        assert_eq!(u256_to_f64_scaled(scaled, 18), value);
+        assert_ne!(f64_to_u256(value), scaled);
+        // 2^64 + 2^11 sits halfway between two f64s and rounds to the even one, 2^64; a
+        // single bit further down tips it up to the next one.
+        let halfway = alloy_sol_types::private::Uint::<256, 4>::from((1u128 << 64) + (1 << 11));
+        assert_eq!(u256_to_f64_scaled(halfway, 0), 2f64.powi(64));
+        assert_eq!(
+            u256_to_f64_scaled(halfway + alloy_sol_types::private::Uint::from(1u64), 0),
+            2f64.powi(64) + 2f64.powi(12)
+        );
+
+        assert_eq!(f64_to_u256_scaled(1e60, 18), None);
+        assert_eq!(f64_to_u256_scaled(-1.0, 6), None);
+        assert_eq!(f64_to_u256_scaled(f64::NAN, 6), None);
+        assert_eq!(f64_to_u256_scaled(f64::INFINITY, 6), None);
+    }
+
+    #[test]
+    fn test_public_values_layout() {
+        // The statistics layout is defined once, here. Every program and the on-chain decoder
+        // rely on this exact field order.
+        assert_eq!(
+            PublicValuesStruct::eip712_encode_type(),
+            "PublicValuesStruct(uint256 start_timestamp,uint256 end_timestamp,\
+             uint256 values_hash,uint256 mean,uint256 median,uint256 std_dev)"
+        );
+    }
+
+    #[cfg(feature = "primitive-types")]
+    #[test]
+    fn test_primitive_u256_round_trip() {
+        let value = f64_to_u256(1.5);
+        let primitive = u256_to_primitive(value);
+        assert_eq!(
+            primitive,
+            primitive_types::U256::from(1_500_000_000_000_000_000u128)
+        );
+        assert_eq!(primitive_to_u256(primitive), value);
+    }
 
     #[test]
     fn test_time_series_creation() {
@@ -250,6 +1045,37 @@ mod tests {
         assert_eq!(ts.median(), 2.5);
     }
 
+    #[test]
+    fn test_median_with_nan() {
+        let ts = TimeSeries::new(vec![1, 2, 3], vec![1.0, f64::NAN, 3.0]);
+        assert!(ts.median().is_nan());
+    }
+
+    #[test]
+    fn test_percentile() {
+        let ts = TimeSeries::new(vec![1, 2, 3, 4, 5], vec![5.0, 1.0, 4.0, 2.0, 3.0]);
+        assert_eq!(ts.percentile(25.0), 2.0);
+        assert_eq!(ts.percentile(50.0), 3.0);
+        assert_eq!(ts.percentile(75.0), 4.0);
+        assert_eq!(ts.percentile(0.0), 1.0);
+        assert_eq!(ts.percentile(100.0), 5.0);
+    }
+
+    #[test]
+    fn test_quantile_interpolates() {
+        let ts = TimeSeries::new(vec![1, 2, 3, 4], vec![1.0, 2.0, 3.0, 4.0]);
+        assert!((ts.quantile(0.25) - 1.75).abs() < 1e-10);
+        assert!((ts.quantile(0.9) - 3.7).abs() < 1e-10);
+        assert_eq!(ts.quantile(0.5), ts.median());
+    }
+
+    #[test]
+    #[should_panic(expected = "Percentile must be between 0 and 100")]
+    fn test_percentile_out_of_range() {
+        let ts = TimeSeries::new(vec![1, 2, 3], vec![1.0, 2.0, 3.0]);
+        ts.percentile(101.0);
+    }
+
     #[test]
     fn test_std_dev() {
         let ts = TimeSeries::new(vec![1, 2, 3], vec![1.0, 2.0, 3.0]);
@@ -268,7 +1094,8 @@ mod tests {
         let ts = TimeSeries::new(vec![1, 2, 3, 4, 5], vec![1.0, 2.0, 3.0, 4.0, 5.0]);
         let ema = ts.exponential_moving_average(0.5);
         assert_eq!(ema.values[0], 1.0);
-        assert!((ema.values[4] - 3.9375).abs() < 1e-10);
+        // Seeded with the first value: 1, 1.5, 2.25, 3.125, 4.0625.
+        assert!((ema.values[4] - 4.0625).abs() < 1e-10);
     }
 
     #[test]
@@ -276,14 +1103,90 @@ mod tests {
         let ts = TimeSeries::new(vec![1, 2, 3, 4, 5], vec![1.0, 2.0, 3.0, 4.0, 5.0]);
         let ses = ts.simple_exponential_smoothing(0.5, 2);
         assert_eq!(ses.timestamps, vec![1, 2, 3, 4, 5, 6, 7]);
-        assert!((ses.values[6] - 5.0).abs() < 1e-10);
+        // The forecast stays at the last smoothed level, the EMA above, not the last value.
+        assert!((ses.values[6] - 4.0625).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_simple_exponential_smoothing_single_point() {
+        let ts = TimeSeries::new(vec![100], vec![4.0]);
+        let ses = ts.simple_exponential_smoothing(0.5, 2);
+        assert_eq!(ses.timestamps, vec![100, 101, 102]);
+        assert_eq!(ses.values, vec![4.0, 4.0, 4.0]);
+    }
+
+    #[test]
+    fn test_forecast_timestamps_use_typical_spacing() {
+        // The first gap is an outlier; the rest of the series is sampled every 60 seconds.
+        let ts = TimeSeries::new(vec![0, 600, 660, 720, 780], vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+        let ses = ts.simple_exponential_smoothing(0.5, 2);
+        assert_eq!(ses.timestamps[5..], [840, 900]);
+        let holt = ts.holt_linear(0.5, 0.5, 2);
+        assert_eq!(holt.timestamps[5..], [840, 900]);
+    }
+
+    #[test]
+    fn test_smoothing_empty_series() {
+        let empty = TimeSeries::new(vec![], vec![]);
+        assert!(empty.exponential_moving_average(0.5).values.is_empty());
+        assert!(empty.simple_exponential_smoothing(0.5, 3).values.is_empty());
+        let holt = empty.holt_linear(0.5, 0.5, 3);
+        assert!(holt.values.is_empty() && holt.timestamps.is_empty());
+    }
+
+    #[test]
+    fn test_holt_linear_continues_trend() {
+        let ts = TimeSeries::new(vec![10, 20, 30, 40, 50], vec![2.0, 4.0, 6.0, 8.0, 10.0]);
+        let holt = ts.holt_linear(0.5, 0.5, 3);
+        assert_eq!(holt.timestamps, vec![10, 20, 30, 40, 50, 60, 70, 80]);
+        for (value, expected) in holt.values[5..].iter().zip([12.0, 14.0, 16.0]) {
+            assert!((value - expected).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Beta must be in (0, 1]")]
+    fn test_holt_linear_rejects_zero_beta() {
+        let ts = TimeSeries::new(vec![1, 2, 3], vec![1.0, 2.0, 3.0]);
+        ts.holt_linear(0.5, 0.0, 1);
+    }
+
+    #[test]
+    fn test_double_exponential_smoothing_continues_slope() {
+        let ts = TimeSeries::new(vec![0, 5, 10, 15], vec![1.0, 0.5, 0.0, -0.5]);
+        let forecast = ts.double_exponential_smoothing(0.3, 0.1, 4);
+        assert_eq!(forecast.timestamps, vec![0, 5, 10, 15, 20, 25, 30, 35]);
+        for (value, expected) in forecast
+            .values
+            .iter()
+            .zip([1.0, 0.5, 0.0, -0.5, -1.0, -1.5, -2.0, -2.5])
+        {
+            assert!((value - expected).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_double_exponential_smoothing_single_point() {
+        let ts = TimeSeries::new(vec![7], vec![3.0]);
+        let forecast = ts.double_exponential_smoothing(0.5, 0.5, 2);
+        assert_eq!(forecast.timestamps, vec![7, 8, 9]);
+        assert_eq!(forecast.values, vec![3.0, 3.0, 3.0]);
     }
 
     #[test]
     fn test_f64_to_u256_conversion() {
-        let value = std::f64::consts::PI;
+        let value = core::f64::consts::PI;
         let converted = f64_to_u256(value);
         let back = u256_to_f64(converted);
         assert!((value - back).abs() < 1e-10);
     }
+
+    #[test]
+    fn test_f64_to_i256_conversion() {
+        let value = -core::f64::consts::E;
+        let converted = f64_to_i256(value);
+        assert!(converted.is_negative());
+        assert!((i256_to_f64(converted) - value).abs() < 1e-10);
+        assert_eq!(i256_to_f64(f64_to_i256(2.5)), 2.5);
+    }
 }
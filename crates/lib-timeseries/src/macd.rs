@@ -0,0 +1,125 @@
+use crate::{period_to_alpha, TimeSeries, TimeSeriesError};
+
+/// The three aligned series of the MACD indicator.
+#[derive(Clone, Debug)]
+pub struct MacdResult {
+    /// The fast EMA minus the slow EMA.
+    pub macd_line: TimeSeries,
+    /// The EMA of the MACD line.
+    pub signal_line: TimeSeries,
+    /// The MACD line minus the signal line.
+    pub histogram: TimeSeries,
+}
+
+impl TimeSeries {
+    /// Computes the moving average convergence/divergence (MACD) indicator.
+    ///
+    /// Every EMA uses the span convention of [`period_to_alpha`] and, like
+    /// [`TimeSeries::exponential_moving_average`], starts at the first value, so all three series
+    /// have one point per input point. The common choice is `macd(12, 26, 9)`.
+    ///
+    /// # Arguments
+    /// * `fast` - The period of the fast EMA
+    /// * `slow` - The period of the slow EMA, which must be longer than `fast`
+    /// * `signal` - The period of the EMA of the MACD line
+    ///
+    /// # Errors
+    /// Returns [`TimeSeriesError::InvalidParameter`] if a period is zero or `fast >= slow`, and
+    /// [`TimeSeriesError::InsufficientData`] if the series is shorter than `slow`.
+    pub fn macd(
+        &self,
+        fast: usize,
+        slow: usize,
+        signal: usize,
+    ) -> Result<MacdResult, TimeSeriesError> {
+        if fast == 0 {
+            return Err(TimeSeriesError::InvalidParameter { name: "fast" });
+        }
+        if signal == 0 {
+            return Err(TimeSeriesError::InvalidParameter { name: "signal" });
+        }
+        if fast >= slow {
+            return Err(TimeSeriesError::InvalidParameter { name: "slow" });
+        }
+        if self.values.len() < slow {
+            return Err(TimeSeriesError::InsufficientData {
+                required: slow,
+                actual: self.values.len(),
+            });
+        }
+
+        let fast_ema = self.exponential_moving_average(period_to_alpha(fast));
+        let slow_ema = self.exponential_moving_average(period_to_alpha(slow));
+        let macd_values = fast_ema
+            .values
+            .iter()
+            .zip(slow_ema.values.iter())
+            .map(|(f, s)| f - s)
+            .collect();
+        let macd_line = TimeSeries::new(self.timestamps.clone(), macd_values);
+        let signal_line = macd_line.exponential_moving_average(period_to_alpha(signal));
+        let histogram_values = macd_line
+            .values
+            .iter()
+            .zip(signal_line.values.iter())
+            .map(|(m, s)| m - s)
+            .collect();
+
+        Ok(MacdResult {
+            histogram: TimeSeries::new(self.timestamps.clone(), histogram_values),
+            macd_line,
+            signal_line,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_macd_histogram_is_macd_minus_signal() {
+        let values = (0..60)
+            .map(|i| 100.0 + (i as f64 / 5.0).sin() * 10.0 + i as f64 * 0.3)
+            .collect();
+        let ts = TimeSeries::new((0..60).collect(), values);
+        let macd = ts.macd(12, 26, 9).unwrap();
+
+        assert_eq!(macd.macd_line.values.len(), 60);
+        assert_eq!(macd.signal_line.timestamps, ts.timestamps);
+        for i in 0..60 {
+            let expected = macd.macd_line.values[i] - macd.signal_line.values[i];
+            assert_eq!(macd.histogram.values[i], expected);
+        }
+        assert_eq!(macd.macd_line.values[0], 0.0);
+    }
+
+    #[test]
+    fn test_macd_of_rising_series_is_positive() {
+        let ts = TimeSeries::new((0..30).collect(), (0..30).map(|i| i as f64).collect());
+        let macd = ts.macd(3, 6, 2).unwrap();
+        // The fast EMA lags a linear trend less than the slow one.
+        assert!(macd.macd_line.values[1..].iter().all(|&m| m > 0.0));
+        assert_eq!(period_to_alpha(3), 0.5);
+    }
+
+    #[test]
+    fn test_macd_errors() {
+        let ts = TimeSeries::new((0..10).collect(), vec![1.0; 10]);
+        assert_eq!(
+            ts.macd(26, 12, 9).err(),
+            Some(TimeSeriesError::InvalidParameter { name: "slow" })
+        );
+        assert_eq!(
+            ts.macd(0, 12, 9).err(),
+            Some(TimeSeriesError::InvalidParameter { name: "fast" })
+        );
+        assert_eq!(
+            ts.macd(5, 12, 9).err(),
+            Some(TimeSeriesError::InsufficientData {
+                required: 12,
+                actual: 10
+            })
+        );
+    }
+}
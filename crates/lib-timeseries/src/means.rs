@@ -0,0 +1,61 @@
+use crate::{detmath, TimeSeries};
+
+impl TimeSeries {
+    /// Calculates the geometric mean of the time series values, the aggregate for growth
+    /// factors and ratios.
+    ///
+    /// Computed as `exp(mean(ln(value)))` with [`detmath`], so it does not overflow on long
+    /// series. The geometric mean is only defined for positive data: returns NaN if any value
+    /// is zero, negative or NaN, or if the series is empty.
+    pub fn geometric_mean(&self) -> f64 {
+        if self.values.is_empty() || !self.values.iter().all(|&v| v > 0.0) {
+            return f64::NAN;
+        }
+        let log_sum: f64 = self.values.iter().map(|&v| detmath::ln(v)).sum();
+        detmath::exp(log_sum / self.values.len() as f64)
+    }
+
+    /// Calculates the harmonic mean of the time series values, the aggregate for rates such as
+    /// speeds averaged over equal distances.
+    ///
+    /// Returns NaN if any value is zero, negative or NaN, since the reciprocal of zero is
+    /// undefined and mixed signs make the mean meaningless, or if the series is empty.
+    pub fn harmonic_mean(&self) -> f64 {
+        if self.values.is_empty() || !self.values.iter().all(|&v| v > 0.0) {
+            return f64::NAN;
+        }
+        let reciprocal_sum: f64 = self.values.iter().map(|v| 1.0 / v).sum();
+        self.values.len() as f64 / reciprocal_sum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_geometric_and_harmonic_means() {
+        let ts = TimeSeries::new(vec![1, 2], vec![1.0, 4.0]);
+        assert!((ts.geometric_mean() - 2.0).abs() < 1e-12);
+
+        let ts = TimeSeries::new(vec![1, 2, 3], vec![1.0, 2.0, 4.0]);
+        assert!((ts.harmonic_mean() - 12.0 / 7.0).abs() < 1e-12);
+        assert!((ts.geometric_mean() - 2.0).abs() < 1e-12);
+        assert!(ts.harmonic_mean() <= ts.geometric_mean() && ts.geometric_mean() <= ts.mean());
+    }
+
+    #[test]
+    fn test_means_of_non_positive_values_are_nan() {
+        let with_zero = TimeSeries::new(vec![1, 2], vec![0.0, 4.0]);
+        assert!(with_zero.geometric_mean().is_nan());
+        assert!(with_zero.harmonic_mean().is_nan());
+
+        let negative = TimeSeries::new(vec![1, 2], vec![-1.0, 4.0]);
+        assert!(negative.geometric_mean().is_nan());
+        assert!(negative.harmonic_mean().is_nan());
+
+        let empty = TimeSeries::new(vec![], vec![]);
+        assert!(empty.geometric_mean().is_nan());
+        assert!(empty.harmonic_mean().is_nan());
+    }
+}
@@ -0,0 +1,184 @@
+use sha3::{Digest, Keccak256};
+
+use crate::prelude::*;
+use crate::TimeSeries;
+
+/// Prefixes a leaf preimage so a leaf can never be mistaken for an internal node.
+const LEAF_PREFIX: u8 = 0x00;
+/// Prefixes an internal node preimage.
+const NODE_PREFIX: u8 = 0x01;
+
+/// Computes the Merkle leaf of one point: `keccak256(0x00 || timestamp || value)`, with the
+/// timestamp and the raw f64 bits of the value in big-endian order as in
+/// [`TimeSeries::hash_preimage`].
+pub fn merkle_leaf(timestamp: u64, value: f64) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    hasher.update([LEAF_PREFIX]);
+    hasher.update(timestamp.to_be_bytes());
+    hasher.update(value.to_be_bytes());
+    hasher.finalize().into()
+}
+
+fn merkle_node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    hasher.update([NODE_PREFIX]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// Hashes each pair of nodes into the level above; an unpaired last node moves up unchanged.
+fn next_level(level: &[[u8; 32]]) -> Vec<[u8; 32]> {
+    level
+        .chunks(2)
+        .map(|pair| match pair {
+            [left, right] => merkle_node(left, right),
+            [single] => *single,
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+/// Checks that the point `(timestamp, value)` sits at `index` in a series of `leaf_count` points
+/// whose [`TimeSeries::merkle_root`] is `root`.
+///
+/// `proof` is the output of [`TimeSeries::merkle_proof`] for that index.
+pub fn verify_merkle_proof(
+    root: [u8; 32],
+    leaf_count: usize,
+    index: usize,
+    timestamp: u64,
+    value: f64,
+    proof: &[[u8; 32]],
+) -> bool {
+    if index >= leaf_count {
+        return false;
+    }
+    let mut hash = merkle_leaf(timestamp, value);
+    let mut siblings = proof.iter();
+    let (mut index, mut width) = (index, leaf_count);
+    while width > 1 {
+        // The last node of an odd-width level has no sibling and moves up unchanged.
+        if !(index % 2 == 0 && index == width - 1) {
+            let Some(sibling) = siblings.next() else {
+                return false;
+            };
+            hash = if index % 2 == 0 {
+                merkle_node(&hash, sibling)
+            } else {
+                merkle_node(sibling, &hash)
+            };
+        }
+        index /= 2;
+        width = width.div_ceil(2);
+    }
+    siblings.next().is_none() && hash == root
+}
+
+impl TimeSeries {
+    /// Computes the root of a Merkle tree over the points of the series, in stored order.
+    ///
+    /// Each leaf is [`merkle_leaf`] of one point, and each internal node is
+    /// `keccak256(0x01 || left || right)`. The prefixes keep leaves and internal nodes from
+    /// colliding. On a level with an odd number of nodes the last one moves up unchanged rather
+    /// than being paired with a copy of itself. An empty series has an all-zero root.
+    ///
+    /// Unlike the flat values hash, the root lets a single point be disclosed with
+    /// [`TimeSeries::merkle_proof`] and checked with [`verify_merkle_proof`].
+    pub fn merkle_root(&self) -> [u8; 32] {
+        let mut level = self.merkle_leaves();
+        if level.is_empty() {
+            return [0u8; 32];
+        }
+        while level.len() > 1 {
+            level = next_level(&level);
+        }
+        level[0]
+    }
+
+    /// Returns the sibling hashes from the leaf at `index` up to the root, skipping levels where
+    /// the node has no sibling.
+    ///
+    /// # Panics
+    /// Panics if `index` is not a point of the series.
+    pub fn merkle_proof(&self, index: usize) -> Vec<[u8; 32]> {
+        assert!(index < self.values.len(), "Index must be within the series");
+        let mut level = self.merkle_leaves();
+        let mut index = index;
+        let mut proof = Vec::new();
+        while level.len() > 1 {
+            if let Some(sibling) = level.get(index ^ 1) {
+                proof.push(*sibling);
+            }
+            level = next_level(&level);
+            index /= 2;
+        }
+        proof
+    }
+
+    fn merkle_leaves(&self) -> Vec<[u8; 32]> {
+        self.timestamps
+            .iter()
+            .zip(self.values.iter())
+            .map(|(&timestamp, &value)| merkle_leaf(timestamp, value))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_series(n: u64) -> TimeSeries {
+        TimeSeries::new(
+            (0..n).map(|i| i * 3600).collect(),
+            (0..n).map(|i| i as f64 * 1.5).collect(),
+        )
+    }
+
+    #[test]
+    fn test_merkle_proof_round_trip() {
+        for n in 1..=9 {
+            let ts = sample_series(n);
+            let root = ts.merkle_root();
+            for index in 0..n as usize {
+                let proof = ts.merkle_proof(index);
+                let (timestamp, value) = (ts.timestamps[index], ts.values[index]);
+                assert!(verify_merkle_proof(
+                    root, n as usize, index, timestamp, value, &proof
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn test_merkle_proof_rejects_tampering() {
+        let ts = sample_series(7);
+        let root = ts.merkle_root();
+        let proof = ts.merkle_proof(3);
+        assert!(verify_merkle_proof(root, 7, 3, 3 * 3600, 4.5, &proof));
+
+        assert!(!verify_merkle_proof(root, 7, 3, 3 * 3600, 4.6, &proof));
+        assert!(!verify_merkle_proof(root, 7, 3, 3 * 3600 + 1, 4.5, &proof));
+        assert!(!verify_merkle_proof(root, 7, 2, 3 * 3600, 4.5, &proof));
+        assert!(!verify_merkle_proof(root, 7, 3, 3 * 3600, 4.5, &proof[1..]));
+
+        let mut tampered = ts.clone();
+        tampered.values[3] = 4.6;
+        assert_ne!(tampered.merkle_root(), root);
+    }
+
+    #[test]
+    fn test_merkle_root_small_trees() {
+        let single = sample_series(1);
+        assert_eq!(single.merkle_root(), merkle_leaf(0, 0.0));
+        assert!(single.merkle_proof(0).is_empty());
+
+        let pair = sample_series(2);
+        assert_eq!(
+            pair.merkle_root(),
+            merkle_node(&merkle_leaf(0, 0.0), &merkle_leaf(3600, 1.5))
+        );
+        assert_eq!(TimeSeries::new(vec![], vec![]).merkle_root(), [0u8; 32]);
+    }
+}
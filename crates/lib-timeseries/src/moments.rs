@@ -0,0 +1,80 @@
+#[cfg(not(any(feature = "std", test)))]
+use crate::prelude::*;
+use crate::TimeSeries;
+
+impl TimeSeries {
+    /// Calculates the skewness of the values: the third standardized moment,
+    /// `mean((x - mean)^3) / std_dev^3`, using the population standard deviation of
+    /// [`TimeSeries::std_dev`].
+    ///
+    /// Positive skewness means a longer tail to the right. Returns NaN if the values have zero
+    /// variance or the series is empty.
+    pub fn skewness(&self) -> f64 {
+        self.standardized_moment(3)
+    }
+
+    /// Calculates the kurtosis of the values: the fourth standardized moment,
+    /// `mean((x - mean)^4) / std_dev^4`, which is 3 for a normal distribution.
+    ///
+    /// Returns NaN if the values have zero variance or the series is empty.
+    pub fn kurtosis(&self) -> f64 {
+        self.standardized_moment(4)
+    }
+
+    /// Calculates the excess kurtosis, [`TimeSeries::kurtosis`] minus 3, so that a normal
+    /// distribution scores 0 and heavier tails score above it.
+    pub fn excess_kurtosis(&self) -> f64 {
+        self.kurtosis() - 3.0
+    }
+
+    fn standardized_moment(&self, order: i32) -> f64 {
+        let std_dev = self.std_dev();
+        if self.values.is_empty() || std_dev == 0.0 {
+            return f64::NAN;
+        }
+        let mean = self.mean();
+        let moment = self
+            .values
+            .iter()
+            .map(|&value| (value - mean).powi(order))
+            .sum::<f64>()
+            / self.values.len() as f64;
+        moment / std_dev.powi(order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_skewness() {
+        let symmetric = TimeSeries::new((0..5).collect(), vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+        assert!(symmetric.skewness().abs() < 1e-12);
+
+        let right_skewed = TimeSeries::new((0..5).collect(), vec![1.0, 1.0, 1.0, 2.0, 10.0]);
+        assert!(right_skewed.skewness() > 0.0);
+
+        let left_skewed = TimeSeries::new((0..5).collect(), vec![-10.0, -2.0, -1.0, -1.0, -1.0]);
+        assert!((left_skewed.skewness() + right_skewed.skewness()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_kurtosis() {
+        // Two equally likely values have the smallest possible kurtosis, 1.
+        let two_point = TimeSeries::new((0..4).collect(), vec![-1.0, 1.0, -1.0, 1.0]);
+        assert!((two_point.kurtosis() - 1.0).abs() < 1e-12);
+        assert!((two_point.excess_kurtosis() + 2.0).abs() < 1e-12);
+
+        let uniform = TimeSeries::new((0..5).collect(), vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+        assert!((uniform.kurtosis() - 1.7).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_moments_of_constant_series_are_nan() {
+        let flat = TimeSeries::new(vec![1, 2, 3], vec![4.0, 4.0, 4.0]);
+        assert!(flat.skewness().is_nan());
+        assert!(flat.kurtosis().is_nan());
+        assert!(TimeSeries::new(vec![], vec![]).excess_kurtosis().is_nan());
+    }
+}
@@ -0,0 +1,118 @@
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+use crate::{f64_to_i256, sorted_quantile, OrderStatsPublicValuesStruct, TimeSeries};
+
+/// The minimum, maximum and median of a series, computed from a single sort.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct OrderStats {
+    pub min: f64,
+    pub max: f64,
+    pub median: f64,
+}
+
+impl TimeSeries {
+    /// Returns the smallest value, or NaN for an empty series or if any value is NaN.
+    pub fn min(&self) -> f64 {
+        self.extreme(f64::min)
+    }
+
+    /// Returns the largest value, or NaN for an empty series or if any value is NaN.
+    pub fn max(&self) -> f64 {
+        self.extreme(f64::max)
+    }
+
+    /// Folds the values with `pick` in one pass, without sorting or allocating. NaN for an empty
+    /// series or if any value is NaN.
+    fn extreme(&self, pick: fn(f64, f64) -> f64) -> f64 {
+        let Some((&first, rest)) = self.values.split_first() else {
+            return f64::NAN;
+        };
+        rest.iter()
+            .try_fold(first, |extreme, &value| {
+                (!value.is_nan()).then(|| pick(extreme, value))
+            })
+            .filter(|extreme| !first.is_nan() && !extreme.is_nan())
+            .unwrap_or(f64::NAN)
+    }
+
+    /// Computes the minimum, maximum and median of the values with a single sort.
+    ///
+    /// The median matches [`TimeSeries::median`]. Every statistic is NaN for an empty series or
+    /// if any value is NaN.
+    pub fn order_stats(&self) -> OrderStats {
+        if self.values.is_empty() || self.values.iter().any(|v| v.is_nan()) {
+            return OrderStats {
+                min: f64::NAN,
+                max: f64::NAN,
+                median: f64::NAN,
+            };
+        }
+        let mut sorted_values = self.values.clone();
+        sorted_values.sort_by(|a, b| a.total_cmp(b));
+        OrderStats {
+            min: sorted_values[0],
+            max: sorted_values[sorted_values.len() - 1],
+            median: sorted_quantile(&sorted_values, 0.5),
+        }
+    }
+
+    pub fn to_order_stats_public_values(&self) -> OrderStatsPublicValuesStruct {
+        let start_timestamp = *self.timestamps.first().unwrap_or(&0);
+        let end_timestamp = *self.timestamps.last().unwrap_or(&0);
+        let values_hash = self.compute_hash();
+        let stats = self.order_stats();
+
+        OrderStatsPublicValuesStruct {
+            start_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(start_timestamp),
+            end_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(end_timestamp),
+            values_hash: alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(values_hash),
+            min: f64_to_i256(stats.min),
+            max: f64_to_i256(stats.max),
+            median: f64_to_i256(stats.median),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_sol_types::SolValue;
+
+    use super::*;
+    use crate::i256_to_f64;
+
+    #[test]
+    fn test_order_stats_matches_individual_methods() {
+        let ts = TimeSeries::new(vec![1, 2, 3, 4, 5, 6], vec![4.0, -2.5, 9.0, 1.0, 7.5, 3.0]);
+        let stats = ts.order_stats();
+        assert_eq!(stats.min, -2.5);
+        assert_eq!(stats.max, 9.0);
+        assert_eq!(stats.median, 3.5);
+        assert_eq!(ts.median(), 3.5);
+        assert_eq!(ts.min(), -2.5);
+        assert_eq!(ts.max(), 9.0);
+
+        let gappy = TimeSeries::new(vec![1, 2], vec![f64::NAN, 1.0]);
+        assert!(gappy.min().is_nan() && gappy.max().is_nan());
+        assert!(TimeSeries::new(vec![], vec![]).min().is_nan());
+    }
+
+    #[test]
+    fn test_order_stats_public_values_decode() {
+        let ts = TimeSeries::new(vec![10, 20, 30], vec![2.0, -1.0, 5.0]);
+        let bytes = ts.to_order_stats_public_values().abi_encode();
+        let decoded = OrderStatsPublicValuesStruct::abi_decode(&bytes, true).unwrap();
+        assert_eq!(i256_to_f64(decoded.min), ts.min());
+        assert_eq!(i256_to_f64(decoded.max), ts.max());
+        assert_eq!(i256_to_f64(decoded.median), ts.median());
+    }
+
+    #[test]
+    fn test_order_stats_propagates_nan() {
+        let ts = TimeSeries::new(vec![1, 2], vec![1.0, f64::NAN]);
+        assert!(ts.min().is_nan());
+        assert!(ts.max().is_nan());
+        assert!(ts.order_stats().median.is_nan());
+    }
+}
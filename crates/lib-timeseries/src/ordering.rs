@@ -0,0 +1,68 @@
+use crate::prelude::*;
+use crate::TimeSeries;
+
+impl TimeSeries {
+    /// Returns whether the timestamps are in non-decreasing order.
+    ///
+    /// Several methods, such as the forecasts that extend the timestamps by an inferred time step,
+    /// assume ordered input. Duplicate timestamps are allowed here; see
+    /// [`TimeSeries::has_duplicate_timestamps`].
+    pub fn is_monotonic(&self) -> bool {
+        self.timestamps.windows(2).all(|w| w[0] <= w[1])
+    }
+
+    /// Returns whether any timestamp occurs more than once.
+    pub fn has_duplicate_timestamps(&self) -> bool {
+        let mut timestamps = self.timestamps.clone();
+        timestamps.sort_unstable();
+        timestamps.windows(2).any(|w| w[0] == w[1])
+    }
+
+    /// Returns a copy of the series with its points sorted by timestamp.
+    ///
+    /// The values hash commits to points in stored order, so two series holding the same points
+    /// in a different order hash differently. Sorting first gives a canonical order; the sort is
+    /// stable, so points sharing a timestamp keep their relative order.
+    pub fn sorted_by_time(&self) -> TimeSeries {
+        let mut order: Vec<usize> = (0..self.timestamps.len()).collect();
+        order.sort_by_key(|&i| self.timestamps[i]);
+        TimeSeries::new(
+            order.iter().map(|&i| self.timestamps[i]).collect(),
+            order.iter().map(|&i| self.values[i]).collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sorted_by_time_canonicalizes_shuffled_input() {
+        let sorted = TimeSeries::new(vec![10, 20, 30, 40], vec![1.0, 2.0, 3.0, 4.0]);
+        let shuffled = TimeSeries::new(vec![30, 10, 40, 20], vec![3.0, 1.0, 4.0, 2.0]);
+        assert!(sorted.is_monotonic());
+        assert!(!shuffled.is_monotonic());
+        assert_ne!(shuffled.compute_hash(), sorted.compute_hash());
+
+        let canonical = shuffled.sorted_by_time();
+        assert_eq!(canonical.timestamps, sorted.timestamps);
+        assert_eq!(canonical.values, sorted.values);
+        assert_eq!(canonical.compute_hash(), sorted.compute_hash());
+    }
+
+    #[test]
+    fn test_sorted_by_time_is_stable() {
+        let ts = TimeSeries::new(vec![20, 10, 20, 10], vec![1.0, 2.0, 3.0, 4.0]);
+        let sorted = ts.sorted_by_time();
+        assert_eq!(sorted.timestamps, vec![10, 10, 20, 20]);
+        assert_eq!(sorted.values, vec![2.0, 4.0, 1.0, 3.0]);
+        assert!(sorted.is_monotonic());
+    }
+
+    #[test]
+    fn test_has_duplicate_timestamps() {
+        assert!(TimeSeries::new(vec![3, 1, 3], vec![0.0; 3]).has_duplicate_timestamps());
+        assert!(!TimeSeries::new(vec![3, 1, 2], vec![0.0; 3]).has_duplicate_timestamps());
+    }
+}
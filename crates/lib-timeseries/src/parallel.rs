@@ -0,0 +1,70 @@
+//! Multi-threaded statistics for large host-side datasets, behind the `rayon` feature.
+//!
+//! Parallel sums add the values in a different order than the sequential methods, so results can
+//! differ from them in the last bits. Use these to explore data, never to build public values a
+//! guest must reproduce exactly.
+
+use rayon::prelude::*;
+
+use crate::{Summary, TimeSeries};
+
+impl TimeSeries {
+    /// Computes [`TimeSeries::mean`] across threads.
+    pub fn mean_par(&self) -> f64 {
+        let sum: f64 = self.values.par_iter().sum();
+        sum / self.values.len() as f64
+    }
+
+    /// Computes [`TimeSeries::std_dev`] (the population standard deviation) across threads.
+    pub fn std_dev_par(&self) -> f64 {
+        let mean = self.mean_par();
+        let variance = self
+            .values
+            .par_iter()
+            .map(|&value| (value - mean).powi(2))
+            .sum::<f64>()
+            / self.values.len() as f64;
+        variance.sqrt()
+    }
+}
+
+/// Computes [`TimeSeries::summary`] of every series, one series per task.
+///
+/// Each summary is computed sequentially, so it equals the one [`TimeSeries::summary`] returns.
+pub fn batch_summary(series: &[TimeSeries]) -> Vec<Summary> {
+    series.par_iter().map(TimeSeries::summary).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mean_par_matches_mean() {
+        let n = 1_000_000u64;
+        let timestamps: Vec<u64> = (0..n).collect();
+        let values: Vec<f64> = (0..n).map(|i| ((i * 7919) % 1000) as f64 / 7.0).collect();
+        let ts = TimeSeries::new(timestamps, values);
+
+        assert!((ts.mean_par() - ts.mean()).abs() < 1e-9);
+        assert!((ts.std_dev_par() - ts.std_dev()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_batch_summary_matches_summary() {
+        let series: Vec<TimeSeries> = (1..=8u64)
+            .map(|k| {
+                TimeSeries::new(
+                    (0..k * 10).collect(),
+                    (0..k * 10).map(|i| i as f64).collect(),
+                )
+            })
+            .collect();
+        let summaries = batch_summary(&series);
+
+        assert_eq!(summaries.len(), series.len());
+        for (summary, ts) in summaries.iter().zip(series.iter()) {
+            assert_eq!(*summary, ts.summary());
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use crate::prelude::*;
+use crate::TimeSeries;
+
+impl TimeSeries {
+    /// Counts the zero crossings of the values: how often consecutive nonzero values differ in
+    /// sign.
+    ///
+    /// Zeros and NaNs are skipped, so `1, 0, -1` crosses once and `1, 0, 1` does not cross.
+    pub fn sign_changes(&self) -> usize {
+        let mut previous_positive: Option<bool> = None;
+        let mut changes = 0;
+        for &value in self.values.iter().filter(|&&v| v != 0.0 && !v.is_nan()) {
+            let positive = value > 0.0;
+            if previous_positive.is_some_and(|previous| previous != positive) {
+                changes += 1;
+            }
+            previous_positive = Some(positive);
+        }
+        changes
+    }
+
+    /// Finds the local maxima whose prominence exceeds `min_prominence`.
+    ///
+    /// A peak is a value, or a plateau of equal values, with a strictly lower value on both
+    /// sides; the first and last points are never peaks, and a plateau is reported at its first
+    /// point. The prominence of a peak is how far it rises above the higher of its two bases,
+    /// where each base is the lowest value between the peak and the nearest strictly higher
+    /// value on that side, or the end of the series. A NaN next to a value stops it from being a
+    /// peak.
+    ///
+    /// # Returns
+    /// The `(timestamp, value)` of each peak, in order. The pair is what a proof of "the series
+    /// peaked at timestamp T with value V" commits to.
+    pub fn find_peaks(&self, min_prominence: f64) -> Vec<(u64, f64)> {
+        let values = &self.values;
+        let n = values.len();
+        let mut peaks = Vec::new();
+        let mut i = 1;
+        while i + 1 < n {
+            if values[i] > values[i - 1] {
+                let mut end = i;
+                while end + 1 < n && values[end + 1] == values[i] {
+                    end += 1;
+                }
+                if end + 1 < n
+                    && values[end + 1] < values[i]
+                    && self.prominence(i, end) > min_prominence
+                {
+                    peaks.push((self.timestamps[i], values[i]));
+                }
+                i = end + 1;
+            } else {
+                i += 1;
+            }
+        }
+        peaks
+    }
+
+    /// Returns the prominence of the plateau `start..=end`, as described in
+    /// [`TimeSeries::find_peaks`].
+    fn prominence(&self, start: usize, end: usize) -> f64 {
+        let peak = self.values[start];
+        let left_base = self.values[..start]
+            .iter()
+            .rev()
+            .take_while(|&&v| v <= peak)
+            .fold(peak, |base, &v| base.min(v));
+        let right_base = self.values[end + 1..]
+            .iter()
+            .take_while(|&&v| v <= peak)
+            .fold(peak, |base, &v| base.min(v));
+        peak - left_base.max(right_base)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_changes_skips_zeros() {
+        let ts = TimeSeries::new((0..6).collect(), vec![1.0, -1.0, 0.0, -2.0, 3.0, 0.0]);
+        assert_eq!(ts.sign_changes(), 2);
+        let flat = TimeSeries::new(vec![0, 1, 2], vec![0.0, 0.0, 0.0]);
+        assert_eq!(flat.sign_changes(), 0);
+    }
+
+    #[test]
+    fn test_find_peaks_on_noisy_sine() {
+        // Three periods of 20 points, peaking at 5, 25 and 45, with deterministic noise.
+        let values: Vec<f64> = (0..60)
+            .map(|i| {
+                let noise = ((i * 37) % 13) as f64 / 13.0 - 0.5;
+                (2.0 * core::f64::consts::PI * i as f64 / 20.0).sin() + 0.4 * noise
+            })
+            .collect();
+        let ts = TimeSeries::new((0..60).map(|i| i * 10).collect(), values);
+
+        let peaks = ts.find_peaks(1.0);
+        assert_eq!(peaks.len(), 3);
+        for (k, &(timestamp, value)) in peaks.iter().enumerate() {
+            let expected = (5 + 20 * k as u64) * 10;
+            assert!(timestamp.abs_diff(expected) <= 20, "peak at {timestamp}");
+            assert!(value > 0.8);
+        }
+        // The noise adds small local maxima that a zero threshold keeps.
+        assert!(ts.find_peaks(0.0).len() > 3);
+    }
+
+    #[test]
+    fn test_find_peaks_plateaus() {
+        let ts = TimeSeries::new((0..7).collect(), vec![0.0, 1.0, 3.0, 3.0, 3.0, 1.0, 0.0]);
+        assert_eq!(ts.find_peaks(0.0), vec![(2, 3.0)]);
+        // A plateau that keeps rising afterwards is a shoulder, not a peak.
+        let shoulder = TimeSeries::new((0..5).collect(), vec![0.0, 2.0, 2.0, 3.0, 0.0]);
+        assert_eq!(shoulder.find_peaks(0.0), vec![(3, 3.0)]);
+        // The small peak at 2 rises 1 above its higher base at 1.
+        let twin = TimeSeries::new((0..5).collect(), vec![0.0, 2.0, 1.0, 4.0, 0.0]);
+        assert_eq!(twin.find_peaks(0.5), vec![(1, 2.0), (3, 4.0)]);
+        assert_eq!(twin.find_peaks(1.0), vec![(3, 4.0)]);
+    }
+
+    #[test]
+    fn test_find_peaks_flat_series() {
+        let flat = TimeSeries::new((0..5).collect(), vec![2.0; 5]);
+        assert!(flat.find_peaks(0.0).is_empty());
+        assert!(TimeSeries::new(vec![], vec![]).find_peaks(0.0).is_empty());
+    }
+}
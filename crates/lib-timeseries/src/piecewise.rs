@@ -0,0 +1,134 @@
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{f64_to_u256, vec_f64_to_i256, PiecewiseLinearPublicValuesStruct, TimeSeries};
+
+impl TimeSeries {
+    /// Approximates the time series by a piecewise-linear function with bounded error.
+    ///
+    /// Breakpoints are chosen with the Douglas-Peucker algorithm: starting from the segment
+    /// between the first and last points, the point furthest from the segment is added as a
+    /// breakpoint until every point lies within `max_error` of its segment. Error is measured
+    /// vertically, i.e. as the difference between a value and the linear interpolation of the
+    /// enclosing breakpoints at its timestamp.
+    ///
+    /// # Arguments
+    /// * `max_error` - The largest allowed absolute difference between a value and the
+    ///   approximation (max_error >= 0)
+    ///
+    /// # Returns
+    /// The breakpoints, always including the first and last points of the series.
+    pub fn piecewise_linear(&self, max_error: f64) -> TimeSeries {
+        assert!(max_error >= 0.0, "Max error must be non-negative");
+        let n = self.values.len();
+        if n <= 2 {
+            return self.clone();
+        }
+
+        let mut keep = vec![false; n];
+        keep[0] = true;
+        keep[n - 1] = true;
+        let mut segments = vec![(0, n - 1)];
+        while let Some((start, end)) = segments.pop() {
+            let furthest = (start + 1..end)
+                .map(|i| (i, (self.values[i] - self.interpolate(start, end, i)).abs()))
+                .max_by(|a, b| a.1.total_cmp(&b.1));
+            if let Some((index, error)) = furthest {
+                if error > max_error {
+                    keep[index] = true;
+                    segments.push((start, index));
+                    segments.push((index, end));
+                }
+            }
+        }
+
+        let (timestamps, values) = keep
+            .iter()
+            .enumerate()
+            .filter(|(_, &kept)| kept)
+            .map(|(i, _)| (self.timestamps[i], self.values[i]))
+            .unzip();
+        TimeSeries::new(timestamps, values)
+    }
+
+    /// Linearly interpolates between points `start` and `end` at the timestamp of point `i`.
+    fn interpolate(&self, start: usize, end: usize, i: usize) -> f64 {
+        let span = self.timestamps[end].saturating_sub(self.timestamps[start]);
+        if span == 0 {
+            return self.values[start];
+        }
+        let fraction =
+            self.timestamps[i].saturating_sub(self.timestamps[start]) as f64 / span as f64;
+        self.values[start] + fraction * (self.values[end] - self.values[start])
+    }
+
+    pub fn to_piecewise_linear_public_values(
+        &self,
+        max_error: f64,
+    ) -> PiecewiseLinearPublicValuesStruct {
+        let start_timestamp = *self.timestamps.first().unwrap_or(&0);
+        let end_timestamp = *self.timestamps.last().unwrap_or(&0);
+        let values_hash = self.compute_hash();
+        let breakpoints = self.piecewise_linear(max_error);
+
+        PiecewiseLinearPublicValuesStruct {
+            start_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(start_timestamp),
+            end_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(end_timestamp),
+            values_hash: alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(values_hash),
+            max_error: f64_to_u256(max_error),
+            breakpoint_timestamps: breakpoints
+                .timestamps
+                .iter()
+                .map(|&t| alloy_sol_types::private::Uint::<256, 4>::from(t))
+                .collect(),
+            breakpoint_values: vec_f64_to_i256(&breakpoints.values),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_piecewise_linear_recovers_breakpoints() {
+        // Rises to 4 at t = 4, falls back to 0 at t = 8, then stays flat.
+        let timestamps: Vec<u64> = (0..=12).collect();
+        let values = timestamps
+            .iter()
+            .map(|&t| match t {
+                0..=4 => t as f64,
+                5..=8 => 8.0 - t as f64,
+                _ => 0.0,
+            })
+            .collect();
+        let ts = TimeSeries::new(timestamps, values);
+        let breakpoints = ts.piecewise_linear(1e-9);
+        assert_eq!(breakpoints.timestamps, vec![0, 4, 8, 12]);
+        assert_eq!(breakpoints.values, vec![0.0, 4.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_piecewise_linear_respects_max_error() {
+        let timestamps: Vec<u64> = (0..50).map(|i| i * 10).collect();
+        let values: Vec<f64> = (0..50).map(|i| (i as f64 / 5.0).sin()).collect();
+        let ts = TimeSeries::new(timestamps, values);
+        let breakpoints = ts.piecewise_linear(0.05);
+        assert!(breakpoints.values.len() < 50);
+
+        let (bt, bv) = (&breakpoints.timestamps, &breakpoints.values);
+        for (&t, &value) in ts.timestamps.iter().zip(ts.values.iter()) {
+            let end = bt.partition_point(|&b| b < t).max(1);
+            let fraction = (t - bt[end - 1]) as f64 / (bt[end] - bt[end - 1]) as f64;
+            let approximation = bv[end - 1] + fraction * (bv[end] - bv[end - 1]);
+            assert!((value - approximation).abs() <= 0.05 + 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_piecewise_linear_public_values() {
+        let ts = TimeSeries::new(vec![0, 1, 2], vec![0.0, -1.0, -2.0]);
+        let public_values = ts.to_piecewise_linear_public_values(0.0);
+        assert_eq!(public_values.breakpoint_timestamps.len(), 2);
+        assert!(public_values.breakpoint_values[1].is_negative());
+    }
+}
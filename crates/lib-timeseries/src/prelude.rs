@@ -0,0 +1,8 @@
+//! The `alloc` items the modules use, imported with `use crate::prelude::*` so the same code
+//! builds with and without the `std` feature.
+
+pub(crate) use alloc::string::String;
+pub(crate) use alloc::vec::Vec;
+
+#[cfg(not(any(feature = "std", test)))]
+pub(crate) use crate::float::Float;
@@ -0,0 +1,271 @@
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::TimeSeries;
+
+/// The result of a least-squares fit of values against timestamps.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct LinRegResult {
+    /// The change in value per unit of timestamp.
+    pub slope: f64,
+    /// The fitted value at timestamp 0.
+    pub intercept: f64,
+    /// The coefficient of determination of the fit.
+    pub r_squared: f64,
+    /// The residual standard error, `sqrt(SSE / (n - 2))`.
+    pub residual_std_error: f64,
+}
+
+/// A series with its least-squares linear trend removed, as returned by
+/// [`TimeSeries::detrend_with_trend`].
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Detrended {
+    /// The residuals, on the original timestamps.
+    pub series: TimeSeries,
+    /// The slope of the removed trend, per unit of timestamp.
+    pub slope: f64,
+    /// The removed trend's value at timestamp 0.
+    pub intercept: f64,
+}
+
+impl Detrended {
+    /// Adds the removed trend, `slope * timestamp + intercept`, back onto `series`, such as a
+    /// forecast made from the residuals.
+    pub fn restore_trend(&self, series: &TimeSeries) -> TimeSeries {
+        let values = series
+            .timestamps
+            .iter()
+            .zip(series.values.iter())
+            .map(|(&t, &v)| v + self.slope * t as f64 + self.intercept)
+            .collect();
+        TimeSeries::new(series.timestamps.clone(), values)
+    }
+}
+
+impl TimeSeries {
+    /// Fits `value = slope * timestamp + intercept` by ordinary least squares.
+    ///
+    /// Timestamps are centered on their mean before fitting, so large Unix timestamps do not lose
+    /// precision in the sums of squares. Points are weighted equally regardless of their spacing.
+    ///
+    /// Degenerate inputs are handled as follows:
+    /// * If every timestamp is the same (including a single point), the slope is `0.0` and the
+    ///   intercept is the mean value.
+    /// * If the values are constant, the fit is exact and `r_squared` is `1.0`.
+    /// * With fewer than three points the residual standard error is NaN.
+    /// * An empty series returns NaN for every field.
+    pub fn linear_regression(&self) -> LinRegResult {
+        let n = self.values.len();
+        if n == 0 {
+            return LinRegResult {
+                slope: f64::NAN,
+                intercept: f64::NAN,
+                r_squared: f64::NAN,
+                residual_std_error: f64::NAN,
+            };
+        }
+
+        let mean_t = self.timestamps.iter().map(|&t| t as f64).sum::<f64>() / n as f64;
+        let mean_v = self.mean();
+        let mut sxx = 0.0;
+        let mut sxy = 0.0;
+        let mut syy = 0.0;
+        for (&t, &v) in self.timestamps.iter().zip(self.values.iter()) {
+            let dt = t as f64 - mean_t;
+            let dv = v - mean_v;
+            sxx += dt * dt;
+            sxy += dt * dv;
+            syy += dv * dv;
+        }
+
+        let slope = if sxx == 0.0 { 0.0 } else { sxy / sxx };
+        let sse: f64 = self
+            .timestamps
+            .iter()
+            .zip(self.values.iter())
+            .map(|(&t, &v)| {
+                let residual = v - mean_v - slope * (t as f64 - mean_t);
+                residual * residual
+            })
+            .sum();
+        let r_squared = if syy == 0.0 { 1.0 } else { 1.0 - sse / syy };
+        let residual_std_error = if n < 3 {
+            f64::NAN
+        } else {
+            (sse / (n - 2) as f64).sqrt()
+        };
+
+        LinRegResult {
+            slope,
+            intercept: mean_v - slope * mean_t,
+            r_squared,
+            residual_std_error,
+        }
+    }
+
+    /// Returns the coefficient of determination of [`TimeSeries::linear_regression`].
+    pub fn r_squared(&self) -> f64 {
+        self.linear_regression().r_squared
+    }
+
+    /// Subtracts the [`TimeSeries::linear_regression`] fit, `slope * timestamp + intercept`,
+    /// from each value, leaving the residuals on the same timestamps.
+    ///
+    /// The fitted line is evaluated about the mean timestamp rather than from the intercept, so
+    /// large Unix timestamps do not cost the residuals their precision.
+    pub fn detrend(&self) -> TimeSeries {
+        self.detrend_with_trend().series
+    }
+
+    /// Like [`TimeSeries::detrend`], but also returns the slope and intercept of the removed
+    /// trend so it can be added back with [`Detrended::restore_trend`].
+    ///
+    /// An empty series is returned unchanged with a NaN slope and intercept.
+    pub fn detrend_with_trend(&self) -> Detrended {
+        let fit = self.linear_regression();
+        if self.values.is_empty() {
+            return Detrended {
+                series: self.clone(),
+                slope: fit.slope,
+                intercept: fit.intercept,
+            };
+        }
+        let n = self.timestamps.len() as f64;
+        let mean_t = self.timestamps.iter().map(|&t| t as f64).sum::<f64>() / n;
+        let mean_v = self.mean();
+        let values = self
+            .timestamps
+            .iter()
+            .zip(self.values.iter())
+            .map(|(&t, &v)| v - mean_v - fit.slope * (t as f64 - mean_t))
+            .collect();
+        Detrended {
+            series: TimeSeries::new(self.timestamps.clone(), values),
+            slope: fit.slope,
+            intercept: fit.intercept,
+        }
+    }
+
+    /// Subtracts the mean from each value, a simpler alternative to [`TimeSeries::detrend`] that
+    /// removes only the level.
+    pub fn detrend_mean(&self) -> TimeSeries {
+        let mean = self.mean();
+        TimeSeries::new(
+            self.timestamps.clone(),
+            self.values.iter().map(|v| v - mean).collect(),
+        )
+    }
+
+    /// Removes a constant trend, the mean, from each value. The same as
+    /// [`TimeSeries::detrend_mean`], under the name that pairs with [`TimeSeries::detrend`].
+    pub fn detrend_constant(&self) -> TimeSeries {
+        self.detrend_mean()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_linear_regression_exact_line() {
+        let base = 1_700_000_000u64;
+        let timestamps: Vec<u64> = (0..10).map(|i| base + i * 60).collect();
+        let values = timestamps
+            .iter()
+            .map(|&t| 0.5 * (t - base) as f64 + 3.0)
+            .collect();
+        let fit = TimeSeries::new(timestamps, values).linear_regression();
+        assert!((fit.slope - 0.5).abs() < 1e-12);
+        assert!((fit.intercept - (3.0 - 0.5 * base as f64)).abs() < 1e-3);
+        assert!((fit.r_squared - 1.0).abs() < 1e-12);
+        assert!(fit.residual_std_error < 1e-9);
+    }
+
+    #[test]
+    fn test_linear_regression_noisy_series() {
+        let base = 1_700_000_000u64;
+        let noise = [0.3, -0.2, 0.1, -0.4, 0.2, 0.0, -0.1, 0.3, -0.3, 0.1];
+        let timestamps: Vec<u64> = (0..10).map(|i| base + i * 3600).collect();
+        let values = (0..10).map(|i| 2.0 * i as f64 + noise[i]).collect();
+        let ts = TimeSeries::new(timestamps, values);
+        let fit = ts.linear_regression();
+        assert!((fit.slope * 3600.0 - 2.0).abs() < 0.1);
+        assert!(ts.r_squared() > 0.99 && ts.r_squared() < 1.0);
+        assert!(fit.residual_std_error > 0.0);
+    }
+
+    #[test]
+    fn test_linear_regression_constant_series() {
+        let ts = TimeSeries::new(vec![10, 20, 30, 40], vec![7.0, 7.0, 7.0, 7.0]);
+        let fit = ts.linear_regression();
+        assert_eq!(fit.slope, 0.0);
+        assert_eq!(fit.intercept, 7.0);
+        assert_eq!(fit.r_squared, 1.0);
+        assert_eq!(fit.residual_std_error, 0.0);
+    }
+
+    #[test]
+    fn test_linear_regression_irregular_spacing() {
+        // Using indices instead of timestamps would give a different slope.
+        let ts = TimeSeries::new(vec![0, 1, 5, 6], vec![1.0, 2.0, 4.0, 3.0]);
+        let fit = ts.linear_regression();
+        // Mean timestamp 3, mean value 2.5, sxx = 26, sxy = 10.
+        assert!((fit.slope - 10.0 / 26.0).abs() < 1e-12);
+        assert!((fit.intercept - (2.5 - 3.0 * 10.0 / 26.0)).abs() < 1e-12);
+        // syy = 5, so r² = sxy² / (sxx * syy).
+        assert!((fit.r_squared - 100.0 / 130.0).abs() < 1e-12);
+        let sse = 5.0 - 100.0 / 26.0;
+        assert!((fit.residual_std_error - (sse / 2.0f64).sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_detrend_removes_linear_trend() {
+        let base = 1_700_000_000u64;
+        let noise = [0.3, -0.2, 0.1, -0.4, 0.2, 0.0, -0.1, 0.3, -0.3, 0.1];
+        let timestamps: Vec<u64> = (0..10).map(|i| base + i * 3600).collect();
+        let values = (0..10).map(|i| 5.0 + 2.0 * i as f64 + noise[i]).collect();
+        let ts = TimeSeries::new(timestamps, values);
+
+        let detrended = ts.detrend();
+        assert_eq!(detrended.timestamps, ts.timestamps);
+        assert!(detrended.mean().abs() < 1e-12);
+        assert!(detrended.linear_regression().slope.abs() < 1e-12);
+        // What is left is close to the noise, shifted by its small fitted trend.
+        for (residual, noise) in detrended.values.iter().zip(noise) {
+            assert!((residual - noise).abs() < 0.2);
+        }
+
+        let demeaned = ts.detrend_mean();
+        assert!(demeaned.mean().abs() < 1e-12);
+        assert_eq!(ts.detrend_constant().values, demeaned.values);
+        assert!((demeaned.linear_regression().slope * 3600.0 - 2.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_detrend_exact_line_leaves_zeros() {
+        let base = 1_700_000_000u64;
+        let timestamps: Vec<u64> = (0..20).map(|i| base + i * 60).collect();
+        let values = timestamps
+            .iter()
+            .map(|&t| 42.0 - 0.25 * (t - base) as f64)
+            .collect();
+        let ts = TimeSeries::new(timestamps, values);
+
+        let detrended = ts.detrend_with_trend();
+        assert_eq!(detrended.series.timestamps, ts.timestamps);
+        assert!(detrended.series.values.iter().all(|v| v.abs() < 1e-9));
+        assert!((detrended.slope + 0.25).abs() < 1e-12);
+        assert_eq!(ts.detrend().values, detrended.series.values);
+
+        // Re-adding the trend recovers the series, up to the precision of the intercept.
+        let restored = detrended.restore_trend(&detrended.series);
+        for (r, v) in restored.values.iter().zip(&ts.values) {
+            assert!((r - v).abs() < 1e-4);
+        }
+    }
+}
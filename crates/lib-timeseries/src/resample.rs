@@ -0,0 +1,468 @@
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+use crate::{TimeSeries, TimeSeriesError};
+
+/// How [`TimeSeries::resample`] fills grid points that fall between observations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum ResampleMethod {
+    /// The value of the latest observation at or before the grid point.
+    ForwardFill,
+    /// Linear interpolation between the observations on either side of the grid point.
+    LinearInterpolate,
+    /// The value of the closest observation, preferring the earlier one on ties.
+    NearestNeighbor,
+}
+
+/// How [`TimeSeries::resample_aggregate`] combines the observations in one bucket.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum Aggregation {
+    /// The arithmetic mean of the values.
+    Mean,
+    /// The sum of the values.
+    Sum,
+    /// The smallest value.
+    Min,
+    /// The largest value.
+    Max,
+    /// The value of the latest observation.
+    Last,
+    /// The number of observations.
+    Count,
+}
+
+impl Aggregation {
+    /// Combines a non-empty bucket of values, given in timestamp order.
+    fn apply(self, values: &[f64]) -> f64 {
+        match self {
+            Aggregation::Mean => values.iter().sum::<f64>() / values.len() as f64,
+            Aggregation::Sum => values.iter().sum(),
+            Aggregation::Min => values.iter().copied().fold(f64::INFINITY, f64::min),
+            Aggregation::Max => values.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+            Aggregation::Last => values[values.len() - 1],
+            Aggregation::Count => values.len() as f64,
+        }
+    }
+}
+
+/// What [`TimeSeries::resample_aggregate`] outputs for a bucket without observations.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum FillPolicy {
+    /// Omit the bucket from the output.
+    Skip,
+    /// Repeat the value of the previous bucket.
+    ForwardFill,
+    /// Output the given value, e.g. `0.0` for counts or NaN to mark the gap.
+    Constant(f64),
+}
+
+/// What [`TimeSeries::interpolate_linear`] does with a target timestamp before the first or
+/// after the last observation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum Extrapolation {
+    /// Fail with [`TimeSeriesError::TimestampOutOfRange`].
+    Error,
+    /// Use the value of the nearest end of the series.
+    Clamp,
+}
+
+impl TimeSeries {
+    /// Evaluates the series at arbitrary timestamps by linear interpolation between the
+    /// observations on either side.
+    ///
+    /// A target equal to an observed timestamp takes that observation's value; among
+    /// observations sharing a timestamp the last one in the input wins, as in
+    /// [`TimeSeries::resample`]. The series is sorted by timestamp first if it is not already
+    /// monotonic. Both sequences are then walked once, so this takes O(n + m) time for `n`
+    /// points and `m` targets.
+    ///
+    /// # Arguments
+    /// * `target_timestamps` - The timestamps to evaluate, in non-decreasing order
+    /// * `extrapolation` - How to handle targets outside the observed range
+    ///
+    /// # Errors
+    /// Returns [`TimeSeriesError::InsufficientData`] for an empty series,
+    /// [`TimeSeriesError::InvalidParameter`] if the targets are not in non-decreasing order,
+    /// and [`TimeSeriesError::TimestampOutOfRange`] for the first target outside the series when
+    /// `extrapolation` is [`Extrapolation::Error`].
+    pub fn interpolate_linear(
+        &self,
+        target_timestamps: &[u64],
+        extrapolation: Extrapolation,
+    ) -> Result<TimeSeries, TimeSeriesError> {
+        if self.values.is_empty() {
+            return Err(TimeSeriesError::InsufficientData {
+                required: 1,
+                actual: 0,
+            });
+        }
+        if target_timestamps.windows(2).any(|pair| pair[1] < pair[0]) {
+            return Err(TimeSeriesError::InvalidParameter {
+                name: "target_timestamps",
+            });
+        }
+        let TimeSeries { timestamps, values } = self.deduplicated_by_time();
+        let (first, last) = (timestamps[0], timestamps[timestamps.len() - 1]);
+
+        // `next` is the index of the first observation after the current target.
+        let mut next = 0;
+        let mut interpolated = Vec::with_capacity(target_timestamps.len());
+        for &t in target_timestamps {
+            if t < first || t > last {
+                if extrapolation == Extrapolation::Error {
+                    return Err(TimeSeriesError::TimestampOutOfRange { timestamp: t });
+                }
+                interpolated.push(if t < first {
+                    values[0]
+                } else {
+                    values[values.len() - 1]
+                });
+                continue;
+            }
+            while next < timestamps.len() && timestamps[next] <= t {
+                next += 1;
+            }
+            let (previous_t, previous_v) = (timestamps[next - 1], values[next - 1]);
+            let value = if previous_t == t || next == timestamps.len() {
+                previous_v
+            } else {
+                let fraction = (t - previous_t) as f64 / (timestamps[next] - previous_t) as f64;
+                previous_v + fraction * (values[next] - previous_v)
+            };
+            interpolated.push(value);
+        }
+        Ok(TimeSeries::new(target_timestamps.to_vec(), interpolated))
+    }
+
+    /// Returns the series sorted by timestamp with only the last observation, in input order,
+    /// at each timestamp. Skips the sort when the series is already monotonic.
+    pub(crate) fn deduplicated_by_time(&self) -> TimeSeries {
+        let sorted;
+        let source = if self.is_monotonic() {
+            self
+        } else {
+            sorted = self.sorted_by_time();
+            &sorted
+        };
+        let mut timestamps: Vec<u64> = Vec::with_capacity(source.timestamps.len());
+        let mut values: Vec<f64> = Vec::with_capacity(source.values.len());
+        for (&t, &v) in source.timestamps.iter().zip(source.values.iter()) {
+            if timestamps.last() == Some(&t) {
+                *values.last_mut().expect("one value per timestamp") = v;
+            } else {
+                timestamps.push(t);
+                values.push(v);
+            }
+        }
+        TimeSeries::new(timestamps, values)
+    }
+
+    /// Aggregates the series into fixed-width time buckets.
+    ///
+    /// Bucket `k` covers `[start + k * interval, start + (k + 1) * interval)`, where `start` is
+    /// the earliest timestamp, and is output at its start timestamp. A timestamp exactly on a
+    /// boundary belongs to the bucket it starts. The last bucket is the one holding the latest
+    /// timestamp and is output even though it may cover less than `interval` of data.
+    /// Observations are sorted by timestamp first, so out-of-order input is accepted; among
+    /// observations sharing a timestamp the later one in the input counts as later.
+    ///
+    /// # Arguments
+    /// * `interval` - The width of each bucket
+    /// * `agg` - How to combine the values in a bucket
+    /// * `fill` - What to output for buckets without observations
+    ///
+    /// # Panics
+    /// Panics if `interval` is zero.
+    pub fn resample_aggregate(
+        &self,
+        interval: u64,
+        agg: Aggregation,
+        fill: FillPolicy,
+    ) -> TimeSeries {
+        assert!(interval > 0, "Interval must be positive");
+        let mut points: Vec<(u64, f64)> = self
+            .timestamps
+            .iter()
+            .copied()
+            .zip(self.values.iter().copied())
+            .collect();
+        points.sort_by_key(|&(t, _)| t);
+        let Some(&(start, _)) = points.first() else {
+            return TimeSeries::new(vec![], vec![]);
+        };
+
+        let mut timestamps = Vec::new();
+        let mut values: Vec<f64> = Vec::new();
+        let mut next_bucket = 0;
+        for bucket in points.chunk_by(|a, b| (a.0 - start) / interval == (b.0 - start) / interval) {
+            let index = (bucket[0].0 - start) / interval;
+            // Every bucket before `index` that has not been output yet is empty.
+            if fill != FillPolicy::Skip {
+                for empty in next_bucket..index {
+                    let value = match fill {
+                        FillPolicy::ForwardFill => values[values.len() - 1],
+                        FillPolicy::Constant(value) => value,
+                        FillPolicy::Skip => unreachable!(),
+                    };
+                    timestamps.push(start + empty * interval);
+                    values.push(value);
+                }
+            }
+            let bucket_values: Vec<f64> = bucket.iter().map(|&(_, v)| v).collect();
+            timestamps.push(start + index * interval);
+            values.push(agg.apply(&bucket_values));
+            next_bucket = index + 1;
+        }
+        TimeSeries::new(timestamps, values)
+    }
+
+    /// Resamples the series onto evenly spaced timestamps.
+    ///
+    /// The grid starts at the first timestamp and advances by `interval` up to and including the
+    /// last timestamp. Observations are sorted by timestamp first, so out-of-order input is
+    /// accepted; among observations sharing a timestamp the last one in the input wins.
+    ///
+    /// # Arguments
+    /// * `interval` - The spacing of the output timestamps
+    /// * `method` - How to fill grid points between observations
+    ///
+    /// # Panics
+    /// Panics if `interval` is zero.
+    pub fn resample(&self, interval: u64, method: ResampleMethod) -> TimeSeries {
+        assert!(interval > 0, "Interval must be positive");
+        let mut points: Vec<(u64, f64)> = self
+            .timestamps
+            .iter()
+            .copied()
+            .zip(self.values.iter().copied())
+            .collect();
+        points.sort_by_key(|&(t, _)| t);
+        let (Some(&(first, _)), Some(&(last, _))) = (points.first(), points.last()) else {
+            return TimeSeries::new(vec![], vec![]);
+        };
+
+        let mut timestamps = Vec::new();
+        let mut values = Vec::new();
+        let mut t = first;
+        loop {
+            // `previous` is the last observation at or before `t`; the first one always is.
+            let after = points.partition_point(|&(pt, _)| pt <= t);
+            let (previous_t, previous_v) = points[after - 1];
+            let value = match (method, points.get(after)) {
+                (ResampleMethod::ForwardFill, _) | (_, None) => previous_v,
+                (ResampleMethod::LinearInterpolate, Some(&(next_t, next_v))) => {
+                    let fraction = (t - previous_t) as f64 / (next_t - previous_t) as f64;
+                    previous_v + fraction * (next_v - previous_v)
+                }
+                (ResampleMethod::NearestNeighbor, Some(&(next_t, next_v))) => {
+                    if t - previous_t <= next_t - t {
+                        previous_v
+                    } else {
+                        next_v
+                    }
+                }
+            };
+            timestamps.push(t);
+            values.push(value);
+            match t.checked_add(interval) {
+                Some(next) if next <= last => t = next,
+                _ => break,
+            }
+        }
+        TimeSeries::new(timestamps, values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gappy_series() -> TimeSeries {
+        // A gap of three intervals between t = 10 and t = 40.
+        TimeSeries::new(vec![0, 10, 40, 50], vec![1.0, 2.0, 8.0, 9.0])
+    }
+
+    #[test]
+    fn test_resample_forward_fill() {
+        let resampled = gappy_series().resample(10, ResampleMethod::ForwardFill);
+        assert_eq!(resampled.timestamps, vec![0, 10, 20, 30, 40, 50]);
+        assert_eq!(resampled.values, vec![1.0, 2.0, 2.0, 2.0, 8.0, 9.0]);
+    }
+
+    #[test]
+    fn test_resample_linear_interpolate() {
+        let resampled = gappy_series().resample(10, ResampleMethod::LinearInterpolate);
+        assert_eq!(resampled.values, vec![1.0, 2.0, 4.0, 6.0, 8.0, 9.0]);
+
+        let finer = gappy_series().resample(4, ResampleMethod::LinearInterpolate);
+        assert_eq!(finer.timestamps, (0..=48).step_by(4).collect::<Vec<u64>>());
+        assert_eq!(finer.values[1], 1.4);
+    }
+
+    #[test]
+    fn test_resample_nearest_neighbor() {
+        let resampled = gappy_series().resample(5, ResampleMethod::NearestNeighbor);
+        assert_eq!(
+            resampled.timestamps,
+            (0..=50).step_by(5).collect::<Vec<u64>>()
+        );
+        // t = 5 ties between 0 and 10 and takes the earlier; t = 25 ties and takes 10.
+        assert_eq!(
+            resampled.values,
+            vec![1.0, 1.0, 2.0, 2.0, 2.0, 2.0, 8.0, 8.0, 8.0, 8.0, 9.0]
+        );
+    }
+
+    #[test]
+    fn test_resample_sorts_out_of_order_input() {
+        let shuffled = TimeSeries::new(vec![40, 0, 50, 10], vec![8.0, 1.0, 9.0, 2.0]);
+        assert_eq!(
+            shuffled
+                .resample(10, ResampleMethod::LinearInterpolate)
+                .values,
+            gappy_series()
+                .resample(10, ResampleMethod::LinearInterpolate)
+                .values
+        );
+    }
+
+    #[test]
+    fn test_interpolate_linear_hits_and_midpoints() {
+        let ts = gappy_series();
+        let interpolated = ts
+            .interpolate_linear(&[0, 5, 10, 25, 40, 50], Extrapolation::Error)
+            .unwrap();
+        assert_eq!(interpolated.timestamps, vec![0, 5, 10, 25, 40, 50]);
+        assert_eq!(interpolated.values, vec![1.0, 1.5, 2.0, 5.0, 8.0, 9.0]);
+
+        let repeated = ts
+            .interpolate_linear(&[20, 20], Extrapolation::Error)
+            .unwrap();
+        assert_eq!(repeated.values, vec![4.0, 4.0]);
+    }
+
+    #[test]
+    fn test_interpolate_linear_duplicate_source_timestamps() {
+        let ts = TimeSeries::new(vec![0, 10, 10, 20], vec![0.0, 5.0, 7.0, 9.0]);
+        let interpolated = ts
+            .interpolate_linear(&[5, 10, 15], Extrapolation::Error)
+            .unwrap();
+        // The later observation at t = 10 wins, on both sides of it.
+        assert_eq!(interpolated.values, vec![3.5, 7.0, 8.0]);
+
+        let shuffled = TimeSeries::new(vec![40, 0, 50, 10], vec![8.0, 1.0, 9.0, 2.0]);
+        assert_eq!(
+            shuffled
+                .interpolate_linear(&[25], Extrapolation::Error)
+                .unwrap()
+                .values,
+            vec![5.0]
+        );
+    }
+
+    #[test]
+    fn test_interpolate_linear_extrapolation() {
+        let ts = TimeSeries::new(vec![10, 20], vec![1.0, 3.0]);
+        let clamped = ts
+            .interpolate_linear(&[0, 15, 30], Extrapolation::Clamp)
+            .unwrap();
+        assert_eq!(clamped.values, vec![1.0, 2.0, 3.0]);
+        assert_eq!(
+            ts.interpolate_linear(&[15, 30], Extrapolation::Error).err(),
+            Some(TimeSeriesError::TimestampOutOfRange { timestamp: 30 })
+        );
+        assert_eq!(
+            ts.interpolate_linear(&[15, 12], Extrapolation::Clamp).err(),
+            Some(TimeSeriesError::InvalidParameter {
+                name: "target_timestamps"
+            })
+        );
+        assert_eq!(
+            TimeSeries::new(vec![], vec![])
+                .interpolate_linear(&[1], Extrapolation::Clamp)
+                .err(),
+            Some(TimeSeriesError::InsufficientData {
+                required: 1,
+                actual: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_resample_empty_series() {
+        let empty = TimeSeries::new(vec![], vec![]);
+        assert!(empty
+            .resample(10, ResampleMethod::ForwardFill)
+            .values
+            .is_empty());
+        assert!(empty
+            .resample_aggregate(10, Aggregation::Sum, FillPolicy::ForwardFill)
+            .values
+            .is_empty());
+    }
+
+    fn irregular_series() -> TimeSeries {
+        // Buckets of 10 from t = 100: [100, 110) holds three points, t = 110 sits exactly on
+        // the next boundary, and the last bucket [160, 170) is partial.
+        TimeSeries::new(
+            vec![100, 104, 109, 110, 118, 163],
+            vec![4.0, 1.0, 7.0, 2.0, 6.0, 5.0],
+        )
+    }
+
+    #[test]
+    fn test_resample_aggregate_each_aggregation() {
+        let ts = irregular_series();
+        let resample = |agg| ts.resample_aggregate(10, agg, FillPolicy::Skip);
+        assert_eq!(resample(Aggregation::Mean).timestamps, vec![100, 110, 160]);
+        assert_eq!(resample(Aggregation::Mean).values, vec![4.0, 4.0, 5.0]);
+        assert_eq!(resample(Aggregation::Sum).values, vec![12.0, 8.0, 5.0]);
+        assert_eq!(resample(Aggregation::Min).values, vec![1.0, 2.0, 5.0]);
+        assert_eq!(resample(Aggregation::Max).values, vec![7.0, 6.0, 5.0]);
+        assert_eq!(resample(Aggregation::Last).values, vec![7.0, 6.0, 5.0]);
+        assert_eq!(resample(Aggregation::Count).values, vec![3.0, 2.0, 1.0]);
+    }
+
+    #[test]
+    fn test_resample_aggregate_fill_policies() {
+        let ts = irregular_series();
+        let filled = ts.resample_aggregate(10, Aggregation::Count, FillPolicy::Constant(0.0));
+        assert_eq!(filled.timestamps, vec![100, 110, 120, 130, 140, 150, 160]);
+        assert_eq!(filled.values, vec![3.0, 2.0, 0.0, 0.0, 0.0, 0.0, 1.0]);
+
+        let forward = ts.resample_aggregate(10, Aggregation::Last, FillPolicy::ForwardFill);
+        assert_eq!(forward.values, vec![7.0, 6.0, 6.0, 6.0, 6.0, 6.0, 5.0]);
+    }
+
+    #[test]
+    fn test_resample_aggregate_large_gap() {
+        // A year-long gap between two days of hourly readings.
+        let mut timestamps: Vec<u64> = (0..24).map(|h| h * 3600).collect();
+        timestamps.extend((0..24).map(|h| 365 * 86400 + h * 3600));
+        let ts = TimeSeries::new(timestamps, (0..48).map(f64::from).collect());
+
+        let skipped = ts.resample_aggregate(86400, Aggregation::Mean, FillPolicy::Skip);
+        assert_eq!(skipped.timestamps, vec![0, 365 * 86400]);
+        assert_eq!(skipped.values, vec![11.5, 35.5]);
+
+        let filled =
+            ts.resample_aggregate(86400, Aggregation::Mean, FillPolicy::Constant(f64::NAN));
+        assert_eq!(filled.values.len(), 366);
+        assert_eq!(filled.timestamps[200], 200 * 86400);
+        assert!(filled.values[1..365].iter().all(|v| v.is_nan()));
+        assert_eq!(filled.values[365], 35.5);
+    }
+
+    #[test]
+    fn test_resample_aggregate_sorts_and_keeps_input_order_on_ties() {
+        let shuffled = TimeSeries::new(vec![20, 0, 20, 5], vec![1.0, 2.0, 3.0, 4.0]);
+        let last = shuffled.resample_aggregate(10, Aggregation::Last, FillPolicy::Skip);
+        assert_eq!(last.timestamps, vec![0, 20]);
+        assert_eq!(last.values, vec![4.0, 3.0]);
+    }
+}
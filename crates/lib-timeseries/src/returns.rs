@@ -0,0 +1,213 @@
+#[cfg(not(any(feature = "std", test)))]
+use crate::prelude::*;
+use crate::{detmath, TimeSeries, TimeSeriesError};
+
+impl TimeSeries {
+    /// Computes the relative change over `periods` points.
+    ///
+    /// Each output value is `(values[i] - values[i - periods]) / values[i - periods]` at the later
+    /// timestamp, so the output is `periods` points shorter (and empty if the series has no more
+    /// than `periods` points). The change from a zero value is undefined and is returned as NaN,
+    /// never as an infinity, so it stands out rather than dominating a later sum.
+    ///
+    /// # Panics
+    /// Panics if `periods` is 0.
+    pub fn pct_change(&self, periods: usize) -> TimeSeries {
+        assert!(periods > 0, "Periods must be positive");
+        let values = self
+            .values
+            .iter()
+            .zip(self.values.iter().skip(periods))
+            .map(|(&previous, &current)| {
+                if previous == 0.0 {
+                    f64::NAN
+                } else {
+                    (current - previous) / previous
+                }
+            })
+            .collect();
+        TimeSeries::new(
+            self.timestamps.iter().skip(periods).copied().collect(),
+            values,
+        )
+    }
+
+    /// Computes the logarithmic returns `ln(values[i] / values[i - 1])`.
+    ///
+    /// The output is one point shorter, at the later timestamps. Log returns add up over time,
+    /// so `log_returns()?.std_dev()` is the realized volatility per time step. The logarithm
+    /// uses [`detmath::ln`] so the host and the zkVM agree exactly.
+    ///
+    /// # Errors
+    /// Returns [`TimeSeriesError::NonPositiveValue`] for the first value that is zero, negative
+    /// or NaN, since its logarithm is undefined.
+    pub fn log_returns(&self) -> Result<TimeSeries, TimeSeriesError> {
+        if let Some(index) = self.values.iter().position(|&v| v.is_nan() || v <= 0.0) {
+            return Err(TimeSeriesError::NonPositiveValue { index });
+        }
+        let values = self
+            .values
+            .windows(2)
+            .map(|w| detmath::ln(w[1] / w[0]))
+            .collect();
+        Ok(TimeSeries::new(
+            self.timestamps.iter().skip(1).copied().collect(),
+            values,
+        ))
+    }
+
+    /// Computes the annualized Sharpe ratio, treating the values as per-period returns.
+    ///
+    /// `risk_free_rate` is an annual rate and is spread evenly over the periods, so the ratio is
+    /// `mean(r - risk_free_rate / periods_per_year) / s * sqrt(periods_per_year)`, where `s` is the
+    /// sample (`n - 1`) standard deviation of the returns. Returns `None` for fewer than two
+    /// points or when the returns have zero standard deviation.
+    ///
+    /// # Panics
+    /// Panics if `periods_per_year` is not positive.
+    pub fn sharpe_ratio(&self, risk_free_rate: f64, periods_per_year: f64) -> Option<f64> {
+        assert!(periods_per_year > 0.0, "Periods per year must be positive");
+        let std_dev = self.return_std_dev()?;
+        let excess = self.mean() - risk_free_rate / periods_per_year;
+        Some(excess / std_dev * periods_per_year.sqrt())
+    }
+
+    /// Computes the annualized volatility, treating the values as per-period returns.
+    ///
+    /// Variance grows linearly with the number of periods, so the sample standard deviation of the
+    /// returns scales by `sqrt(periods_per_year)`, not by `periods_per_year`. Returns `None` for
+    /// fewer than two points or when the returns have zero standard deviation.
+    ///
+    /// # Panics
+    /// Panics if `periods_per_year` is not positive.
+    pub fn annualized_volatility(&self, periods_per_year: f64) -> Option<f64> {
+        assert!(periods_per_year > 0.0, "Periods per year must be positive");
+        Some(self.return_std_dev()? * periods_per_year.sqrt())
+    }
+
+    /// The sample standard deviation of the values, or `None` if it is undefined or zero.
+    fn return_std_dev(&self) -> Option<f64> {
+        let n = self.values.len();
+        if n < 2 {
+            return None;
+        }
+        let mean = self.mean();
+        let variance = self
+            .values
+            .iter()
+            .map(|&value| (value - mean).powi(2))
+            .sum::<f64>()
+            / (n - 1) as f64;
+        let std_dev = variance.sqrt();
+        (std_dev > 0.0).then_some(std_dev)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_returns_of_doubling_series() {
+        let ts = TimeSeries::new(vec![1, 2, 3, 4], vec![1.0, 2.0, 4.0, 8.0]);
+
+        let pct = ts.pct_change(1);
+        assert_eq!(pct.timestamps, vec![2, 3, 4]);
+        assert_eq!(pct.values, vec![1.0, 1.0, 1.0]);
+
+        let log_returns = ts.log_returns().unwrap();
+        assert_eq!(log_returns.timestamps, vec![2, 3, 4]);
+        for r in &log_returns.values {
+            assert!((r - core::f64::consts::LN_2).abs() < 1e-15);
+        }
+        assert!(log_returns.std_dev() < 1e-15);
+    }
+
+    #[test]
+    fn test_pct_change_from_zero_is_nan() {
+        let ts = TimeSeries::new(vec![1, 2, 3], vec![0.0, 5.0, 10.0]);
+        let pct = ts.pct_change(1);
+        assert!(pct.values[0].is_nan());
+        assert_eq!(pct.values[1], 1.0);
+        assert!(TimeSeries::new(vec![], vec![])
+            .pct_change(1)
+            .values
+            .is_empty());
+    }
+
+    #[test]
+    fn test_pct_change_over_periods() {
+        let ts = TimeSeries::new(vec![1, 2, 3], vec![100.0, 110.0, 121.0]);
+        let pct = ts.pct_change(1);
+        assert_eq!(pct.timestamps, vec![2, 3]);
+        for r in &pct.values {
+            assert!((r - 0.1).abs() < 1e-12);
+        }
+
+        let pct = ts.pct_change(2);
+        assert_eq!(pct.timestamps, vec![3]);
+        assert!((pct.values[0] - 0.21).abs() < 1e-12);
+        assert!(ts.pct_change(3).values.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Periods must be positive")]
+    fn test_pct_change_rejects_zero_periods() {
+        TimeSeries::new(vec![1], vec![1.0]).pct_change(0);
+    }
+
+    #[test]
+    fn test_log_returns_reject_non_positive_values() {
+        let ts = TimeSeries::new(vec![1, 2, 3], vec![1.0, 2.0, -1.0]);
+        assert_eq!(
+            ts.log_returns().err(),
+            Some(TimeSeriesError::NonPositiveValue { index: 2 })
+        );
+    }
+
+    fn monthly_returns() -> TimeSeries {
+        TimeSeries::new(vec![1, 2, 3, 4], vec![0.01, 0.03, -0.02, 0.02])
+    }
+
+    #[test]
+    fn test_annualized_volatility_scales_by_sqrt_of_periods() {
+        // mean = 0.01, squared deviations sum to 0.0014, sample variance = 0.0014 / 3,
+        // annualized variance = 0.0014 / 3 * 12 = 0.0056.
+        let vol = monthly_returns().annualized_volatility(12.0).unwrap();
+        assert!((vol - 0.0056_f64.sqrt()).abs() < 1e-12);
+        assert!((vol - 0.074_833_147_735_478_83).abs() < 1e-12);
+
+        // Per-period volatility is the sample standard deviation itself.
+        let per_period = monthly_returns().annualized_volatility(1.0).unwrap();
+        assert!((per_period - (0.0014_f64 / 3.0).sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_sharpe_ratio_against_hand_computed_value() {
+        // Annual risk-free 2.4% is 0.2% per month, so the mean excess return is 0.008 and the
+        // ratio is 0.008 * 12 / sqrt(0.0056).
+        let sharpe = monthly_returns().sharpe_ratio(0.024, 12.0).unwrap();
+        assert!((sharpe - 1.282_853_961_179_637).abs() < 1e-12);
+
+        // Without a risk-free rate: 0.01 / sqrt(0.0014 / 3) * sqrt(12).
+        let sharpe = monthly_returns().sharpe_ratio(0.0, 12.0).unwrap();
+        assert!((sharpe - 0.12 / 0.0056_f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_sharpe_and_volatility_undefined_without_dispersion() {
+        let flat = TimeSeries::new(vec![1, 2, 3], vec![0.01, 0.01, 0.01]);
+        assert_eq!(flat.sharpe_ratio(0.0, 252.0), None);
+        assert_eq!(flat.annualized_volatility(252.0), None);
+
+        let single = TimeSeries::new(vec![1], vec![0.05]);
+        assert_eq!(single.sharpe_ratio(0.0, 252.0), None);
+        assert_eq!(single.annualized_volatility(252.0), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Periods per year must be positive")]
+    fn test_annualized_volatility_rejects_zero_periods() {
+        monthly_returns().annualized_volatility(0.0);
+    }
+}
@@ -0,0 +1,262 @@
+use crate::prelude::*;
+use crate::{sorted_quantile, TimeSeries, TimeSeriesError};
+
+/// Scales the MAD to estimate the standard deviation of normally distributed data.
+const MAD_CONSISTENCY_CONSTANT: f64 = 1.4826;
+
+impl TimeSeries {
+    /// Computes the median absolute deviation: the median of `|value - median|`.
+    ///
+    /// Unlike [`TimeSeries::std_dev`], a single extreme value barely moves it, since it only
+    /// shifts which deviation sits in the middle. Returns `None` for an empty series or if any
+    /// value is NaN.
+    pub fn mad(&self) -> Option<f64> {
+        if self.values.is_empty() || self.values.iter().any(|v| v.is_nan()) {
+            return None;
+        }
+        let median = self.median();
+        let mut deviations: Vec<f64> = self.values.iter().map(|v| (v - median).abs()).collect();
+        deviations.sort_by(|a, b| a.total_cmp(b));
+        Some(sorted_quantile(&deviations, 0.5))
+    }
+
+    /// Computes the mean absolute deviation: the average of `|value - mean|`.
+    ///
+    /// Returns NaN for an empty series, like [`TimeSeries::mean`].
+    pub fn mean_absolute_deviation(&self) -> f64 {
+        let mean = self.mean();
+        self.values.iter().map(|v| (v - mean).abs()).sum::<f64>() / self.values.len() as f64
+    }
+
+    /// Computes the median absolute deviation, as [`TimeSeries::mad`] does, but returns NaN
+    /// where that returns `None`.
+    pub fn median_absolute_deviation(&self) -> f64 {
+        self.mad().unwrap_or(f64::NAN)
+    }
+
+    /// Scales [`TimeSeries::median_absolute_deviation`] by 1.4826, which estimates the standard
+    /// deviation for normally distributed data without letting outliers inflate it.
+    pub fn mad_normalized(&self) -> f64 {
+        self.median_absolute_deviation() * MAD_CONSISTENCY_CONSTANT
+    }
+
+    /// Computes the robust z-score `(value - median) / (1.4826 * mad)` of every point.
+    ///
+    /// The consistency constant makes the scores comparable to ordinary z-scores for normally
+    /// distributed data, while outliers cannot inflate the scale they are measured against.
+    ///
+    /// # Errors
+    /// Returns [`TimeSeriesError::InsufficientData`] for an empty series,
+    /// [`TimeSeriesError::NonFiniteValue`] if a value is NaN or infinite, and
+    /// [`TimeSeriesError::ZeroMad`] when more than half the values are identical, so that the MAD
+    /// is zero.
+    pub fn robust_zscore(&self) -> Result<TimeSeries, TimeSeriesError> {
+        if self.values.is_empty() {
+            return Err(TimeSeriesError::InsufficientData {
+                required: 1,
+                actual: 0,
+            });
+        }
+        self.validate_finite()?;
+        let median = self.median();
+        let scale = match self.mad() {
+            Some(mad) if mad > 0.0 => mad * MAD_CONSISTENCY_CONSTANT,
+            _ => return Err(TimeSeriesError::ZeroMad),
+        };
+        Ok(TimeSeries::new(
+            self.timestamps.clone(),
+            self.values.iter().map(|v| (v - median) / scale).collect(),
+        ))
+    }
+
+    /// Computes the mean after dropping the `floor(n * proportion)` smallest and largest values.
+    ///
+    /// A trimmed mean cannot be dragged arbitrarily far by a few injected spikes: up to the
+    /// trimmed count of extreme values on each side are ignored outright. A `proportion` of 0
+    /// is the plain mean.
+    ///
+    /// # Errors
+    /// Returns [`TimeSeriesError::InvalidParameter`] unless `0 <= proportion < 0.5`, and
+    /// [`TimeSeriesError::InsufficientData`] for an empty series.
+    pub fn trimmed_mean(&self, proportion: f64) -> Result<f64, TimeSeriesError> {
+        let (sorted, trim) = self.sorted_with_trim(proportion)?;
+        let kept = &sorted[trim..sorted.len() - trim];
+        Ok(kept.iter().sum::<f64>() / kept.len() as f64)
+    }
+
+    /// Clamps the `floor(n * proportion)` smallest and largest values to the nearest value kept
+    /// by [`TimeSeries::trimmed_mean`], keeping every point and its timestamp. An empty series
+    /// is returned unchanged.
+    ///
+    /// # Errors
+    /// Returns [`TimeSeriesError::InvalidParameter`] unless `0 <= proportion < 0.5`, and
+    /// [`TimeSeriesError::NonFiniteValue`] if a value is NaN or infinite.
+    pub fn winsorize(&self, proportion: f64) -> Result<TimeSeries, TimeSeriesError> {
+        if !(0.0..0.5).contains(&proportion) {
+            return Err(TimeSeriesError::InvalidParameter { name: "proportion" });
+        }
+        self.validate_finite()?;
+        if self.values.is_empty() {
+            return Ok(self.clone());
+        }
+        let (sorted, trim) = self.sorted_with_trim(proportion)?;
+        let (low, high) = (sorted[trim], sorted[sorted.len() - 1 - trim]);
+        Ok(TimeSeries::new(
+            self.timestamps.clone(),
+            self.values.iter().map(|v| v.clamp(low, high)).collect(),
+        ))
+    }
+
+    /// Returns the sorted values and the number to trim from each end, `floor(n * proportion)`.
+    fn sorted_with_trim(&self, proportion: f64) -> Result<(Vec<f64>, usize), TimeSeriesError> {
+        if !(0.0..0.5).contains(&proportion) {
+            return Err(TimeSeriesError::InvalidParameter { name: "proportion" });
+        }
+        if self.values.is_empty() {
+            return Err(TimeSeriesError::InsufficientData {
+                required: 1,
+                actual: 0,
+            });
+        }
+        let mut sorted = self.values.clone();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        // Below 0.5 the trim leaves at least one value: 2 * floor(n * p) < n.
+        let trim = (sorted.len() as f64 * proportion).floor() as usize;
+        Ok((sorted, trim))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_outlier_barely_moves_mad() {
+        let clean = TimeSeries::new(vec![1, 2, 3, 4, 5], vec![10.0, 11.0, 12.0, 13.0, 14.0]);
+        let spiked = TimeSeries::new(
+            vec![1, 2, 3, 4, 5, 6],
+            vec![10.0, 11.0, 12.0, 13.0, 14.0, 1000.0],
+        );
+
+        // Deviations from 12 are [2, 1, 0, 1, 2]; from 12.5 they are
+        // [2.5, 1.5, 0.5, 0.5, 1.5, 987.5].
+        assert_eq!(clean.mad(), Some(1.0));
+        assert_eq!(spiked.mad(), Some(1.5));
+        // The same outlier multiplies the standard deviation by more than 200.
+        assert!(spiked.std_dev() > 200.0 * clean.std_dev());
+    }
+
+    #[test]
+    fn test_absolute_deviations() {
+        let ts = TimeSeries::new(vec![1, 2, 3, 4, 5], vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+        // Deviations from both the mean and the median are [2, 1, 0, 1, 2].
+        assert_eq!(ts.mean_absolute_deviation(), 1.2);
+        assert_eq!(ts.median_absolute_deviation(), 1.0);
+        assert_eq!(ts.mad_normalized(), 1.4826);
+
+        let empty = TimeSeries::new(vec![], vec![]);
+        assert!(empty.mean_absolute_deviation().is_nan());
+        assert!(empty.median_absolute_deviation().is_nan());
+        assert!(empty.mad_normalized().is_nan());
+    }
+
+    #[test]
+    fn test_robust_zscore() {
+        let ts = TimeSeries::new(vec![1, 2, 3, 4, 5], vec![10.0, 11.0, 12.0, 13.0, 14.0]);
+        let z = ts.robust_zscore().unwrap();
+        assert_eq!(z.timestamps, ts.timestamps);
+        assert_eq!(z.values[2], 0.0);
+        assert!((z.values[4] - 2.0 / MAD_CONSISTENCY_CONSTANT).abs() < 1e-12);
+        assert!((z.values[0] + 2.0 / MAD_CONSISTENCY_CONSTANT).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_robust_zscore_errors() {
+        let mostly_flat = TimeSeries::new(vec![1, 2, 3, 4], vec![5.0, 5.0, 5.0, 9.0]);
+        assert_eq!(mostly_flat.mad(), Some(0.0));
+        assert_eq!(
+            mostly_flat.robust_zscore().err(),
+            Some(TimeSeriesError::ZeroMad)
+        );
+
+        let empty = TimeSeries::new(vec![], vec![]);
+        assert_eq!(empty.mad(), None);
+        assert!(empty.robust_zscore().is_err());
+
+        let nan = TimeSeries::new(vec![1, 2], vec![1.0, f64::NAN]);
+        assert_eq!(nan.mad(), None);
+        assert_eq!(
+            nan.robust_zscore().err(),
+            Some(TimeSeriesError::NonFiniteValue { index: 1 })
+        );
+    }
+
+    fn spiked() -> TimeSeries {
+        TimeSeries::new(
+            (1..=10).collect(),
+            vec![3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0, 5.0, 1000.0],
+        )
+    }
+
+    #[test]
+    fn test_trimmed_mean_ignores_spike() {
+        let ts = spiked();
+        // floor(10 * 0.1) = 1 value dropped from each end: 1.0 and 1000.0.
+        // The kept values [1, 2, 3, 4, 5, 5, 6, 9] sum to 35.
+        assert_eq!(ts.trimmed_mean(0.1), Ok(35.0 / 8.0));
+        // floor(10 * 0.15) is still 1.
+        assert_eq!(ts.trimmed_mean(0.15), Ok(35.0 / 8.0));
+        assert_eq!(ts.trimmed_mean(0.0), Ok(ts.mean()));
+        assert_eq!(
+            ts.trimmed_mean(0.5),
+            Err(TimeSeriesError::InvalidParameter { name: "proportion" })
+        );
+        assert_eq!(
+            ts.trimmed_mean(-0.1),
+            Err(TimeSeriesError::InvalidParameter { name: "proportion" })
+        );
+        assert!(TimeSeries::new(vec![], vec![]).trimmed_mean(0.1).is_err());
+    }
+
+    #[test]
+    fn test_winsorize_clamps_to_cut_points() {
+        let ts = spiked();
+        let w = ts.winsorize(0.1).unwrap();
+        assert_eq!(w.timestamps, ts.timestamps);
+        // The cut points are 1 and 9, so only the 1000 changes.
+        assert_eq!(
+            w.values,
+            vec![3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0, 5.0, 9.0]
+        );
+        assert_eq!(ts.winsorize(0.0).unwrap().values, ts.values);
+        assert!(TimeSeries::new(vec![], vec![])
+            .winsorize(0.2)
+            .unwrap()
+            .values
+            .is_empty());
+    }
+
+    #[test]
+    fn test_winsorize_errors() {
+        assert_eq!(
+            spiked().winsorize(0.5).err(),
+            Some(TimeSeriesError::InvalidParameter { name: "proportion" })
+        );
+        assert_eq!(
+            spiked().winsorize(f64::NAN).err(),
+            Some(TimeSeriesError::InvalidParameter { name: "proportion" })
+        );
+
+        // Clamping a NaN would panic, and an infinity would become a cut point.
+        let nan = TimeSeries::new(vec![1, 2, 3], vec![1.0, f64::NAN, 3.0]);
+        assert_eq!(
+            nan.winsorize(0.1).err(),
+            Some(TimeSeriesError::NonFiniteValue { index: 1 })
+        );
+        let inf = TimeSeries::new(vec![1, 2, 3], vec![1.0, 2.0, f64::INFINITY]);
+        assert_eq!(
+            inf.winsorize(0.1).err(),
+            Some(TimeSeriesError::NonFiniteValue { index: 2 })
+        );
+    }
+}
@@ -0,0 +1,336 @@
+use alloc::collections::VecDeque;
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+use crate::{sorted_quantile, TimeSeries};
+
+/// How [`TimeSeries::moving_average_with_mode`] handles the first `window_size - 1` points,
+/// which have fewer than `window_size` values before them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum MovingAverageMode {
+    /// Average every value so far, so the output has one value per input point.
+    ShrinkingWindow,
+    /// Drop the leading points, so the output starts at index `window_size - 1`.
+    Valid,
+    /// Output NaN for the leading points, so the output has one value per input point.
+    NanPad,
+}
+
+impl TimeSeries {
+    /// Computes the moving average of the time series, handling the leading points as `mode`
+    /// specifies.
+    ///
+    /// Every output value sits at the timestamp of the last point of its window. A series
+    /// shorter than `window_size` has no full window, so [`MovingAverageMode::Valid`] returns
+    /// an empty series.
+    ///
+    /// # Arguments
+    /// * `window_size` - The size of the moving window (window_size > 0 unless `mode` is
+    ///   [`MovingAverageMode::ShrinkingWindow`])
+    /// * `mode` - How to handle the points before the first full window
+    pub fn moving_average_with_mode(
+        &self,
+        window_size: usize,
+        mode: MovingAverageMode,
+    ) -> TimeSeries {
+        let mut ma_values: Vec<f64> = self
+            .windows_ending_at(window_size)
+            .map(|window| window.iter().sum::<f64>() / window.len() as f64)
+            .collect();
+        let leading = window_size.saturating_sub(1).min(ma_values.len());
+        match mode {
+            MovingAverageMode::ShrinkingWindow => {
+                TimeSeries::new(self.timestamps.clone(), ma_values)
+            }
+            MovingAverageMode::Valid => {
+                assert!(window_size > 0, "Window size must be positive");
+                TimeSeries::new(
+                    self.timestamps[leading..].to_vec(),
+                    ma_values.split_off(leading),
+                )
+            }
+            MovingAverageMode::NanPad => {
+                assert!(window_size > 0, "Window size must be positive");
+                ma_values[..leading].fill(f64::NAN);
+                TimeSeries::new(self.timestamps.clone(), ma_values)
+            }
+        }
+    }
+
+    /// Computes the rolling mean of the time series; the same as [`TimeSeries::moving_average`].
+    ///
+    /// # Arguments
+    /// * `window_size` - The size of the moving window
+    pub fn rolling_mean(&self, window_size: usize) -> TimeSeries {
+        self.moving_average(window_size)
+    }
+
+    /// Computes the rolling population standard deviation of the time series.
+    ///
+    /// Like [`TimeSeries::moving_average`], the first `window_size - 1` points use the shorter
+    /// window of every value so far, so the output has one value per input point.
+    ///
+    /// # Arguments
+    /// * `window_size` - The size of the moving window (window_size > 0)
+    pub fn rolling_std(&self, window_size: usize) -> TimeSeries {
+        assert!(window_size > 0, "Window size must be positive");
+        let std_values = self
+            .windows_ending_at(window_size)
+            .map(|window| {
+                let mean = window.iter().sum::<f64>() / window.len() as f64;
+                let variance = window
+                    .iter()
+                    .map(|&value| (value - mean).powi(2))
+                    .sum::<f64>()
+                    / window.len() as f64;
+                variance.sqrt()
+            })
+            .collect();
+        TimeSeries::new(self.timestamps.clone(), std_values)
+    }
+
+    /// Computes a moving average that weights each point of the window by a kernel.
+    ///
+    /// `weights[0]` applies to the oldest point of each window and the last weight to the
+    /// current point. The weights are normalized to sum to 1, so only their ratios matter. For
+    /// the first `weights.len() - 1` points, where the window is shorter, the last weights are
+    /// renormalized over the available points, matching the partial windows of
+    /// [`TimeSeries::moving_average`]. A window whose weights sum to zero averages to NaN.
+    ///
+    /// # Arguments
+    /// * `weights` - The kernel, oldest point first; non-empty, finite and non-negative
+    pub fn weighted_moving_average(&self, weights: &[f64]) -> TimeSeries {
+        assert!(!weights.is_empty(), "Weights must not be empty");
+        assert!(
+            weights.iter().all(|w| w.is_finite() && *w >= 0.0),
+            "Weights must be finite and non-negative"
+        );
+        let wma_values = self
+            .windows_ending_at(weights.len())
+            .map(|window| {
+                let kernel = &weights[weights.len() - window.len()..];
+                let weighted_sum: f64 = window.iter().zip(kernel).map(|(v, w)| v * w).sum();
+                weighted_sum / kernel.iter().sum::<f64>()
+            })
+            .collect();
+        TimeSeries::new(self.timestamps.clone(), wma_values)
+    }
+
+    /// Computes the rolling minimum of the time series in O(n), using the same shrinking
+    /// windows at the start as [`TimeSeries::moving_average`].
+    ///
+    /// # Arguments
+    /// * `window_size` - The size of the moving window (window_size > 0)
+    pub fn rolling_min(&self, window_size: usize) -> TimeSeries {
+        self.rolling_extreme(window_size, |incoming, kept| incoming <= kept)
+    }
+
+    /// Computes the rolling maximum of the time series in O(n), using the same shrinking
+    /// windows at the start as [`TimeSeries::moving_average`].
+    ///
+    /// # Arguments
+    /// * `window_size` - The size of the moving window (window_size > 0)
+    pub fn rolling_max(&self, window_size: usize) -> TimeSeries {
+        self.rolling_extreme(window_size, |incoming, kept| incoming >= kept)
+    }
+
+    /// Computes the rolling median of the time series, using the same shrinking windows at the
+    /// start as [`TimeSeries::moving_average`].
+    ///
+    /// Windows with an even number of points average the two middle values, like
+    /// [`TimeSeries::median`].
+    ///
+    /// # Arguments
+    /// * `window_size` - The size of the moving window (window_size > 0)
+    pub fn rolling_median(&self, window_size: usize) -> TimeSeries {
+        assert!(window_size > 0, "Window size must be positive");
+        // The current window, kept sorted so each step is a binary search plus a shift.
+        let mut sorted: Vec<f64> = Vec::with_capacity(window_size);
+        let mut median_values = Vec::with_capacity(self.values.len());
+        for (i, &value) in self.values.iter().enumerate() {
+            if i >= window_size {
+                let outgoing = self.values[i - window_size];
+                let position = sorted.partition_point(|v| v.total_cmp(&outgoing).is_lt());
+                sorted.remove(position);
+            }
+            let position = sorted.partition_point(|v| v.total_cmp(&value).is_lt());
+            sorted.insert(position, value);
+            median_values.push(sorted_quantile(&sorted, 0.5));
+        }
+        TimeSeries::new(self.timestamps.clone(), median_values)
+    }
+
+    /// Yields the window ending at each point, shrinking at the start of the series so that
+    /// the first `window_size - 1` windows hold every value so far.
+    fn windows_ending_at(&self, window_size: usize) -> impl Iterator<Item = &[f64]> {
+        (0..self.values.len()).map(move |i| &self.values[window_start(i, window_size)..=i])
+    }
+
+    /// Computes a rolling minimum or maximum with a monotonic deque of indices.
+    ///
+    /// `dominates(incoming, kept)` returns true when a newer value makes an older one
+    /// irrelevant for every later window, so the deque front always holds the extreme.
+    fn rolling_extreme(&self, window_size: usize, dominates: fn(f64, f64) -> bool) -> TimeSeries {
+        assert!(window_size > 0, "Window size must be positive");
+        let mut candidates: VecDeque<usize> = VecDeque::with_capacity(window_size);
+        let mut extreme_values = Vec::with_capacity(self.values.len());
+        for (i, &value) in self.values.iter().enumerate() {
+            while candidates
+                .back()
+                .is_some_and(|&kept| dominates(value, self.values[kept]))
+            {
+                candidates.pop_back();
+            }
+            candidates.push_back(i);
+            if candidates
+                .front()
+                .is_some_and(|&front| front < window_start(i, window_size))
+            {
+                candidates.pop_front();
+            }
+            extreme_values.push(self.values[candidates[0]]);
+        }
+        TimeSeries::new(self.timestamps.clone(), extreme_values)
+    }
+}
+
+/// Returns the index of the first point in the window ending at `i`.
+fn window_start(i: usize, window_size: usize) -> usize {
+    (i + 1).saturating_sub(window_size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn series(values: Vec<f64>) -> TimeSeries {
+        TimeSeries::new((0..values.len() as u64).collect(), values)
+    }
+
+    /// Recomputes `statistic` over each shrinking window from scratch.
+    fn naive(values: &[f64], window_size: usize, statistic: fn(&TimeSeries) -> f64) -> Vec<f64> {
+        (0..values.len())
+            .map(|i| statistic(&series(values[window_start(i, window_size)..=i].to_vec())))
+            .collect()
+    }
+
+    fn min(ts: &TimeSeries) -> f64 {
+        ts.values.iter().copied().fold(f64::INFINITY, f64::min)
+    }
+
+    fn max(ts: &TimeSeries) -> f64 {
+        ts.values.iter().copied().fold(f64::NEG_INFINITY, f64::max)
+    }
+
+    #[test]
+    fn test_rolling_statistics() {
+        let ts = series(vec![3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0]);
+        assert_eq!(
+            ts.rolling_min(3).values,
+            vec![3.0, 1.0, 1.0, 1.0, 1.0, 1.0, 2.0]
+        );
+        assert_eq!(
+            ts.rolling_max(3).values,
+            vec![3.0, 3.0, 4.0, 4.0, 5.0, 9.0, 9.0]
+        );
+        assert_eq!(
+            ts.rolling_median(3).values,
+            vec![3.0, 2.0, 3.0, 1.0, 4.0, 5.0, 5.0]
+        );
+        assert_eq!(ts.rolling_std(2).values[..3], [0.0, 1.0, 1.5]);
+        assert_eq!(ts.rolling_std(3).timestamps, ts.timestamps);
+    }
+
+    #[test]
+    fn test_moving_average_modes() {
+        let ts = series(vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+
+        let shrinking = ts.moving_average_with_mode(3, MovingAverageMode::ShrinkingWindow);
+        assert_eq!(shrinking.values, vec![1.0, 1.5, 2.0, 3.0, 4.0]);
+        assert_eq!(shrinking.values, ts.moving_average(3).values);
+
+        let valid = ts.moving_average_with_mode(3, MovingAverageMode::Valid);
+        assert_eq!(valid.timestamps, vec![2, 3, 4]);
+        assert_eq!(valid.values, vec![2.0, 3.0, 4.0]);
+
+        let padded = ts.moving_average_with_mode(3, MovingAverageMode::NanPad);
+        assert_eq!(padded.timestamps, ts.timestamps);
+        assert!(padded.values[..2].iter().all(|v| v.is_nan()));
+        assert_eq!(padded.values[2..], [2.0, 3.0, 4.0]);
+
+        let short = series(vec![1.0, 2.0]);
+        assert!(short
+            .moving_average_with_mode(3, MovingAverageMode::Valid)
+            .values
+            .is_empty());
+        assert!(short
+            .moving_average_with_mode(3, MovingAverageMode::NanPad)
+            .values
+            .iter()
+            .all(|v| v.is_nan()));
+    }
+
+    #[test]
+    fn test_weighted_moving_average() {
+        let ts = series(vec![3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0]);
+        assert_eq!(
+            ts.weighted_moving_average(&[1.0; 3]).values,
+            ts.moving_average(3).values
+        );
+
+        let triangular = ts.weighted_moving_average(&[1.0, 2.0, 1.0]);
+        assert_eq!(triangular.timestamps, ts.timestamps);
+        assert_eq!(
+            triangular.values,
+            vec![3.0, 7.0 / 3.0, 2.25, 2.5, 2.75, 5.0, 6.25]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Weights must not be empty")]
+    fn test_weighted_moving_average_rejects_empty_weights() {
+        series(vec![1.0]).weighted_moving_average(&[]);
+    }
+
+    #[test]
+    fn test_rolling_std_without_spread_is_zero() {
+        let flat = series(vec![4.0; 6]);
+        assert_eq!(flat.rolling_std(3).values, vec![0.0; 6]);
+
+        let ts = series(vec![3.0, 1.0, 4.0, 1.0, 5.0]);
+        assert_eq!(ts.rolling_std(1).values, vec![0.0; 5]);
+        assert_eq!(ts.rolling_mean(2).values, ts.moving_average(2).values);
+    }
+
+    #[test]
+    #[should_panic(expected = "Window size must be positive")]
+    fn test_rolling_min_rejects_empty_window() {
+        series(vec![1.0]).rolling_min(0);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_rolling_matches_naive(
+            values in prop::collection::vec(-100i32..100, 0..40),
+            window_size in 1usize..8,
+        ) {
+            // Small integers make repeated values likely and keep every sum exact.
+            let values: Vec<f64> = values.into_iter().map(f64::from).collect();
+            let ts = series(values.clone());
+            prop_assert_eq!(ts.rolling_min(window_size).values, naive(&values, window_size, min));
+            prop_assert_eq!(ts.rolling_max(window_size).values, naive(&values, window_size, max));
+            prop_assert_eq!(
+                ts.rolling_median(window_size).values,
+                naive(&values, window_size, TimeSeries::median)
+            );
+            prop_assert_eq!(
+                ts.rolling_std(window_size).values,
+                naive(&values, window_size, TimeSeries::std_dev)
+            );
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use crate::prelude::*;
+use crate::TimeSeries;
+
+impl TimeSeries {
+    /// Counts the values strictly above `threshold`. NaN values are never counted.
+    pub fn count_above(&self, threshold: f64) -> usize {
+        self.values.iter().filter(|&&v| v > threshold).count()
+    }
+
+    /// Counts the values strictly below `threshold`. NaN values are never counted.
+    pub fn count_below(&self, threshold: f64) -> usize {
+        self.values.iter().filter(|&&v| v < threshold).count()
+    }
+
+    /// Finds every run of consecutive samples strictly below `threshold`.
+    ///
+    /// Runs are defined by consecutive samples, however far apart their timestamps are; use
+    /// [`TimeSeries::runs_below_within`] to break runs at missing samples. A NaN value ends a run.
+    ///
+    /// # Returns
+    /// The timestamps of the first and last sample of each run, in order. A run of one sample
+    /// starts and ends at the same timestamp.
+    pub fn runs_below(&self, threshold: f64) -> Vec<(u64, u64)> {
+        self.runs_below_within(threshold, u64::MAX)
+    }
+
+    /// Like [`TimeSeries::runs_below`], but a run also ends where consecutive timestamps are more
+    /// than `max_gap` apart, so a missing sample is not assumed to have been below `threshold`.
+    pub fn runs_below_within(&self, threshold: f64, max_gap: u64) -> Vec<(u64, u64)> {
+        let mut runs: Vec<(u64, u64)> = Vec::new();
+        let mut in_run = false;
+        for (i, (&timestamp, &value)) in self.timestamps.iter().zip(&self.values).enumerate() {
+            if value >= threshold || value.is_nan() {
+                in_run = false;
+                continue;
+            }
+            let gap = i > 0 && timestamp.saturating_sub(self.timestamps[i - 1]) > max_gap;
+            match runs.last_mut() {
+                Some(run) if in_run && !gap => run.1 = timestamp,
+                _ => runs.push((timestamp, timestamp)),
+            }
+            in_run = true;
+        }
+        runs
+    }
+
+    /// Returns the start and end timestamps of the longest run of consecutive samples below
+    /// `threshold`, or `None` if no value is below it.
+    ///
+    /// Runs are compared by the time they span, `end - start`, and the earliest wins a tie. A
+    /// run whose timestamps go backwards spans no time.
+    pub fn longest_run_below(&self, threshold: f64) -> Option<(u64, u64)> {
+        self.longest_run_below_within(threshold, u64::MAX)
+    }
+
+    /// Like [`TimeSeries::longest_run_below`], but with runs broken at gaps longer than
+    /// `max_gap`, as in [`TimeSeries::runs_below_within`].
+    pub fn longest_run_below_within(&self, threshold: f64, max_gap: u64) -> Option<(u64, u64)> {
+        self.runs_below_within(threshold, max_gap)
+            .into_iter()
+            .rev()
+            .max_by_key(|&(start, end)| end.saturating_sub(start))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn uptime() -> TimeSeries {
+        // Outages (below 0.5) at the very start, a single sample at 180, and at the very end.
+        TimeSeries::new(
+            vec![0, 60, 120, 180, 240, 300, 360, 420],
+            vec![0.0, 0.1, 1.0, 0.2, 1.0, 0.0, 0.0, 0.3],
+        )
+    }
+
+    #[test]
+    fn test_counts_exclude_threshold() {
+        let ts = uptime();
+        assert_eq!(ts.count_below(0.5), 6);
+        assert_eq!(ts.count_above(0.5), 2);
+        assert_eq!(ts.count_below(0.0), 0);
+        assert_eq!(ts.count_above(1.0), 0);
+    }
+
+    #[test]
+    fn test_runs_below_at_start_end_and_single() {
+        let ts = uptime();
+        assert_eq!(ts.runs_below(0.5), vec![(0, 60), (180, 180), (300, 420)]);
+        assert_eq!(ts.longest_run_below(0.5), Some((300, 420)));
+        assert_eq!(ts.runs_below(0.05), vec![(0, 0), (300, 360)]);
+        assert_eq!(ts.longest_run_below(-1.0), None);
+        assert!(TimeSeries::new(vec![], vec![]).runs_below(0.0).is_empty());
+    }
+
+    #[test]
+    fn test_runs_below_broken_by_gaps() {
+        // The sample at 180 is missing.
+        let ts = TimeSeries::new(
+            vec![0, 60, 120, 240, 300, 360],
+            vec![0.0, 0.0, 0.0, 0.0, 0.0, 1.0],
+        );
+        assert_eq!(ts.runs_below(0.5), vec![(0, 300)]);
+        assert_eq!(ts.runs_below_within(0.5, 60), vec![(0, 120), (240, 300)]);
+        // Spans of 120 and 60 seconds; the longer one wins.
+        assert_eq!(ts.longest_run_below_within(0.5, 60), Some((0, 120)));
+        assert_eq!(ts.runs_below_within(0.5, 120), vec![(0, 300)]);
+    }
+
+    #[test]
+    fn test_longest_run_below_prefers_earliest_on_tie() {
+        let ts = TimeSeries::new(vec![0, 10, 20, 30, 40], vec![0.0, 0.0, 1.0, 0.0, 0.0]);
+        assert_eq!(ts.longest_run_below(0.5), Some((0, 10)));
+    }
+
+    #[test]
+    fn test_longest_run_below_with_backwards_timestamps() {
+        // The first run ends before it starts; it spans no time rather than underflowing.
+        let ts = TimeSeries::new(vec![50, 10, 20, 30, 40], vec![0.0, 0.0, 1.0, 0.0, 0.0]);
+        assert_eq!(ts.runs_below(0.5), vec![(50, 10), (30, 40)]);
+        assert_eq!(ts.longest_run_below(0.5), Some((30, 40)));
+    }
+}
@@ -0,0 +1,77 @@
+use crate::TimeSeries;
+
+impl TimeSeries {
+    /// Normalizes the values to zero mean and unit standard deviation.
+    ///
+    /// Uses the population standard deviation of [`TimeSeries::std_dev`]. A constant series has
+    /// no spread to normalize, so every value maps to `0.0` instead of NaN.
+    pub fn z_score_normalize(&self) -> TimeSeries {
+        let mean = self.mean();
+        let std_dev = self.std_dev();
+        let values = self
+            .values
+            .iter()
+            .map(|&v| {
+                if std_dev == 0.0 {
+                    0.0
+                } else {
+                    (v - mean) / std_dev
+                }
+            })
+            .collect();
+        TimeSeries::new(self.timestamps.clone(), values)
+    }
+
+    /// Rescales the values linearly so the observed minimum maps to `lo` and the maximum to `hi`.
+    ///
+    /// A constant series has no range to rescale, so every value maps to `lo` instead of NaN.
+    ///
+    /// # Arguments
+    /// * `lo` - The value the minimum maps to
+    /// * `hi` - The value the maximum maps to
+    pub fn min_max_scale(&self, lo: f64, hi: f64) -> TimeSeries {
+        let stats = self.order_stats();
+        let range = stats.max - stats.min;
+        let values = self
+            .values
+            .iter()
+            .map(|&v| {
+                if range == 0.0 {
+                    lo
+                } else {
+                    lo + (v - stats.min) / range * (hi - lo)
+                }
+            })
+            .collect();
+        TimeSeries::new(self.timestamps.clone(), values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_z_score_normalize() {
+        let ts = TimeSeries::new(vec![1, 2, 3, 4, 5], vec![10.0, 12.0, 23.0, 23.0, 16.0]);
+        let normalized = ts.z_score_normalize();
+        assert_eq!(normalized.timestamps, ts.timestamps);
+        assert!(normalized.mean().abs() < 1e-12);
+        assert!((normalized.std_dev() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_min_max_scale() {
+        let ts = TimeSeries::new(vec![1, 2, 3, 4], vec![5.0, -3.0, 1.0, 13.0]);
+        let scaled = ts.min_max_scale(-1.0, 1.0);
+        assert_eq!(scaled.timestamps, ts.timestamps);
+        assert_eq!(scaled.values, vec![0.0, -1.0, -0.5, 1.0]);
+    }
+
+    #[test]
+    fn test_scaling_constant_series() {
+        let ts = TimeSeries::new(vec![1, 2, 3], vec![4.0, 4.0, 4.0]);
+        assert_eq!(ts.z_score_normalize().values, vec![0.0; 3]);
+        assert_eq!(ts.min_max_scale(2.0, 3.0).values, vec![2.0; 3]);
+    }
+}
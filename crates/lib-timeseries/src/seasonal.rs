@@ -0,0 +1,306 @@
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+use crate::{
+    f64_to_u256, vec_f64_to_i256, HoltWintersPublicValuesStruct, TimeSeries, TimeSeriesError,
+};
+
+/// The components of an additive classical decomposition, each aligned to the original
+/// timestamps so that `trend + seasonal + residual` reproduces every value with a trend.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Decomposition {
+    /// The centered moving average; NaN for the first and last `period / 2` points.
+    pub trend: TimeSeries,
+    /// The average detrended value of each position in the period, repeated across the series.
+    pub seasonal: TimeSeries,
+    /// What remains after removing trend and seasonal; NaN wherever the trend is.
+    pub residual: TimeSeries,
+}
+
+impl TimeSeries {
+    /// Splits the series into trend, seasonal and residual components by additive classical
+    /// decomposition.
+    ///
+    /// The trend is a centered moving average over one period; for an even period it averages
+    /// `period + 1` points with half weight on the two ends. The first and last `period / 2`
+    /// points have no centered window, so their trend and residual are NaN rather than
+    /// truncated. The seasonal component averages the detrended values at each position in
+    /// the period, then shifts those averages to sum to zero.
+    ///
+    /// # Arguments
+    /// * `period` - The number of points in one season, e.g. 7 for a weekly cycle of daily data
+    ///
+    /// # Errors
+    /// Returns [`TimeSeriesError::InvalidParameter`] if `period` is below 2 and
+    /// [`TimeSeriesError::InsufficientData`] if the series holds fewer than two full periods.
+    pub fn seasonal_decompose(&self, period: usize) -> Result<Decomposition, TimeSeriesError> {
+        if period < 2 {
+            return Err(TimeSeriesError::InvalidParameter { name: "period" });
+        }
+        let n = self.values.len();
+        if n < 2 * period {
+            return Err(TimeSeriesError::InsufficientData {
+                required: 2 * period,
+                actual: n,
+            });
+        }
+
+        let half = period / 2;
+        let trend: Vec<f64> = (0..n)
+            .map(|i| {
+                if i < half || i + half >= n {
+                    return f64::NAN;
+                }
+                let window = &self.values[i - half..=i + half];
+                if period % 2 == 1 {
+                    window.iter().sum::<f64>() / period as f64
+                } else {
+                    let inner: f64 = window[1..period].iter().sum();
+                    (inner + (window[0] + window[period]) / 2.0) / period as f64
+                }
+            })
+            .collect();
+
+        let mut sums = vec![0.0; period];
+        let mut counts = vec![0usize; period];
+        for (i, (value, trend)) in self.values.iter().zip(trend.iter()).enumerate() {
+            if !trend.is_nan() {
+                sums[i % period] += value - trend;
+                counts[i % period] += 1;
+            }
+        }
+        let averages: Vec<f64> = sums
+            .iter()
+            .zip(counts.iter())
+            .map(|(sum, &count)| sum / count as f64)
+            .collect();
+        let offset = averages.iter().sum::<f64>() / period as f64;
+        let seasonal: Vec<f64> = (0..n).map(|i| averages[i % period] - offset).collect();
+
+        let residual = (0..n)
+            .map(|i| self.values[i] - trend[i] - seasonal[i])
+            .collect();
+        Ok(Decomposition {
+            trend: TimeSeries::new(self.timestamps.clone(), trend),
+            seasonal: TimeSeries::new(self.timestamps.clone(), seasonal),
+            residual: TimeSeries::new(self.timestamps.clone(), residual),
+        })
+    }
+
+    /// Performs additive Holt-Winters (triple exponential smoothing) for forecasting.
+    ///
+    /// The level, trend and seasonal components are initialized from the first two seasons: the
+    /// trend is the difference of their means divided by `season_length`, and each seasonal index
+    /// is the average detrended deviation of that position across both seasons. The returned
+    /// series holds the smoothed value (level plus seasonal index) for every historical point
+    /// followed by `horizon` forecasts.
+    ///
+    /// # Arguments
+    /// * `alpha` - The level smoothing factor (0 < alpha <= 1)
+    /// * `beta` - The trend smoothing factor (0 < beta <= 1)
+    /// * `gamma` - The seasonal smoothing factor (0 < gamma <= 1)
+    /// * `season_length` - The number of points in one season, e.g. 7 for a weekly cycle of daily
+    ///   data
+    /// * `horizon` - The number of time steps to forecast
+    ///
+    /// # Errors
+    /// Returns [`TimeSeriesError::InvalidParameter`] if a smoothing factor is out of range or
+    /// `season_length` is zero, and [`TimeSeriesError::InsufficientData`] if the series holds
+    /// fewer than two full seasons.
+    pub fn holt_winters(
+        &self,
+        alpha: f64,
+        beta: f64,
+        gamma: f64,
+        season_length: usize,
+        horizon: usize,
+    ) -> Result<TimeSeries, TimeSeriesError> {
+        for (name, value) in [("alpha", alpha), ("beta", beta), ("gamma", gamma)] {
+            if !(value > 0.0 && value <= 1.0) {
+                return Err(TimeSeriesError::InvalidParameter { name });
+            }
+        }
+        if season_length == 0 {
+            return Err(TimeSeriesError::InvalidParameter {
+                name: "season_length",
+            });
+        }
+        if self.values.len() < 2 * season_length {
+            return Err(TimeSeriesError::InsufficientData {
+                required: 2 * season_length,
+                actual: self.values.len(),
+            });
+        }
+
+        let m = season_length as f64;
+        let first_mean = self.values[..season_length].iter().sum::<f64>() / m;
+        let second_mean = self.values[season_length..2 * season_length]
+            .iter()
+            .sum::<f64>()
+            / m;
+        let mut trend = (second_mean - first_mean) / m;
+        // The season mean is the level at the middle of the season; step back to just before
+        // the first point.
+        let mut level = first_mean - (m + 1.0) / 2.0 * trend;
+        let mut seasonal: Vec<f64> = (0..season_length)
+            .map(|i| {
+                let offset = (i as f64 - (m - 1.0) / 2.0) * trend;
+                let first = self.values[i] - (first_mean + offset);
+                let second = self.values[i + season_length] - (second_mean + offset);
+                (first + second) / 2.0
+            })
+            .collect();
+
+        let mut forecast = Vec::with_capacity(self.values.len() + horizon);
+        for (t, &value) in self.values.iter().enumerate() {
+            let index = t % season_length;
+            let previous_level = level;
+            level = alpha * (value - seasonal[index]) + (1.0 - alpha) * (level + trend);
+            trend = beta * (level - previous_level) + (1.0 - beta) * trend;
+            seasonal[index] = gamma * (value - level) + (1.0 - gamma) * seasonal[index];
+            forecast.push(level + seasonal[index]);
+        }
+        let last = self.values.len() - 1;
+        for h in 1..=horizon {
+            forecast.push(level + h as f64 * trend + seasonal[(last + h) % season_length]);
+        }
+        Ok(TimeSeries::new(self.extended_timestamps(horizon), forecast))
+    }
+
+    pub fn to_holt_winters_public_values(
+        &self,
+        alpha: f64,
+        beta: f64,
+        gamma: f64,
+        season_length: usize,
+        horizon: usize,
+    ) -> Result<HoltWintersPublicValuesStruct, TimeSeriesError> {
+        let start_timestamp = *self.timestamps.first().unwrap_or(&0);
+        let end_timestamp = *self.timestamps.last().unwrap_or(&0);
+        let values_hash = self.compute_hash();
+        let forecast = self.holt_winters(alpha, beta, gamma, season_length, horizon)?;
+
+        Ok(HoltWintersPublicValuesStruct {
+            start_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(start_timestamp),
+            end_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(end_timestamp),
+            values_hash: alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(values_hash),
+            alpha: f64_to_u256(alpha),
+            beta: f64_to_u256(beta),
+            gamma: f64_to_u256(gamma),
+            season_length: alloy_sol_types::private::Uint::<256, 4>::from(season_length),
+            horizon: alloy_sol_types::private::Uint::<256, 4>::from(horizon),
+            forecast: vec_f64_to_i256(&forecast.values[self.values.len()..]),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WEEKLY_PATTERN: [f64; 7] = [3.0, 1.0, 0.0, -1.0, -2.0, -3.0, 2.0];
+
+    fn weekly_series(days: u64) -> TimeSeries {
+        let timestamps: Vec<u64> = (0..days).map(|d| d * 86400).collect();
+        let values = (0..days)
+            .map(|d| 100.0 + 0.5 * d as f64 + WEEKLY_PATTERN[d as usize % 7])
+            .collect();
+        TimeSeries::new(timestamps, values)
+    }
+
+    #[test]
+    fn test_holt_winters_continues_trend_and_season() {
+        let ts = weekly_series(21);
+        let forecast = ts.holt_winters(0.4, 0.2, 0.3, 7, 10).unwrap();
+        let expected = weekly_series(31);
+        assert_eq!(forecast.timestamps, expected.timestamps);
+        for (value, expected) in forecast.values.iter().zip(expected.values.iter()) {
+            assert!((value - expected).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_holt_winters_errors() {
+        let ts = weekly_series(13);
+        assert_eq!(
+            ts.holt_winters(0.5, 0.5, 0.5, 7, 1).err(),
+            Some(TimeSeriesError::InsufficientData {
+                required: 14,
+                actual: 13
+            })
+        );
+        assert_eq!(
+            ts.holt_winters(0.5, 0.5, 1.5, 4, 1).err(),
+            Some(TimeSeriesError::InvalidParameter { name: "gamma" })
+        );
+        assert_eq!(
+            ts.holt_winters(0.0, 0.5, 0.5, 4, 1).err(),
+            Some(TimeSeriesError::InvalidParameter { name: "alpha" })
+        );
+        assert_eq!(
+            ts.holt_winters(0.5, 0.5, 0.5, 0, 1).err(),
+            Some(TimeSeriesError::InvalidParameter {
+                name: "season_length"
+            })
+        );
+    }
+
+    #[test]
+    fn test_seasonal_decompose_recovers_components() {
+        let trend = |i: usize| 10.0 + 0.5 * i as f64;
+        let season = |i: usize| (2.0 * core::f64::consts::PI * i as f64 / 12.0).sin();
+        let timestamps: Vec<u64> = (0..48).collect();
+        let values = (0..48).map(|i| trend(i) + season(i)).collect();
+        let decomposition = TimeSeries::new(timestamps.clone(), values)
+            .seasonal_decompose(12)
+            .unwrap();
+
+        assert_eq!(decomposition.trend.timestamps, timestamps);
+        for i in 0..48 {
+            assert!((decomposition.seasonal.values[i] - season(i)).abs() < 1e-9);
+            if (6..42).contains(&i) {
+                assert!((decomposition.trend.values[i] - trend(i)).abs() < 1e-9);
+                assert!(decomposition.residual.values[i].abs() < 1e-9);
+            } else {
+                assert!(decomposition.trend.values[i].is_nan());
+                assert!(decomposition.residual.values[i].is_nan());
+            }
+        }
+
+        let odd = weekly_series(28).seasonal_decompose(7).unwrap();
+        assert!(odd.trend.values[2].is_nan());
+        assert!((odd.trend.values[3] - (100.0 + 0.5 * 3.0)).abs() < 1e-9);
+        assert!((odd.seasonal.values[0] - WEEKLY_PATTERN[0]).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_seasonal_decompose_errors() {
+        let ts = weekly_series(13);
+        assert_eq!(
+            ts.seasonal_decompose(7).err(),
+            Some(TimeSeriesError::InsufficientData {
+                required: 14,
+                actual: 13
+            })
+        );
+        assert_eq!(
+            ts.seasonal_decompose(1).err(),
+            Some(TimeSeriesError::InvalidParameter { name: "period" })
+        );
+    }
+
+    #[test]
+    fn test_holt_winters_public_values() {
+        let ts = weekly_series(14);
+        let public_values = ts
+            .to_holt_winters_public_values(0.5, 0.5, 0.5, 7, 3)
+            .unwrap();
+        assert_eq!(public_values.forecast.len(), 3);
+        assert_eq!(
+            public_values.season_length,
+            alloy_sol_types::private::Uint::<256, 4>::from(7u64)
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use crate::prelude::*;
+use crate::{TimeSeries, TimeSeriesError};
+
+impl TimeSeries {
+    /// Moves the values `periods` positions later (or earlier, if negative) relative to the
+    /// timestamps, as for a lagged or leading copy of the series.
+    ///
+    /// With `periods = 1` each timestamp gets the value of the point before it. Values shifted
+    /// past either end are dropped and the points left without a value are removed rather than
+    /// padded with NaN, so the result has `|periods|` fewer points (or none, if `|periods|` is
+    /// at least the length).
+    pub fn shift(&self, periods: i64) -> TimeSeries {
+        let n = self.values.len();
+        let k = usize::try_from(periods.unsigned_abs()).map_or(n, |k| k.min(n));
+        if periods >= 0 {
+            TimeSeries::new(self.timestamps[k..].to_vec(), self.values[..n - k].to_vec())
+        } else {
+            TimeSeries::new(self.timestamps[..n - k].to_vec(), self.values[k..].to_vec())
+        }
+    }
+
+    /// Offsets every timestamp by `delta`, keeping the values, for example to correct a clock
+    /// skew before aligning two series.
+    ///
+    /// # Errors
+    /// Returns [`TimeSeriesError::TimestampOverflow`] for the first timestamp that would fall
+    /// below 0 or above `u64::MAX`.
+    pub fn shift_time(&self, delta: i64) -> Result<TimeSeries, TimeSeriesError> {
+        let timestamps = self
+            .timestamps
+            .iter()
+            .enumerate()
+            .map(|(index, &t)| {
+                t.checked_add_signed(delta)
+                    .ok_or(TimeSeriesError::TimestampOverflow { index })
+            })
+            .collect::<Result<Vec<u64>, _>>()?;
+        Ok(TimeSeries::new(timestamps, self.values.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn series() -> TimeSeries {
+        TimeSeries::new(vec![10, 20, 30, 40], vec![1.0, 2.0, 3.0, 4.0])
+    }
+
+    #[test]
+    fn test_shift_lag_and_lead() {
+        let ts = series();
+        let lagged = ts.shift(1);
+        assert_eq!(lagged.timestamps, vec![20, 30, 40]);
+        assert_eq!(lagged.values, vec![1.0, 2.0, 3.0]);
+
+        let lead = ts.shift(-2);
+        assert_eq!(lead.timestamps, vec![10, 20]);
+        assert_eq!(lead.values, vec![3.0, 4.0]);
+
+        let unchanged = ts.shift(0);
+        assert_eq!(unchanged.timestamps, ts.timestamps);
+        assert_eq!(unchanged.values, ts.values);
+
+        assert!(ts.shift(4).values.is_empty());
+        assert!(ts.shift(i64::MIN).timestamps.is_empty());
+    }
+
+    #[test]
+    fn test_shift_time() {
+        let ts = series();
+        assert_eq!(ts.shift_time(5).unwrap().timestamps, vec![15, 25, 35, 45]);
+        assert_eq!(ts.shift_time(-10).unwrap().timestamps, vec![0, 10, 20, 30]);
+        assert_eq!(ts.shift_time(0).unwrap().timestamps, ts.timestamps);
+        assert_eq!(ts.shift_time(-5).unwrap().values, ts.values);
+    }
+
+    #[test]
+    fn test_shift_time_rejects_overflow() {
+        let ts = series();
+        assert_eq!(
+            ts.shift_time(-11).unwrap_err(),
+            TimeSeriesError::TimestampOverflow { index: 0 }
+        );
+        let late = TimeSeries::new(vec![0, u64::MAX - 1], vec![1.0, 2.0]);
+        assert_eq!(
+            late.shift_time(2).unwrap_err(),
+            TimeSeriesError::TimestampOverflow { index: 1 }
+        );
+    }
+}
@@ -0,0 +1,267 @@
+use core::ops::Range;
+
+use crate::{PublicValuesStruct, RangeCountPublicValuesStruct, TimeSeries, TimeSeriesError};
+
+impl TimeSeries {
+    /// Returns the points whose timestamps fall within `start..=end`.
+    ///
+    /// Both bounds are inclusive. Points keep their stored order and timestamps need not be
+    /// sorted. If no point matches, or `start > end`, the result is empty.
+    ///
+    /// # Arguments
+    /// * `start` - The first timestamp to keep
+    /// * `end` - The last timestamp to keep
+    pub fn slice_by_time(&self, start: u64, end: u64) -> TimeSeries {
+        let (timestamps, values) = self
+            .timestamps
+            .iter()
+            .zip(self.values.iter())
+            .filter(|(&timestamp, _)| start <= timestamp && timestamp <= end)
+            .unzip();
+        TimeSeries::new(timestamps, values)
+    }
+
+    /// Returns the points at the indices in `range`, as with slice indexing: the start is
+    /// inclusive and the end exclusive.
+    ///
+    /// Unlike slice indexing it does not panic: the range is clamped to the series, so a range
+    /// past the end, or with `start >= end`, gives an empty series.
+    pub fn slice_by_index(&self, range: Range<usize>) -> TimeSeries {
+        let end = range.end.min(self.values.len());
+        let start = range.start.min(end);
+        TimeSeries::new(
+            self.timestamps[start..end].to_vec(),
+            self.values[start..end].to_vec(),
+        )
+    }
+
+    /// Returns the index range of the points whose timestamps fall within `start..end`.
+    ///
+    /// Timestamps must be sorted in non-decreasing order; the bounds are found by binary search
+    /// in O(log n). If `start >= end` the range is empty.
+    pub fn time_range_indices(&self, start: u64, end: u64) -> Range<usize> {
+        debug_assert!(self.is_monotonic(), "Timestamps must be sorted");
+        let first = self.timestamps.partition_point(|&t| t < start);
+        let last = self.timestamps.partition_point(|&t| t < end).max(first);
+        first..last
+    }
+
+    /// Returns the points whose timestamps fall within `start..end`.
+    ///
+    /// The start is inclusive and the end exclusive, so consecutive billing periods such as
+    /// `[day, day + 86400)` tile a history without sharing points. Timestamps must be sorted;
+    /// the bounds are found by binary search (see [`TimeSeries::time_range_indices`]). For
+    /// unsorted series or inclusive bounds use [`TimeSeries::slice_by_time`].
+    ///
+    /// The slice's own public values commit its first and last points as `start_timestamp` and
+    /// `end_timestamp`, which lie within `start..end`. To commit the requested bounds instead,
+    /// use [`TimeSeries::to_public_values_for_range`].
+    pub fn slice_range(&self, start: u64, end: u64) -> TimeSeries {
+        let range = self.time_range_indices(start, end);
+        TimeSeries::new(
+            self.timestamps[range.clone()].to_vec(),
+            self.values[range].to_vec(),
+        )
+    }
+
+    /// Builds the public values of the statistics program over the points within `start..end`.
+    ///
+    /// The statistics and values hash cover [`TimeSeries::slice_range`], but `start_timestamp` and
+    /// `end_timestamp` commit the requested half-open bounds rather than the first and last points
+    /// in them. A proof for `[day, day + 86400)` therefore states the billing period it covers,
+    /// even when no point falls exactly on its boundaries, and consecutive periods commit
+    /// touching bounds.
+    ///
+    /// # Errors
+    /// Returns [`TimeSeriesError::NonFiniteValue`] if a value within the range is NaN or infinite,
+    /// with its index in the slice.
+    pub fn to_public_values_for_range(
+        &self,
+        start: u64,
+        end: u64,
+    ) -> Result<PublicValuesStruct, TimeSeriesError> {
+        let mut public_values = self.slice_range(start, end).to_public_values()?;
+        public_values.start_timestamp = alloy_sol_types::private::Uint::<256, 4>::from(start);
+        public_values.end_timestamp = alloy_sol_types::private::Uint::<256, 4>::from(end);
+        Ok(public_values)
+    }
+
+    /// Splits a sorted series into the points before `t` and the points at or after `t`.
+    ///
+    /// Uses the same half-open convention as [`TimeSeries::slice_range`], so the second part
+    /// starts with any point at exactly `t`.
+    pub fn split_at_timestamp(&self, t: u64) -> (TimeSeries, TimeSeries) {
+        debug_assert!(self.is_monotonic(), "Timestamps must be sorted");
+        let split = self.timestamps.partition_point(|&timestamp| timestamp < t);
+        (
+            TimeSeries::new(
+                self.timestamps[..split].to_vec(),
+                self.values[..split].to_vec(),
+            ),
+            TimeSeries::new(
+                self.timestamps[split..].to_vec(),
+                self.values[split..].to_vec(),
+            ),
+        )
+    }
+
+    /// Counts the points whose timestamps fall within `start..=end`.
+    ///
+    /// Uses the same inclusive bounds as [`TimeSeries::slice_by_time`].
+    pub fn count_in_time_range(&self, start: u64, end: u64) -> usize {
+        self.slice_by_time(start, end).values.len()
+    }
+
+    /// Commits the number of points within `start..=end`, without revealing the points.
+    pub fn to_range_count_public_values(
+        &self,
+        range_start: u64,
+        range_end: u64,
+    ) -> RangeCountPublicValuesStruct {
+        let start_timestamp = *self.timestamps.first().unwrap_or(&0);
+        let end_timestamp = *self.timestamps.last().unwrap_or(&0);
+        let values_hash = self.compute_hash();
+        let count = self.count_in_time_range(range_start, range_end);
+
+        RangeCountPublicValuesStruct {
+            start_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(start_timestamp),
+            end_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(end_timestamp),
+            values_hash: alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(values_hash),
+            range_start: alloy_sol_types::private::Uint::<256, 4>::from(range_start),
+            range_end: alloy_sol_types::private::Uint::<256, 4>::from(range_end),
+            count: alloy_sol_types::private::Uint::<256, 4>::from(count),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_in_time_range() {
+        let ts = TimeSeries::new(
+            vec![10, 20, 30, 40, 50, 60],
+            vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+        );
+        // Three points inside (bounds included), three outside.
+        assert_eq!(ts.count_in_time_range(20, 40), 3);
+        assert_eq!(ts.count_in_time_range(21, 39), 1);
+        assert_eq!(ts.count_in_time_range(61, 100), 0);
+        assert_eq!(ts.count_in_time_range(40, 20), 0);
+
+        let slice = ts.slice_by_time(20, 40);
+        assert_eq!(slice.timestamps, vec![20, 30, 40]);
+        assert_eq!(slice.values, vec![2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn test_slice_middle_of_ten_points() {
+        let ts = TimeSeries::new(
+            (1..=10).map(|day| day * 86400).collect(),
+            (1..=10).map(f64::from).collect(),
+        );
+        let by_time = ts.slice_by_time(4 * 86400, 6 * 86400);
+        assert_eq!(by_time.timestamps, vec![4 * 86400, 5 * 86400, 6 * 86400]);
+        assert_eq!(by_time.values, vec![4.0, 5.0, 6.0]);
+
+        let by_index = ts.slice_by_index(3..6);
+        assert_eq!(by_index.timestamps, by_time.timestamps);
+        assert_eq!(by_index.values, by_time.values);
+    }
+
+    #[test]
+    fn test_slice_matching_nothing() {
+        let ts = TimeSeries::new(
+            (1..=10).map(|day| day * 86400).collect(),
+            (1..=10).map(f64::from).collect(),
+        );
+        let by_time = ts.slice_by_time(11 * 86400, 20 * 86400);
+        assert!(by_time.timestamps.is_empty() && by_time.values.is_empty());
+        assert!(ts.slice_by_time(86400 + 1, 2 * 86400 - 1).values.is_empty());
+
+        assert!(ts.slice_by_index(10..12).values.is_empty());
+        assert!(ts.slice_by_index(5..5).timestamps.is_empty());
+        assert_eq!(ts.slice_by_index(8..100).values, vec![9.0, 10.0]);
+    }
+
+    #[test]
+    fn test_slice_range() {
+        let ts = TimeSeries::new(vec![10, 20, 30, 40], vec![1.0, 2.0, 3.0, 4.0]);
+
+        let before = ts.slice_range(0, 10);
+        assert!(before.values.is_empty() && before.timestamps.is_empty());
+
+        let inside = ts.slice_range(20, 40);
+        assert_eq!(inside.timestamps, vec![20, 30]);
+        assert_eq!(inside.values, vec![2.0, 3.0]);
+        assert_eq!(ts.time_range_indices(20, 40), 1..3);
+        assert_eq!(ts.slice_range(15, 35).timestamps, vec![20, 30]);
+        assert_eq!(ts.slice_range(0, 100).values, ts.values);
+
+        assert!(ts.slice_range(41, 100).values.is_empty());
+        assert!(ts.slice_range(30, 20).values.is_empty());
+    }
+
+    #[test]
+    fn test_public_values_for_range_commit_the_bounds() {
+        let ts = TimeSeries::new(vec![10, 20, 30, 40], vec![1.0, 2.0, 3.0, 4.0]);
+        let public_values = ts.to_public_values_for_range(15, 35).unwrap();
+        let slice = ts.slice_range(15, 35).to_public_values().unwrap();
+        assert_eq!(
+            public_values.start_timestamp,
+            alloy_sol_types::private::Uint::<256, 4>::from(15u64)
+        );
+        assert_eq!(
+            public_values.end_timestamp,
+            alloy_sol_types::private::Uint::<256, 4>::from(35u64)
+        );
+        assert_eq!(
+            slice.end_timestamp,
+            alloy_sol_types::private::Uint::<256, 4>::from(30u64)
+        );
+        assert_eq!(public_values.values_hash, slice.values_hash);
+        assert_eq!(public_values.mean, slice.mean);
+
+        let nan = TimeSeries::new(vec![10, 20, 30], vec![f64::NAN, 2.0, f64::NAN]);
+        assert!(nan.to_public_values_for_range(15, 25).is_ok());
+        assert_eq!(
+            nan.to_public_values_for_range(0, 25).err(),
+            Some(TimeSeriesError::NonFiniteValue { index: 0 })
+        );
+    }
+
+    #[test]
+    fn test_split_at_timestamp() {
+        let ts = TimeSeries::new(vec![10, 20, 30, 40], vec![1.0, 2.0, 3.0, 4.0]);
+        let (before, after) = ts.split_at_timestamp(30);
+        assert_eq!(before.timestamps, vec![10, 20]);
+        assert_eq!(after.timestamps, vec![30, 40]);
+        assert_eq!(after.values, vec![3.0, 4.0]);
+
+        let (before, after) = ts.split_at_timestamp(5);
+        assert!(before.values.is_empty());
+        assert_eq!(after.values, ts.values);
+        let (before, after) = ts.split_at_timestamp(50);
+        assert_eq!(before.values, ts.values);
+        assert!(after.values.is_empty());
+    }
+
+    #[test]
+    fn test_range_count_public_values() {
+        let ts = TimeSeries::new(vec![10, 20, 30], vec![1.0, 2.0, 3.0]);
+        let public_values = ts.to_range_count_public_values(15, 35);
+        assert_eq!(
+            public_values.values_hash,
+            ts.to_public_values().unwrap().values_hash
+        );
+        assert_eq!(
+            public_values.count,
+            alloy_sol_types::private::Uint::<256, 4>::from(2u64)
+        );
+        assert_eq!(
+            public_values.range_start,
+            alloy_sol_types::private::Uint::<256, 4>::from(15u64)
+        );
+    }
+}
@@ -0,0 +1,169 @@
+use core::f64::consts::PI;
+
+use crate::prelude::*;
+use crate::{detmath, TimeSeries};
+
+/// How far any step between timestamps may stray from the mean step, as a fraction of it, for
+/// [`TimeSeries::dominant_period`] to treat the series as uniformly sampled.
+const SPACING_TOLERANCE: f64 = 0.1;
+
+impl TimeSeries {
+    /// Estimates the period of the strongest cycle in the series.
+    ///
+    /// Removes the mean, zero-pads the values to the next power of two and runs a radix-2 FFT.
+    /// The frequency with the largest magnitude, excluding the constant term, is refined by
+    /// fitting a parabola through its neighbors, since the padded bins rarely fall exactly on
+    /// the true frequency.
+    ///
+    /// # Returns
+    /// The period in timestamp units, or `None` if the series has fewer than four points, holds
+    /// a NaN or infinite value, is constant, or is not uniformly sampled: timestamps must
+    /// increase and every step must be within 10% of the mean step.
+    pub fn dominant_period(&self) -> Option<f64> {
+        let n = self.values.len();
+        if n < 4 || self.validate_finite().is_err() {
+            return None;
+        }
+        let step = (self.timestamps[n - 1] as f64 - self.timestamps[0] as f64) / (n - 1) as f64;
+        let uniform = self.timestamps.windows(2).all(|pair| {
+            pair[1] > pair[0]
+                && ((pair[1] - pair[0]) as f64 - step).abs() <= SPACING_TOLERANCE * step
+        });
+        if !uniform {
+            return None;
+        }
+
+        let size = n.next_power_of_two();
+        let mean = self.mean();
+        let mut spectrum: Vec<(f64, f64)> = self.values.iter().map(|v| (v - mean, 0.0)).collect();
+        spectrum.resize(size, (0.0, 0.0));
+        fft(&mut spectrum);
+
+        let magnitudes: Vec<f64> = spectrum[..=size / 2]
+            .iter()
+            .map(|&(re, im)| (re * re + im * im).sqrt())
+            .collect();
+        let (peak, &peak_magnitude) = magnitudes
+            .iter()
+            .enumerate()
+            .skip(1)
+            .max_by(|a, b| a.1.total_cmp(b.1))?;
+        if peak_magnitude == 0.0 {
+            return None;
+        }
+
+        let mut frequency = peak as f64;
+        if peak < size / 2 {
+            let (left, right) = (magnitudes[peak - 1], magnitudes[peak + 1]);
+            let curvature = left - 2.0 * peak_magnitude + right;
+            if curvature != 0.0 {
+                frequency += 0.5 * (left - right) / curvature;
+            }
+        }
+        Some(size as f64 / frequency * step)
+    }
+}
+
+/// Computes the discrete Fourier transform of `data` in place with the iterative radix-2
+/// Cooley-Tukey algorithm. The length of `data` must be a power of two.
+fn fft(data: &mut [(f64, f64)]) {
+    let n = data.len();
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let angle = -2.0 * PI / len as f64;
+        for start in (0..n).step_by(len) {
+            for k in 0..len / 2 {
+                let twiddle = (
+                    detmath::cos(angle * k as f64),
+                    detmath::sin(angle * k as f64),
+                );
+                let (a, b) = (data[start + k], data[start + k + len / 2]);
+                let product = (
+                    b.0 * twiddle.0 - b.1 * twiddle.1,
+                    b.0 * twiddle.1 + b.1 * twiddle.0,
+                );
+                data[start + k] = (a.0 + product.0, a.1 + product.1);
+                data[start + k + len / 2] = (a.0 - product.0, a.1 - product.1);
+            }
+        }
+        len <<= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fft_matches_direct_transform() {
+        let mut data: Vec<(f64, f64)> = [1.0, 2.0, 0.0, -1.0, 3.0, 0.5, -2.0, 1.0]
+            .iter()
+            .map(|&v| (v, 0.0))
+            .collect();
+        let direct: Vec<(f64, f64)> = (0..8)
+            .map(|k| {
+                data.iter()
+                    .enumerate()
+                    .fold((0.0, 0.0), |sum, (t, &(v, _))| {
+                        let angle = -2.0 * PI * (k * t) as f64 / 8.0;
+                        (sum.0 + v * angle.cos(), sum.1 + v * angle.sin())
+                    })
+            })
+            .collect();
+        fft(&mut data);
+        for (fast, slow) in data.iter().zip(direct.iter()) {
+            assert!((fast.0 - slow.0).abs() < 1e-12 && (fast.1 - slow.1).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_dominant_period_of_daily_cycle() {
+        let timestamps: Vec<u64> = (0..240).map(|h| 1_700_000_000 + h * 3600).collect();
+        let values = (0..240)
+            .map(|h| 5.0 + (2.0 * PI * h as f64 / 24.0).sin())
+            .collect();
+        let period = TimeSeries::new(timestamps, values)
+            .dominant_period()
+            .unwrap();
+        assert!((period / 3600.0 - 24.0).abs() < 0.5, "{}", period / 3600.0);
+    }
+
+    #[test]
+    fn test_dominant_period_rejects_unsuitable_series() {
+        let flat = TimeSeries::new(vec![0, 1, 2, 3, 4], vec![1.0; 5]);
+        assert_eq!(flat.dominant_period(), None);
+
+        let irregular = TimeSeries::new(vec![0, 1, 2, 10, 11], vec![1.0, 2.0, 1.0, 2.0, 1.0]);
+        assert_eq!(irregular.dominant_period(), None);
+
+        let short = TimeSeries::new(vec![0, 1, 2], vec![1.0, 2.0, 1.0]);
+        assert_eq!(short.dominant_period(), None);
+    }
+
+    #[test]
+    fn test_dominant_period_rejects_non_finite_values() {
+        let timestamps: Vec<u64> = (0..48).collect();
+        let mut values: Vec<f64> = (0..48)
+            .map(|h| (2.0 * PI * h as f64 / 12.0).sin())
+            .collect();
+        values[5] = f64::NAN;
+        let nan = TimeSeries::new(timestamps.clone(), values.clone());
+        assert_eq!(nan.dominant_period(), None);
+
+        values[5] = f64::INFINITY;
+        assert_eq!(TimeSeries::new(timestamps, values).dominant_period(), None);
+    }
+}
@@ -0,0 +1,91 @@
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::prelude::*;
+use crate::TimeSeries;
+
+/// Descriptive statistics of a series, computed together by [`TimeSeries::summary`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Summary {
+    /// The number of points.
+    pub count: usize,
+    pub mean: f64,
+    /// The population standard deviation, as [`TimeSeries::std_dev`].
+    pub std_dev: f64,
+    pub min: f64,
+    pub max: f64,
+    pub median: f64,
+    /// `std_dev / mean`, as [`TimeSeries::coefficient_of_variation`].
+    pub coefficient_of_variation: f64,
+}
+
+impl TimeSeries {
+    /// Computes the common descriptive statistics in two passes over the values plus one sort.
+    ///
+    /// Every field equals the result of the corresponding method, such as
+    /// [`TimeSeries::mean`] or [`TimeSeries::median`], including NaN for an empty series.
+    pub fn summary(&self) -> Summary {
+        let mean = self.mean();
+        let variance = self
+            .values
+            .iter()
+            .map(|&value| (value - mean).powi(2))
+            .sum::<f64>()
+            / self.values.len() as f64;
+        let std_dev = variance.sqrt();
+        let order_stats = self.order_stats();
+        Summary {
+            count: self.values.len(),
+            mean,
+            std_dev,
+            min: order_stats.min,
+            max: order_stats.max,
+            median: order_stats.median,
+            coefficient_of_variation: std_dev / mean,
+        }
+    }
+
+    /// Calculates the coefficient of variation, the standard deviation relative to the mean.
+    ///
+    /// It is only meaningful for positive data; a mean of zero gives an infinite result, or NaN
+    /// if the values are also constant.
+    pub fn coefficient_of_variation(&self) -> f64 {
+        self.std_dev() / self.mean()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summary_matches_individual_methods() {
+        let ts = TimeSeries::new(
+            (0..7).map(|i| i * 86400).collect(),
+            vec![3.0, 1.5, 4.0, 1.0, 5.5, 9.0, 2.5],
+        );
+        let summary = ts.summary();
+        assert_eq!(summary.count, 7);
+        assert_eq!(summary.mean, ts.mean());
+        assert_eq!(summary.std_dev, ts.std_dev());
+        assert_eq!(summary.min, ts.min());
+        assert_eq!(summary.max, ts.max());
+        assert_eq!(summary.median, ts.median());
+        assert_eq!(
+            summary.coefficient_of_variation,
+            ts.coefficient_of_variation()
+        );
+    }
+
+    #[test]
+    fn test_coefficient_of_variation() {
+        let ts = TimeSeries::new(vec![1, 2], vec![2.0, 6.0]);
+        assert_eq!(ts.coefficient_of_variation(), 0.5);
+
+        let empty = TimeSeries::new(vec![], vec![]).summary();
+        assert_eq!(empty.count, 0);
+        assert!(empty.mean.is_nan() && empty.median.is_nan());
+    }
+}
@@ -0,0 +1,125 @@
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+use crate::{TimeSeries, TimeSeriesError};
+
+/// The unit of a series' Unix timestamps.
+///
+/// The statistics treat timestamps as plain integers, so rates such as
+/// [`TimeSeries::annualized_growth`] assume seconds. Series recorded in finer units can be
+/// brought to seconds with [`TimeSeries::convert_time_unit`] first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum TimeUnit {
+    /// Seconds since the epoch, as most of the crate assumes.
+    Seconds,
+    /// Milliseconds since the epoch, as JavaScript's `Date.now()` returns.
+    Millis,
+    /// Microseconds since the epoch.
+    Micros,
+}
+
+impl TimeUnit {
+    /// Returns the number of timestamp units in one second.
+    pub fn per_second(self) -> u64 {
+        match self {
+            TimeUnit::Seconds => 1,
+            TimeUnit::Millis => 1_000,
+            TimeUnit::Micros => 1_000_000,
+        }
+    }
+
+    /// Guesses the unit of a Unix timestamp from its magnitude.
+    ///
+    /// Dates from 1973 to 5138 are below 10^11 in seconds and at least that in milliseconds,
+    /// and likewise at 10^14 between milliseconds and microseconds, so any timestamp from that
+    /// span is classified correctly.
+    pub fn infer(timestamp: u64) -> TimeUnit {
+        if timestamp < 100_000_000_000 {
+            TimeUnit::Seconds
+        } else if timestamp < 100_000_000_000_000 {
+            TimeUnit::Millis
+        } else {
+            TimeUnit::Micros
+        }
+    }
+}
+
+impl TimeSeries {
+    /// Rescales the timestamps from the unit `from` to the unit `to`, keeping the values.
+    ///
+    /// Converting to a coarser unit rounds each timestamp down, so points within the same
+    /// second can end up sharing a timestamp.
+    ///
+    /// # Errors
+    /// Returns [`TimeSeriesError::TimestampOverflow`] for the first converted timestamp that
+    /// does not fit in a `u64`.
+    pub fn convert_time_unit(
+        &self,
+        from: TimeUnit,
+        to: TimeUnit,
+    ) -> Result<TimeSeries, TimeSeriesError> {
+        let (from, to) = (u128::from(from.per_second()), u128::from(to.per_second()));
+        let timestamps = self
+            .timestamps
+            .iter()
+            .enumerate()
+            .map(|(index, &t)| {
+                u64::try_from(u128::from(t) * to / from)
+                    .map_err(|_| TimeSeriesError::TimestampOverflow { index })
+            })
+            .collect::<Result<Vec<u64>, _>>()?;
+        Ok(TimeSeries::new(timestamps, self.values.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_infer_time_unit() {
+        assert_eq!(TimeUnit::infer(1_700_000_000), TimeUnit::Seconds);
+        assert_eq!(TimeUnit::infer(1_700_000_000_000), TimeUnit::Millis);
+        assert_eq!(TimeUnit::infer(1_700_000_000_000_000), TimeUnit::Micros);
+    }
+
+    #[test]
+    fn test_convert_time_unit() {
+        let millis = TimeSeries::new(
+            vec![1_700_000_000_000, 1_700_000_000_500, 1_700_000_060_000],
+            vec![1.0, 2.0, 3.0],
+        );
+        let seconds = millis
+            .convert_time_unit(TimeUnit::Millis, TimeUnit::Seconds)
+            .unwrap();
+        assert_eq!(
+            seconds.timestamps,
+            vec![1_700_000_000, 1_700_000_000, 1_700_000_060]
+        );
+        assert_eq!(seconds.values, millis.values);
+
+        let micros = seconds
+            .convert_time_unit(TimeUnit::Seconds, TimeUnit::Micros)
+            .unwrap();
+        assert_eq!(micros.timestamps[2], 1_700_000_060_000_000);
+        assert_eq!(
+            millis.median_time_step() / TimeUnit::Millis.per_second(),
+            seconds.median_time_step()
+        );
+    }
+
+    #[test]
+    fn test_convert_time_unit_overflow() {
+        let ts = TimeSeries::new(vec![1_700_000_000, u64::MAX / 1_000], vec![1.0, 2.0]);
+        assert!(ts
+            .convert_time_unit(TimeUnit::Seconds, TimeUnit::Millis)
+            .is_ok());
+        assert_eq!(
+            ts.convert_time_unit(TimeUnit::Seconds, TimeUnit::Micros)
+                .err(),
+            Some(TimeSeriesError::TimestampOverflow { index: 1 })
+        );
+    }
+}
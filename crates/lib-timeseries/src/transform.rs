@@ -0,0 +1,182 @@
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::prelude::*;
+use crate::{f64_to_u256, TimeSeries, TimeSeriesError, TransformLinkStruct};
+
+/// A deterministic transform that derives one time series from another.
+///
+/// The same `apply` implementation is used by the host and by the guest program, so a derived
+/// series published by the host can be recomputed bit-for-bit inside the zkVM.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum Transform {
+    /// A moving average with the given window size, see [`TimeSeries::moving_average`].
+    MovingAverage { window_size: usize },
+    /// An exponential moving average with the given smoothing factor, see
+    /// [`TimeSeries::exponential_moving_average`].
+    ExponentialMovingAverage { alpha: f64 },
+}
+
+impl Transform {
+    /// Returns the identifier committed for this transform.
+    pub fn id(&self) -> u8 {
+        match self {
+            Transform::MovingAverage { .. } => 0,
+            Transform::ExponentialMovingAverage { .. } => 1,
+        }
+    }
+
+    /// Computes the Keccak256 hash of the transform identifier and its parameters.
+    pub fn params_hash(&self) -> [u8; 32] {
+        let mut hasher = Keccak256::new();
+        hasher.update([self.id()]);
+        match self {
+            Transform::MovingAverage { window_size } => {
+                hasher.update((*window_size as u64).to_be_bytes())
+            }
+            Transform::ExponentialMovingAverage { alpha } => hasher.update(alpha.to_be_bytes()),
+        }
+        hasher.finalize().into()
+    }
+
+    /// Applies the transform to the given time series.
+    pub fn apply(&self, series: &TimeSeries) -> TimeSeries {
+        match self {
+            Transform::MovingAverage { window_size } => series.moving_average(*window_size),
+            Transform::ExponentialMovingAverage { alpha } => {
+                series.exponential_moving_average(*alpha)
+            }
+        }
+    }
+}
+
+impl TimeSeries {
+    /// Links a claimed output series to this (raw) series through a declared transform.
+    ///
+    /// The transform is recomputed over the raw series and compared element-wise against
+    /// `output`, which shares the raw series' timestamps.
+    ///
+    /// # Arguments
+    /// * `transform` - The transform the output claims to be derived with
+    /// * `output` - The claimed output values
+    /// * `tolerance` - The maximum allowed absolute error per element
+    ///
+    /// # Errors
+    /// Returns [`TimeSeriesError::NonFiniteValue`] if a raw or output value is NaN or infinite,
+    /// since its error could not be compared against `tolerance`.
+    ///
+    /// # Panics
+    /// Panics if `output` has a different length than the series, or if any element differs
+    /// from the recomputed transform by more than `tolerance`.
+    pub fn to_transform_link_public_values(
+        &self,
+        transform: &Transform,
+        output: &[f64],
+        tolerance: f64,
+    ) -> Result<TransformLinkStruct, TimeSeriesError> {
+        assert_eq!(
+            self.values.len(),
+            output.len(),
+            "Output must have the same length as the raw series"
+        );
+        self.validate_finite()?;
+        if let Some(index) = output.iter().position(|v| !v.is_finite()) {
+            return Err(TimeSeriesError::NonFiniteValue { index });
+        }
+        let expected = transform.apply(self);
+        let max_abs_error = expected
+            .values
+            .iter()
+            .zip(output.iter())
+            .map(|(&e, &o)| (e - o).abs())
+            // Unlike f64::max, keep a NaN error so that it fails the tolerance check.
+            .fold(0.0, |max, error| {
+                if error > max || error.is_nan() {
+                    error
+                } else {
+                    max
+                }
+            });
+        assert!(
+            max_abs_error <= tolerance,
+            "Output differs from the declared transform beyond tolerance"
+        );
+        let output_series = TimeSeries::new(self.timestamps.clone(), output.to_vec());
+
+        Ok(TransformLinkStruct {
+            raw_hash: alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(self.compute_hash()),
+            transform_id: transform.id(),
+            params_hash: alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(
+                transform.params_hash(),
+            ),
+            output_hash: alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(
+                output_series.compute_hash(),
+            ),
+            tolerance: f64_to_u256(tolerance),
+            max_abs_error: f64_to_u256(max_abs_error),
+            n: alloy_sol_types::private::Uint::<256, 4>::from(self.values.len()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn raw_series() -> TimeSeries {
+        TimeSeries::new(vec![1, 2, 3, 4, 5], vec![1.0, 2.0, 3.0, 4.0, 5.0])
+    }
+
+    #[test]
+    fn test_apply_matches_direct_call() {
+        let ts = raw_series();
+        let transform = Transform::MovingAverage { window_size: 3 };
+        assert_eq!(transform.apply(&ts).values, ts.moving_average(3).values);
+    }
+
+    #[test]
+    fn test_params_hash_depends_on_params() {
+        let a = Transform::MovingAverage { window_size: 3 };
+        let b = Transform::MovingAverage { window_size: 4 };
+        assert_ne!(a.params_hash(), b.params_hash());
+    }
+
+    #[test]
+    fn test_transform_link_verifies() {
+        let ts = raw_series();
+        let transform = Transform::MovingAverage { window_size: 3 };
+        let output = ts.moving_average(3).values;
+        let link = ts
+            .to_transform_link_public_values(&transform, &output, 1e-9)
+            .unwrap();
+        assert_eq!(link.transform_id, 0);
+        assert_eq!(link.max_abs_error, f64_to_u256(0.0));
+        assert_eq!(link.n, alloy_sol_types::private::Uint::<256, 4>::from(5));
+    }
+
+    #[test]
+    #[should_panic(expected = "Output differs from the declared transform beyond tolerance")]
+    fn test_transform_link_rejects_perturbed_output() {
+        let ts = raw_series();
+        let transform = Transform::ExponentialMovingAverage { alpha: 0.5 };
+        let mut output = ts.exponential_moving_average(0.5).values;
+        output[2] += 0.01;
+        let _ = ts.to_transform_link_public_values(&transform, &output, 1e-3);
+    }
+
+    #[test]
+    fn test_transform_link_rejects_non_finite_output() {
+        let ts = raw_series();
+        let transform = Transform::MovingAverage { window_size: 3 };
+        let mut output = ts.moving_average(3).values;
+        output[1] = f64::NAN;
+        assert_eq!(
+            ts.to_transform_link_public_values(&transform, &output, 1e-3)
+                .err(),
+            Some(TimeSeriesError::NonFiniteValue { index: 1 })
+        );
+    }
+}
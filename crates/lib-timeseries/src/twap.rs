@@ -0,0 +1,94 @@
+use crate::TimeSeries;
+
+impl TimeSeries {
+    /// Computes the mean of the values weighted by how long each one held.
+    ///
+    /// The series is read as a step function: each value holds from its timestamp until the next
+    /// one. The last point has no next timestamp, so it closes the series and gets weight 0; use
+    /// [`TimeSeries::twap`] to let it hold until a chosen end time. Timestamps must be sorted in
+    /// non-decreasing order.
+    ///
+    /// Returns `None` for fewer than two points or when no time elapses across the series.
+    pub fn time_weighted_mean(&self) -> Option<f64> {
+        let (first, last) = (*self.timestamps.first()?, *self.timestamps.last()?);
+        self.twap(first, last)
+    }
+
+    /// Computes the time-weighted average price over the window `start..end`.
+    ///
+    /// Each value holds from its timestamp until the next one, and the last value holds until
+    /// `end`, as an oracle price does until it is updated. Intervals that straddle a window edge
+    /// count only for their part inside the window. Time before the first point has no value, so
+    /// it is left out rather than counted. Timestamps must be sorted in non-decreasing order.
+    ///
+    /// Returns `None` if `start >= end` or no point's value holds anywhere inside the window.
+    pub fn twap(&self, start: u64, end: u64) -> Option<f64> {
+        let mut weighted_sum = 0.0;
+        let mut total_duration = 0u64;
+        for (i, (&timestamp, &value)) in self.timestamps.iter().zip(self.values.iter()).enumerate()
+        {
+            let next = self.timestamps.get(i + 1).copied().unwrap_or(end);
+            let from = timestamp.max(start);
+            let to = next.min(end);
+            if to > from {
+                weighted_sum += value * (to - from) as f64;
+                total_duration += to - from;
+            }
+        }
+        (total_duration > 0).then(|| weighted_sum / total_duration as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 2 for nine seconds, then 12 for one second, then 4.
+    fn steps() -> TimeSeries {
+        TimeSeries::new(vec![0, 9, 10], vec![2.0, 12.0, 4.0])
+    }
+
+    #[test]
+    fn test_time_weighted_mean_weights_by_duration() {
+        // (2 * 9 + 12 * 1) / 10; the closing 4 has no duration.
+        assert_eq!(steps().time_weighted_mean(), Some(3.0));
+        // The plain mean weights the short-lived 12 like the rest.
+        assert_eq!(steps().mean(), 6.0);
+    }
+
+    #[test]
+    fn test_twap_clips_partial_intervals() {
+        let ts = steps();
+        // [5, 9) at 2, [9, 10) at 12, [10, 15) at 4: (8 + 12 + 20) / 10.
+        assert_eq!(ts.twap(5, 15), Some(4.0));
+        assert_eq!(ts.twap(0, 9), Some(2.0));
+        assert_eq!(ts.twap(3, 4), Some(2.0));
+        // The last value holds past the last timestamp.
+        assert_eq!(ts.twap(20, 30), Some(4.0));
+        assert_eq!(ts.twap(0, 10), ts.time_weighted_mean());
+    }
+
+    #[test]
+    fn test_twap_ignores_time_before_first_point() {
+        let ts = TimeSeries::new(vec![100, 200], vec![10.0, 30.0]);
+        assert_eq!(ts.twap(0, 50), None);
+        // Only [100, 150) is covered.
+        assert_eq!(ts.twap(50, 150), Some(10.0));
+        assert_eq!(ts.twap(150, 250), Some(20.0));
+    }
+
+    #[test]
+    fn test_twap_undefined_cases() {
+        assert_eq!(steps().twap(9, 9), None);
+        assert_eq!(steps().twap(10, 5), None);
+        assert_eq!(TimeSeries::new(vec![], vec![]).time_weighted_mean(), None);
+        assert_eq!(
+            TimeSeries::new(vec![5], vec![1.0]).time_weighted_mean(),
+            None
+        );
+        assert_eq!(
+            TimeSeries::new(vec![5, 5], vec![1.0, 2.0]).time_weighted_mean(),
+            None
+        );
+    }
+}
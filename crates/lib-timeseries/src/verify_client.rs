@@ -0,0 +1,235 @@
+//! Verification helpers for services consuming time series proofs.
+//!
+//! Each helper verifies an SP1 proof against a verifying key, checks that the public values the
+//! caller holds are the ones the proof commits to, and decodes them into plain Rust values with
+//! the 1e18 fixed-point scaling removed. Proofs are always checked by the local verifier, whatever
+//! `SP1_PROVER` is set to, so a mock proof never verifies.
+//!
+//! Proofs are passed as the bincode serialization of `SP1ProofWithPublicValues` (what
+//! `SP1ProofWithPublicValues::save` writes), and verifying keys as the hex-encoded bincode
+//! serialization of `SP1VerifyingKey`, e.g. `hex::encode(bincode::serialize(&vk)?)`.
+
+use core::fmt;
+
+use alloy_sol_types::SolType;
+use sp1_sdk::{ProverClient, SP1ProofWithPublicValues, SP1VerifyingKey};
+
+use crate::{u256_to_f64, vec_u256_to_f64, MovingAveragePublicValuesStruct, PublicValuesStruct};
+
+/// Errors returned when verifying or decoding a proof.
+#[derive(Clone, Debug, PartialEq)]
+pub enum VerifyError {
+    /// The verifying key is not valid hex or does not deserialize to a verifying key.
+    InvalidVkey(String),
+    /// The proof bytes do not deserialize to an SP1 proof.
+    InvalidProof(String),
+    /// The public values differ from the ones committed by the proof.
+    PublicValuesMismatch,
+    /// The proof does not verify against the verifying key.
+    VerificationFailed(String),
+    /// The public values do not decode to the expected struct.
+    Decode(String),
+}
+
+impl fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VerifyError::InvalidVkey(e) => write!(f, "invalid verifying key: {e}"),
+            VerifyError::InvalidProof(e) => write!(f, "invalid proof: {e}"),
+            VerifyError::PublicValuesMismatch => {
+                write!(f, "public values differ from the proof's commitment")
+            }
+            VerifyError::VerificationFailed(e) => write!(f, "proof verification failed: {e}"),
+            VerifyError::Decode(e) => write!(f, "failed to decode public values: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for VerifyError {}
+
+/// The decoded public values of the data-hash statistics program.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimeSeriesSummary {
+    pub start_timestamp: u64,
+    pub end_timestamp: u64,
+    pub values_hash: [u8; 32],
+    pub mean: f64,
+    pub median: f64,
+    pub std_dev: f64,
+}
+
+/// The decoded public values of the moving-average program.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MovingAverageSummary {
+    pub start_timestamp: u64,
+    pub end_timestamp: u64,
+    pub values_hash: [u8; 32],
+    pub window_size: u64,
+    pub moving_averages: Vec<f64>,
+}
+
+/// Verifies a proof of the statistics program and decodes its public values.
+pub fn verify_and_decode_stats(
+    proof_bytes: &[u8],
+    public_values_bytes: &[u8],
+    vkey_hex: &str,
+) -> Result<TimeSeriesSummary, VerifyError> {
+    verify(proof_bytes, public_values_bytes, vkey_hex)?;
+    decode_stats(public_values_bytes)
+}
+
+/// Verifies a proof of the moving-average program and decodes its public values.
+pub fn verify_and_decode_moving_average(
+    proof_bytes: &[u8],
+    public_values_bytes: &[u8],
+    vkey_hex: &str,
+) -> Result<MovingAverageSummary, VerifyError> {
+    verify(proof_bytes, public_values_bytes, vkey_hex)?;
+    decode_moving_average(public_values_bytes)
+}
+
+/// Decodes the public values of the statistics program without verifying a proof.
+pub fn decode_stats(public_values_bytes: &[u8]) -> Result<TimeSeriesSummary, VerifyError> {
+    let decoded = PublicValuesStruct::abi_decode(public_values_bytes, true)
+        .map_err(|e| VerifyError::Decode(e.to_string()))?;
+    Ok(TimeSeriesSummary {
+        start_timestamp: to_u64(decoded.start_timestamp)?,
+        end_timestamp: to_u64(decoded.end_timestamp)?,
+        values_hash: decoded.values_hash.to_be_bytes(),
+        mean: u256_to_f64(decoded.mean),
+        median: u256_to_f64(decoded.median),
+        std_dev: u256_to_f64(decoded.std_dev),
+    })
+}
+
+/// Decodes the public values of the moving-average program without verifying a proof.
+pub fn decode_moving_average(
+    public_values_bytes: &[u8],
+) -> Result<MovingAverageSummary, VerifyError> {
+    let decoded = MovingAveragePublicValuesStruct::abi_decode(public_values_bytes, true)
+        .map_err(|e| VerifyError::Decode(e.to_string()))?;
+    Ok(MovingAverageSummary {
+        start_timestamp: to_u64(decoded.start_timestamp)?,
+        end_timestamp: to_u64(decoded.end_timestamp)?,
+        values_hash: decoded.values_hash.to_be_bytes(),
+        window_size: to_u64(decoded.window_size)?,
+        moving_averages: vec_u256_to_f64(&decoded.moving_averages),
+    })
+}
+
+/// Parses a hex-encoded, bincode-serialized verifying key, with or without a `0x` prefix.
+pub fn parse_vkey(vkey_hex: &str) -> Result<SP1VerifyingKey, VerifyError> {
+    let bytes = hex::decode(vkey_hex.strip_prefix("0x").unwrap_or(vkey_hex))
+        .map_err(|e| VerifyError::InvalidVkey(e.to_string()))?;
+    bincode::deserialize(&bytes).map_err(|e| VerifyError::InvalidVkey(e.to_string()))
+}
+
+fn verify(
+    proof_bytes: &[u8],
+    public_values_bytes: &[u8],
+    vkey_hex: &str,
+) -> Result<(), VerifyError> {
+    let proof: SP1ProofWithPublicValues =
+        bincode::deserialize(proof_bytes).map_err(|e| VerifyError::InvalidProof(e.to_string()))?;
+    if proof.public_values.as_slice() != public_values_bytes {
+        return Err(VerifyError::PublicValuesMismatch);
+    }
+    let vk = parse_vkey(vkey_hex)?;
+    // Not `ProverClient::new()`: it honours `SP1_PROVER=mock`, whose verifier accepts mock proofs
+    // without checking them.
+    ProverClient::local()
+        .verify(&proof, &vk)
+        .map_err(|e| VerifyError::VerificationFailed(e.to_string()))
+}
+
+fn to_u64(value: alloy_sol_types::private::Uint<256, 4>) -> Result<u64, VerifyError> {
+    u64::try_from(value).map_err(|_| VerifyError::Decode(format!("{value} does not fit in a u64")))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_sol_types::SolValue;
+    use sp1_sdk::{SP1Proof, SP1PublicValues, SP1Stdin};
+
+    use super::*;
+    use crate::TimeSeries;
+
+    fn sample_series() -> TimeSeries {
+        let timestamps: Vec<u64> = (0..5).map(|i| i * 86400).collect();
+        let values: Vec<f64> = (0..5).map(|i| i as f64 * 1.5).collect();
+        TimeSeries::new(timestamps, values)
+    }
+
+    /// Serializes a proof shaped like the ones `SP1_PROVER=mock` produces, which carry the
+    /// committed public values but no shard proofs.
+    fn mock_proof(public_values: &[u8]) -> Vec<u8> {
+        bincode::serialize(&SP1ProofWithPublicValues {
+            proof: SP1Proof::Core(vec![]),
+            stdin: SP1Stdin::new(),
+            public_values: SP1PublicValues::from(public_values),
+            sp1_version: String::new(),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_decode_stats() {
+        let ts = sample_series();
+        let bytes = ts.to_public_values().unwrap().abi_encode();
+        let summary = decode_stats(&bytes).unwrap();
+        assert_eq!(summary.start_timestamp, 0);
+        assert_eq!(summary.end_timestamp, 4 * 86400);
+        assert_eq!(summary.values_hash, ts.compute_hash());
+        assert!((summary.mean - ts.mean()).abs() < 1e-12);
+        assert!((summary.median - ts.median()).abs() < 1e-12);
+        assert!((summary.std_dev - ts.std_dev()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_decode_moving_average() {
+        let ts = sample_series();
+        let bytes = ts.to_moving_average_public_values(3).unwrap().abi_encode();
+        let summary = decode_moving_average(&bytes).unwrap();
+        assert_eq!(summary.window_size, 3);
+        assert_eq!(summary.moving_averages.len(), 5);
+        assert!(matches!(
+            decode_stats(&bytes[..64]),
+            Err(VerifyError::Decode(_))
+        ));
+    }
+
+    #[test]
+    fn test_rejects_malformed_inputs() {
+        let public_values = sample_series().to_public_values().unwrap().abi_encode();
+        assert!(matches!(
+            verify_and_decode_stats(&[], &public_values, "0xnot-hex"),
+            Err(VerifyError::InvalidProof(_))
+        ));
+        assert!(matches!(
+            verify_and_decode_stats(&mock_proof(&public_values), &public_values, "0xnot-hex"),
+            Err(VerifyError::InvalidVkey(_))
+        ));
+        assert!(matches!(
+            parse_vkey("0x00"),
+            Err(VerifyError::InvalidVkey(_))
+        ));
+    }
+
+    #[test]
+    fn test_rejects_mismatched_public_values() {
+        let ts = sample_series();
+        let committed = ts.to_public_values().unwrap().abi_encode();
+        let proof = mock_proof(&committed);
+
+        let shifted = TimeSeries::new(ts.timestamps.clone(), vec![9.0; 5]);
+        let claimed = shifted.to_public_values().unwrap().abi_encode();
+        assert_eq!(
+            verify_and_decode_stats(&proof, &claimed, "0x00"),
+            Err(VerifyError::PublicValuesMismatch)
+        );
+        assert_eq!(
+            verify_and_decode_moving_average(&proof, &committed[..64], "0x00"),
+            Err(VerifyError::PublicValuesMismatch)
+        );
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{f64_to_i256, TimeSeries, TimeSeriesError, VwapPublicValuesStruct};
+
+impl TimeSeries {
+    /// Computes the running volume-weighted average price, treating the values as prices.
+    ///
+    /// Point `i` of the output is `sum(price * volume) / sum(volume)` over points `0..=i`, so the
+    /// last point is the VWAP of the whole series. Leading points before any volume has traded
+    /// have no defined price and are NaN.
+    ///
+    /// # Arguments
+    /// * `volumes` - The volume traded at each point, with exactly the timestamps of this series
+    ///
+    /// # Errors
+    /// Returns [`TimeSeriesError::LengthMismatch`] or [`TimeSeriesError::TimestampMismatch`] if
+    /// the volumes are not paired with this series, [`TimeSeriesError::InvalidWeight`] if a
+    /// volume is negative or not finite, and [`TimeSeriesError::ZeroTotalWeight`] if no volume
+    /// traded at all.
+    pub fn vwap(&self, volumes: &TimeSeries) -> Result<TimeSeries, TimeSeriesError> {
+        self.check_paired(volumes)?;
+        if volumes.values.iter().all(|&volume| volume == 0.0) {
+            return Err(TimeSeriesError::ZeroTotalWeight);
+        }
+        let mut notional = 0.0;
+        let mut volume_so_far = 0.0;
+        let values = self
+            .values
+            .iter()
+            .zip(volumes.values.iter())
+            .map(|(&price, &volume)| {
+                notional += price * volume;
+                volume_so_far += volume;
+                if volume_so_far == 0.0 {
+                    f64::NAN
+                } else {
+                    notional / volume_so_far
+                }
+            })
+            .collect();
+        Ok(TimeSeries::new(self.timestamps.clone(), values))
+    }
+
+    /// Builds the public values of the VWAP program.
+    ///
+    /// `vwap` is the volume-weighted average price over the whole series. The prices and volumes
+    /// are committed by their own data hashes, so each can be checked against its source.
+    ///
+    /// # Errors
+    /// Returns [`TimeSeriesError::NonFiniteValue`] if a price is NaN or infinite, and otherwise
+    /// the same errors as [`TimeSeries::weighted_mean_by`], which rejects a NaN, infinite or
+    /// negative volume with [`TimeSeriesError::InvalidWeight`].
+    pub fn to_vwap_public_values(
+        &self,
+        volumes: &TimeSeries,
+    ) -> Result<VwapPublicValuesStruct, TimeSeriesError> {
+        self.validate_finite()?;
+        let vwap = self.weighted_mean_by(volumes)?;
+
+        Ok(VwapPublicValuesStruct {
+            values_hash: alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(
+                self.compute_hash(),
+            ),
+            volumes_hash: alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(
+                volumes.compute_hash(),
+            ),
+            vwap: f64_to_i256(vwap),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_running_vwap() {
+        let prices = TimeSeries::new(vec![1, 2, 3, 4], vec![10.0, 12.0, 11.0, 20.0]);
+        let volumes = TimeSeries::new(vec![1, 2, 3, 4], vec![0.0, 100.0, 300.0, 0.0]);
+        let vwap = prices.vwap(&volumes).unwrap();
+
+        assert_eq!(vwap.timestamps, prices.timestamps);
+        // Nothing has traded at the first point.
+        assert!(vwap.values[0].is_nan());
+        assert_eq!(vwap.values[1], 12.0);
+        // (12 * 100 + 11 * 300) / 400 = 11.25, unchanged by a point with no volume.
+        assert_eq!(vwap.values[2], 11.25);
+        assert_eq!(vwap.values[3], 11.25);
+        assert_eq!(prices.weighted_mean_by(&volumes), Ok(11.25));
+    }
+
+    #[test]
+    fn test_vwap_rejects_invalid_volumes() {
+        let prices = TimeSeries::new(vec![1, 2], vec![10.0, 12.0]);
+        assert_eq!(
+            prices
+                .vwap(&TimeSeries::new(vec![1, 2], vec![0.0, 0.0]))
+                .err(),
+            Some(TimeSeriesError::ZeroTotalWeight)
+        );
+        assert_eq!(
+            prices
+                .vwap(&TimeSeries::new(vec![1, 2], vec![5.0, -1.0]))
+                .err(),
+            Some(TimeSeriesError::InvalidWeight { index: 1 })
+        );
+        assert_eq!(
+            prices
+                .vwap(&TimeSeries::new(vec![1, 5], vec![5.0, 1.0]))
+                .err(),
+            Some(TimeSeriesError::TimestampMismatch { index: 1 })
+        );
+    }
+
+    #[test]
+    fn test_vwap_public_values() {
+        let prices = TimeSeries::new(vec![1, 2], vec![10.0, 20.0]);
+        let volumes = TimeSeries::new(vec![1, 2], vec![3.0, 1.0]);
+        let public_values = prices.to_vwap_public_values(&volumes).unwrap();
+
+        assert_eq!(public_values.vwap, f64_to_i256(12.5));
+        assert_eq!(
+            public_values.volumes_hash,
+            alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(volumes.compute_hash())
+        );
+        assert_ne!(public_values.values_hash, public_values.volumes_hash);
+    }
+
+    #[test]
+    fn test_vwap_public_values_reject_non_finite_inputs() {
+        let prices = TimeSeries::new(vec![1, 2], vec![10.0, f64::NAN]);
+        let volumes = TimeSeries::new(vec![1, 2], vec![3.0, 1.0]);
+        assert_eq!(
+            prices.to_vwap_public_values(&volumes).err(),
+            Some(TimeSeriesError::NonFiniteValue { index: 1 })
+        );
+
+        let prices = TimeSeries::new(vec![1, 2], vec![10.0, 20.0]);
+        for volume in [f64::NAN, f64::INFINITY] {
+            let volumes = TimeSeries::new(vec![1, 2], vec![volume, 1.0]);
+            assert_eq!(
+                prices.to_vwap_public_values(&volumes).err(),
+                Some(TimeSeriesError::InvalidWeight { index: 0 })
+            );
+        }
+    }
+}
@@ -0,0 +1,87 @@
+//! `wasm-bindgen` bindings for previewing statistics in the browser before proving them.
+//!
+//! Only the statistics are exposed; building public values and hashing stay on the prover side.
+//! Invalid parameters are reported as JavaScript errors rather than the panics the Rust API uses,
+//! since a panic aborts the whole WebAssembly instance.
+
+use wasm_bindgen::prelude::*;
+
+use crate::TimeSeries;
+
+/// A [`TimeSeries`] exported to JavaScript as `TimeSeries`.
+#[wasm_bindgen(js_name = TimeSeries)]
+pub struct WasmTimeSeries {
+    inner: TimeSeries,
+}
+
+#[wasm_bindgen(js_class = TimeSeries)]
+impl WasmTimeSeries {
+    /// Creates a series from a `BigUint64Array` of timestamps and a `Float64Array` of values.
+    #[wasm_bindgen(constructor)]
+    pub fn new(timestamps: Vec<u64>, values: Vec<f64>) -> Result<WasmTimeSeries, JsError> {
+        if timestamps.len() != values.len() {
+            return Err(JsError::new(
+                "timestamps and values must have the same length",
+            ));
+        }
+        Ok(WasmTimeSeries {
+            inner: TimeSeries::new(timestamps, values),
+        })
+    }
+
+    /// The number of points.
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.inner.values.len()
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.inner.mean()
+    }
+
+    pub fn median(&self) -> f64 {
+        self.inner.median()
+    }
+
+    #[wasm_bindgen(js_name = stdDev)]
+    pub fn std_dev(&self) -> f64 {
+        self.inner.std_dev()
+    }
+
+    pub fn min(&self) -> f64 {
+        self.inner.min()
+    }
+
+    pub fn max(&self) -> f64 {
+        self.inner.max()
+    }
+
+    /// The `q`-th quantile, for `q` in `0..=1`.
+    pub fn quantile(&self, q: f64) -> Result<f64, JsError> {
+        if !(0.0..=1.0).contains(&q) {
+            return Err(JsError::new("quantile must be between 0 and 1"));
+        }
+        Ok(self.inner.quantile(q))
+    }
+
+    /// The moving average values, one per point; see [`TimeSeries::moving_average`].
+    #[wasm_bindgen(js_name = movingAverage)]
+    pub fn moving_average(&self, window_size: usize) -> Result<Vec<f64>, JsError> {
+        if window_size == 0 {
+            return Err(JsError::new("window size must be positive"));
+        }
+        Ok(self.inner.moving_average(window_size).values)
+    }
+
+    /// The exponential moving average values, one per point.
+    #[wasm_bindgen(js_name = exponentialMovingAverage)]
+    pub fn exponential_moving_average(&self, alpha: f64) -> Result<Vec<f64>, JsError> {
+        if !(0.0..=1.0).contains(&alpha) {
+            return Err(JsError::new("alpha must be between 0 and 1"));
+        }
+        if self.inner.values.is_empty() {
+            return Ok(Vec::new());
+        }
+        Ok(self.inner.exponential_moving_average(alpha).values)
+    }
+}
@@ -0,0 +1,252 @@
+use sha3::{Digest, Keccak256};
+
+use crate::prelude::*;
+use crate::{
+    f64_to_i256, vec_f64_to_u256, TimeSeries, TimeSeriesError, WeightedAveragePublicValuesStruct,
+};
+
+impl TimeSeries {
+    /// Computes the weighted mean of the time series values.
+    ///
+    /// # Arguments
+    /// * `weights` - One non-negative weight per point; they need not sum to 1
+    ///
+    /// # Errors
+    /// Returns [`TimeSeriesError::LengthMismatch`] if there is not one weight per point,
+    /// [`TimeSeriesError::InvalidWeight`] if a weight is negative or not finite, and
+    /// [`TimeSeriesError::ZeroTotalWeight`] if the weights sum to zero.
+    pub fn weighted_mean(&self, weights: &[f64]) -> Result<f64, TimeSeriesError> {
+        self.check_weights(weights)?;
+        let total_weight: f64 = weights.iter().sum();
+        if total_weight == 0.0 {
+            return Err(TimeSeriesError::ZeroTotalWeight);
+        }
+        let weighted_sum: f64 = self
+            .values
+            .iter()
+            .zip(weights.iter())
+            .map(|(v, w)| v * w)
+            .sum();
+        Ok(weighted_sum / total_weight)
+    }
+
+    /// Computes the weighted mean using a paired series of weights, such as traded volumes.
+    ///
+    /// This is [`TimeSeries::weighted_mean`] for weights that carry their own timestamps, which
+    /// must match this series point for point.
+    ///
+    /// # Errors
+    /// Returns [`TimeSeriesError::LengthMismatch`] or [`TimeSeriesError::TimestampMismatch`] if
+    /// the weights are not paired with this series, and otherwise the same errors as
+    /// [`TimeSeries::weighted_mean`].
+    pub fn weighted_mean_by(&self, weights: &TimeSeries) -> Result<f64, TimeSeriesError> {
+        self.check_paired(weights)?;
+        self.weighted_mean(&weights.values)
+    }
+
+    /// Returns the trapezoidal weight of each point: half the gap to the previous timestamp plus
+    /// half the gap to the next one.
+    ///
+    /// Weighting values this way integrates the straight lines between consecutive points, so
+    /// each point counts for the time around it rather than once. Timestamps must be sorted in
+    /// non-decreasing order.
+    pub fn trapezoid_weights(&self) -> Vec<f64> {
+        let n = self.timestamps.len();
+        (0..n)
+            .map(|i| {
+                let before = if i > 0 {
+                    self.timestamps[i].saturating_sub(self.timestamps[i - 1])
+                } else {
+                    0
+                };
+                let after = if i + 1 < n {
+                    self.timestamps[i + 1].saturating_sub(self.timestamps[i])
+                } else {
+                    0
+                };
+                (before + after) as f64 / 2.0
+            })
+            .collect()
+    }
+
+    /// Computes the mean of the values weighted by [`TimeSeries::trapezoid_weights`].
+    ///
+    /// Unlike [`TimeSeries::time_weighted_mean`], which holds each value until the next point,
+    /// this interpolates linearly between points, so the first and last points count for half
+    /// of their one neighbouring gap.
+    ///
+    /// # Errors
+    /// Returns [`TimeSeriesError::ZeroTotalWeight`] if no time elapses across the series, which
+    /// includes series of fewer than two points.
+    pub fn trapezoidal_mean(&self) -> Result<f64, TimeSeriesError> {
+        self.weighted_mean(&self.trapezoid_weights())
+    }
+
+    /// Computes a Keccak256 hash committing to the time series together with its weights.
+    ///
+    /// Every point contributes its timestamp, value and weight, each as big-endian bytes, so the
+    /// hash extends the plain values hash with the weight of each point.
+    ///
+    /// # Errors
+    /// Returns the same weight errors as [`TimeSeries::weighted_mean`], except that weights
+    /// summing to zero are accepted.
+    pub fn weighted_hash(&self, weights: &[f64]) -> Result<[u8; 32], TimeSeriesError> {
+        self.check_weights(weights)?;
+        let mut hasher = Keccak256::new();
+        for ((timestamp, value), weight) in self
+            .timestamps
+            .iter()
+            .zip(self.values.iter())
+            .zip(weights.iter())
+        {
+            hasher.update(timestamp.to_be_bytes());
+            hasher.update(value.to_be_bytes());
+            hasher.update(weight.to_be_bytes());
+        }
+        Ok(hasher.finalize().into())
+    }
+
+    pub fn to_weighted_average_public_values(
+        &self,
+        weights: &[f64],
+    ) -> Result<WeightedAveragePublicValuesStruct, TimeSeriesError> {
+        let start_timestamp = *self.timestamps.first().unwrap_or(&0);
+        let end_timestamp = *self.timestamps.last().unwrap_or(&0);
+        let weighted_values_hash = self.weighted_hash(weights)?;
+        let weighted_mean = self.weighted_mean(weights)?;
+
+        Ok(WeightedAveragePublicValuesStruct {
+            start_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(start_timestamp),
+            end_timestamp: alloy_sol_types::private::Uint::<256, 4>::from(end_timestamp),
+            weighted_values_hash: alloy_sol_types::private::Uint::<256, 4>::from_be_bytes(
+                weighted_values_hash,
+            ),
+            weights: vec_f64_to_u256(weights),
+            weighted_mean: f64_to_i256(weighted_mean),
+        })
+    }
+
+    /// Checks that `weights` has exactly the timestamps of this series and valid weights.
+    pub(crate) fn check_paired(&self, weights: &TimeSeries) -> Result<(), TimeSeriesError> {
+        if weights.timestamps.len() != self.timestamps.len() {
+            return Err(TimeSeriesError::LengthMismatch {
+                expected: self.timestamps.len(),
+                actual: weights.timestamps.len(),
+            });
+        }
+        if let Some(index) = self
+            .timestamps
+            .iter()
+            .zip(weights.timestamps.iter())
+            .position(|(a, b)| a != b)
+        {
+            return Err(TimeSeriesError::TimestampMismatch { index });
+        }
+        self.check_weights(&weights.values)
+    }
+
+    fn check_weights(&self, weights: &[f64]) -> Result<(), TimeSeriesError> {
+        if weights.len() != self.values.len() {
+            return Err(TimeSeriesError::LengthMismatch {
+                expected: self.values.len(),
+                actual: weights.len(),
+            });
+        }
+        match weights.iter().position(|w| !w.is_finite() || *w < 0.0) {
+            Some(index) => Err(TimeSeriesError::InvalidWeight { index }),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weighted_mean() {
+        let ts = TimeSeries::new(vec![1, 2, 3], vec![10.0, 20.0, 40.0]);
+        // (10 * 1 + 20 * 2 + 40 * 1) / 4 = 22.5
+        assert_eq!(ts.weighted_mean(&[1.0, 2.0, 1.0]), Ok(22.5));
+        assert_eq!(ts.weighted_mean(&[1.0, 1.0, 1.0]), Ok(ts.mean()));
+    }
+
+    #[test]
+    fn test_trapezoidal_mean_on_uneven_spacing() {
+        // A long quiet stretch at 1.0, then a burst of closely spaced readings at 10.0.
+        let ts = TimeSeries::new(vec![0, 100, 101, 102], vec![1.0, 1.0, 10.0, 10.0]);
+        assert_eq!(ts.trapezoid_weights(), vec![50.0, 50.5, 1.0, 0.5]);
+        // (1 * 100.5 + 10 * 1.5) / 102: the burst barely moves the mean.
+        assert_eq!(ts.trapezoidal_mean(), Ok(115.5 / 102.0));
+        assert_eq!(ts.mean(), 5.5);
+
+        let even = TimeSeries::new(vec![0, 10, 20, 30], vec![2.0, 4.0, 6.0, 8.0]);
+        assert_eq!(even.trapezoidal_mean(), Ok(5.0));
+
+        let single = TimeSeries::new(vec![5], vec![1.0]);
+        assert_eq!(
+            single.trapezoidal_mean(),
+            Err(TimeSeriesError::ZeroTotalWeight)
+        );
+    }
+
+    #[test]
+    fn test_weighted_mean_errors() {
+        let ts = TimeSeries::new(vec![1, 2], vec![1.0, 2.0]);
+        assert_eq!(
+            ts.weighted_mean(&[1.0]),
+            Err(TimeSeriesError::LengthMismatch {
+                expected: 2,
+                actual: 1
+            })
+        );
+        assert_eq!(
+            ts.weighted_mean(&[1.0, -1.0]),
+            Err(TimeSeriesError::InvalidWeight { index: 1 })
+        );
+        assert_eq!(
+            ts.weighted_mean(&[0.0, 0.0]),
+            Err(TimeSeriesError::ZeroTotalWeight)
+        );
+    }
+
+    #[test]
+    fn test_weighted_mean_by_paired_series() {
+        let ts = TimeSeries::new(vec![1, 2, 3], vec![10.0, 20.0, 40.0]);
+        let weights = TimeSeries::new(vec![1, 2, 3], vec![1.0, 2.0, 1.0]);
+        assert_eq!(ts.weighted_mean_by(&weights), Ok(22.5));
+
+        let shifted = TimeSeries::new(vec![1, 3, 4], vec![1.0, 2.0, 1.0]);
+        assert_eq!(
+            ts.weighted_mean_by(&shifted),
+            Err(TimeSeriesError::TimestampMismatch { index: 1 })
+        );
+        let short = TimeSeries::new(vec![1, 2], vec![1.0, 2.0]);
+        assert_eq!(
+            ts.weighted_mean_by(&short),
+            Err(TimeSeriesError::LengthMismatch {
+                expected: 3,
+                actual: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_weighted_hash_depends_on_weights() {
+        let ts = TimeSeries::new(vec![1, 2], vec![1.0, 2.0]);
+        let a = ts.weighted_hash(&[1.0, 2.0]).unwrap();
+        let b = ts.weighted_hash(&[2.0, 1.0]).unwrap();
+        assert_ne!(a, b);
+        assert_ne!(a, ts.compute_hash());
+    }
+
+    #[test]
+    fn test_weighted_average_public_values() {
+        let ts = TimeSeries::new(vec![1, 2, 3], vec![-10.0, 20.0, 40.0]);
+        let public_values = ts
+            .to_weighted_average_public_values(&[3.0, 1.0, 0.0])
+            .unwrap();
+        assert_eq!(public_values.weights.len(), 3);
+        assert_eq!(public_values.weighted_mean, f64_to_i256(-2.5));
+    }
+}
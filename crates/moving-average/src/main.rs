@@ -14,7 +14,9 @@ pub fn main() {
     let time_series = TimeSeries::new(timestamps, forecast_values);
 
     // Generate the public values struct for moving average
-    let public_values = time_series.to_moving_average_public_values(window_size);
+    let public_values = time_series
+        .to_moving_average_public_values(window_size)
+        .expect("values must be finite");
 
     // Encode the public values using ABI encoding
     let bytes = public_values.abi_encode();
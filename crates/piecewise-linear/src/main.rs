@@ -0,0 +1,28 @@
+//! A SP1 program committing a piecewise-linear approximation of a time series.
+//!
+//! The breakpoints are committed together with the hash of the full series and the error bound,
+//! proving that the compact summary stays within `max_error` of every original point.
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use alloy_sol_types::SolValue;
+use lib_timeseries::TimeSeries;
+
+pub fn main() {
+    // Read the timestamps, values and error bound from the prover
+    let timestamps = sp1_zkvm::io::read::<Vec<u64>>();
+    let values = sp1_zkvm::io::read::<Vec<f64>>();
+    let max_error = sp1_zkvm::io::read::<f64>();
+
+    // Create a TimeSeries instance for statistical analysis
+    let time_series = TimeSeries::new(timestamps, values);
+
+    // Generate the public values struct for the piecewise-linear approximation
+    let public_values = time_series.to_piecewise_linear_public_values(max_error);
+
+    // Encode the public values using ABI encoding
+    let bytes = public_values.abi_encode();
+
+    // Commit the encoded public values as output of the ZK proof
+    sp1_zkvm::io::commit_slice(&bytes);
+}
@@ -0,0 +1,29 @@
+//! A SP1 program committing how many readings fell within a time range.
+//!
+//! Only the count, the range and the data hash are committed, so an auditor learns how many
+//! readings were taken in the window without seeing them.
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use alloy_sol_types::SolValue;
+use lib_timeseries::TimeSeries;
+
+pub fn main() {
+    // Read the timestamps, values and inclusive time range from the prover
+    let timestamps = sp1_zkvm::io::read::<Vec<u64>>();
+    let values = sp1_zkvm::io::read::<Vec<f64>>();
+    let range_start = sp1_zkvm::io::read::<u64>();
+    let range_end = sp1_zkvm::io::read::<u64>();
+
+    // Create a TimeSeries instance for statistical analysis
+    let time_series = TimeSeries::new(timestamps, values);
+
+    // Generate the public values struct for the range count
+    let public_values = time_series.to_range_count_public_values(range_start, range_end);
+
+    // Encode the public values using ABI encoding
+    let bytes = public_values.abi_encode();
+
+    // Commit the encoded public values as output of the ZK proof
+    sp1_zkvm::io::commit_slice(&bytes);
+}
@@ -0,0 +1,29 @@
+//! A SP1 program attesting continuous compliance of a time series.
+//!
+//! The program evaluates a predicate over every rolling window of the series and commits the
+//! results as a packed bitmap, so a verifier can check the compliance of any individual window.
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use alloy_sol_types::SolValue;
+use lib_timeseries::{Predicate, TimeSeries};
+
+pub fn main() {
+    // Read the timestamps and values from the prover
+    let timestamps = sp1_zkvm::io::read::<Vec<u64>>();
+    let values = sp1_zkvm::io::read::<Vec<f64>>();
+    let window_size = sp1_zkvm::io::read::<usize>();
+    let predicate = sp1_zkvm::io::read::<Predicate>();
+
+    // Create a TimeSeries instance for statistical analysis
+    let time_series = TimeSeries::new(timestamps, values);
+
+    // Generate the public values struct for the compliance bitmap
+    let public_values = time_series.to_compliance_public_values(window_size, predicate);
+
+    // Encode the public values using ABI encoding
+    let bytes = public_values.abi_encode();
+
+    // Commit the encoded public values as output of the ZK proof
+    sp1_zkvm::io::commit_slice(&bytes);
+}
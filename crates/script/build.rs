@@ -1,6 +1,48 @@
-use sp1_helper::build_program_with_args;
+use sp1_helper::{build_program_with_args, BuildArgs};
+
+/// The guest programs built into `elf/`, by crate directory.
+///
+/// Each is written to `elf/riscv32im-succinct-zkvm-<program>-elf` at the workspace root, where
+/// the bins and tests load it from, so the ELFs always match the guest sources.
+const PROGRAMS: &[&str] = &[
+    "data-hash",
+    "moving-average",
+    "transform-link",
+    "rolling-compliance",
+    "acf",
+    "dual-hash",
+    "weighted-average",
+    "piecewise-linear",
+    "holt-winters",
+    "exclusion-stats",
+    "order-stats",
+    "flatline",
+    "welch-t",
+    "ema",
+    "forecast-interval",
+    "non-negativity",
+    "extended-stats",
+    "range-count",
+    "exponential-growth",
+    "disjoint-claim",
+    "anomaly",
+    "band-breach",
+    "vwap",
+    "histogram",
+    "cusum",
+    "correlation",
+    "kalman",
+];
 
 fn main() {
-    // build_program_with_args("../data-hash", Default::default());
-    // build_program_with_args("../moving-average", Default::default());
+    for program in PROGRAMS {
+        build_program_with_args(
+            &format!("../{program}"),
+            BuildArgs {
+                elf_name: format!("riscv32im-succinct-zkvm-{program}-elf"),
+                output_directory: "../../elf".to_string(),
+                ..Default::default()
+            },
+        );
+    }
 }
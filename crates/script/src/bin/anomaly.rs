@@ -0,0 +1,98 @@
+//! An end-to-end example of proving the number of z-score anomalies in a time series.
+//!
+//! You can run this script using the following command:
+//! ```shell
+//! RUST_LOG=info cargo run --release --bin anomaly -- --execute --spikes 2
+//! ```
+//! or
+//! ```shell
+//! RUST_LOG=info cargo run --release --bin anomaly -- --prove --spikes 2
+//! ```
+
+use alloy_sol_types::SolType;
+use clap::Parser;
+use lib_timeseries::AnomalyPublicValuesStruct;
+use sp1_sdk::SP1Stdin;
+use tracing::log::info;
+use zk_timeseries_script::run::RunArgs;
+
+/// The ELF file for the Succinct RISC-V zkVM anomaly program.
+pub const ANOMALY_ELF: &[u8] =
+    include_bytes!("../../../../elf/riscv32im-succinct-zkvm-anomaly-elf");
+
+/// The arguments for the command.
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None)]
+struct Args {
+    #[clap(flatten)]
+    run: RunArgs,
+
+    #[clap(long, default_value = "200")]
+    n: usize,
+
+    /// The absolute z-score a point must exceed to count as an anomaly
+    #[clap(long, default_value = "3")]
+    z_threshold: f64,
+
+    /// The number of evenly spaced spikes to plant in the sample data
+    #[clap(long, default_value = "0")]
+    spikes: usize,
+}
+
+fn main() {
+    // Setup the logger.
+    sp1_sdk::utils::setup_logger();
+
+    // Parse the command line arguments.
+    let args = Args::parse();
+
+    if let Err(e) = run(&args) {
+        eprintln!("Error: {e}");
+        std::process::exit(1);
+    }
+}
+
+fn run(args: &Args) -> Result<(), String> {
+    args.run.check_mode()?;
+
+    // Setup the inputs.
+    let mut stdin = SP1Stdin::new();
+    // Generate some sample sensor readings, one per minute, with a few spikes planted
+    let timestamps: Vec<u64> = (0..args.n).map(|i| i as u64 * 60).collect();
+    let spacing = args.n / (args.spikes + 1);
+    let values: Vec<f64> = (0..args.n)
+        .map(|i| {
+            let spike = spacing > 0 && i > 0 && i % spacing == 0 && i / spacing <= args.spikes;
+            (i as f64 / 10.0).sin() + if spike { 50.0 } else { 0.0 }
+        })
+        .collect();
+
+    stdin.write(&timestamps);
+    stdin.write(&values);
+    stdin.write(&args.z_threshold);
+
+    info!("Timestamps: {:?}", timestamps);
+    info!("Values: {:?}", values);
+    info!("Z threshold: {}", args.z_threshold);
+
+    args.run.run("anomaly", ANOMALY_ELF, stdin, |output| {
+        let AnomalyPublicValuesStruct {
+            start_timestamp,
+            end_timestamp,
+            values_hash,
+            z_threshold,
+            anomaly_count,
+            first_anomaly_timestamp,
+        } = AnomalyPublicValuesStruct::abi_decode(output, true)
+            .map_err(|e| format!("failed to decode output: {e:?}"))?;
+
+        info!("Decoded output:");
+        info!("Start timestamp: {}", start_timestamp);
+        info!("End timestamp: {}", end_timestamp);
+        info!("Values hash: {}", values_hash);
+        info!("Z threshold: {}", z_threshold);
+        info!("Anomaly count: {}", anomaly_count);
+        info!("First anomaly timestamp: {}", first_anomaly_timestamp);
+        Ok(())
+    })
+}
@@ -0,0 +1,112 @@
+//! An end-to-end example of proving how much of a data provider's time range has not been claimed
+//! yet.
+//!
+//! The previously claimed ranges are read from a JSON file of `[start, end]` pairs. You can run
+//! this script using the following command:
+//! ```shell
+//! RUST_LOG=info cargo run --release --bin claim -- --execute --claimed claimed.json
+//! ```
+//! or
+//! ```shell
+//! RUST_LOG=info cargo run --release --bin claim -- --prove --claimed claimed.json
+//! ```
+
+use std::path::PathBuf;
+
+use alloy_sol_types::SolType;
+use clap::Parser;
+use lib_timeseries::{hash_ranges, ClaimStruct};
+use sp1_sdk::SP1Stdin;
+use tracing::log::info;
+use zk_timeseries_script::parse_ranges_json;
+use zk_timeseries_script::run::RunArgs;
+
+/// The ELF file for the Succinct RISC-V zkVM disjoint claim program.
+pub const DISJOINT_CLAIM_ELF: &[u8] =
+    include_bytes!("../../../../elf/riscv32im-succinct-zkvm-disjoint-claim-elf");
+
+/// The arguments for the command.
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None)]
+struct Args {
+    #[clap(flatten)]
+    run: RunArgs,
+
+    /// A JSON file holding the previously claimed half-open ranges, e.g. `[[0, 86400]]`
+    #[clap(long)]
+    claimed: PathBuf,
+
+    /// The published hash of the claimed ranges, as 0x-prefixed hex; the file must match it
+    #[clap(long)]
+    claimed_hash: Option<String>,
+}
+
+fn main() {
+    // Setup the logger.
+    sp1_sdk::utils::setup_logger();
+
+    // Parse the command line arguments.
+    let args = Args::parse();
+
+    if let Err(e) = run(&args) {
+        eprintln!("Error: {e}");
+        std::process::exit(1);
+    }
+}
+
+fn run(args: &Args) -> Result<(), String> {
+    args.run.check_mode()?;
+
+    let claimed = std::fs::read_to_string(&args.claimed)
+        .map_err(|e| format!("failed to read {}: {e}", args.claimed.display()))
+        .and_then(|json| parse_ranges_json(&json))?;
+
+    // The proof is only useful if it was made against the claims the verifier has on record.
+    let claimed_hash = format!("0x{}", hex::encode(hash_ranges(&claimed)));
+    if let Some(expected) = &args.claimed_hash {
+        if !expected.eq_ignore_ascii_case(&claimed_hash) {
+            return Err(format!(
+                "claimed ranges hash {claimed_hash} does not match {expected}"
+            ));
+        }
+    }
+
+    // Setup the inputs.
+    let mut stdin = SP1Stdin::new();
+    // Generate some sample data
+    let timestamps: Vec<u64> = (0..5).map(|i| i as u64 * 86400).collect();
+    let forecast_values: Vec<f64> = (0..5).map(|i| i as f64 * 1.5).collect();
+
+    stdin.write(&timestamps);
+    stdin.write(&forecast_values);
+    stdin.write(&claimed);
+
+    info!("Timestamps: {:?}", timestamps);
+    info!("Forecast values: {:?}", forecast_values);
+    info!("Claimed ranges: {:?}", claimed);
+
+    args.run
+        .run("disjoint-claim", DISJOINT_CLAIM_ELF, stdin, |output| {
+            let ClaimStruct {
+                values_hash,
+                new_start,
+                new_end,
+                claimed_ranges_hash,
+                non_overlapping_seconds,
+                n,
+            } = ClaimStruct::abi_decode(output, true)
+                .map_err(|e| format!("failed to decode output: {e:?}"))?;
+
+            if claimed_ranges_hash.to_be_bytes::<32>() != hash_ranges(&claimed) {
+                return Err("committed claimed ranges hash does not match the file".to_string());
+            }
+
+            info!("Decoded output:");
+            info!("Values hash: {}", values_hash);
+            info!("New range: [{}, {})", new_start, new_end);
+            info!("Claimed ranges hash: {}", claimed_ranges_hash);
+            info!("Non-overlapping seconds: {}", non_overlapping_seconds);
+            info!("Number of points: {}", n);
+            Ok(())
+        })
+}
@@ -0,0 +1,55 @@
+//! Generates and checks the conformance kit for third-party implementations.
+//!
+//! You can run this script using the following commands:
+//! ```shell
+//! cargo run --release --bin conformance -- gen-conformance --out conformance
+//! cargo run --release --bin conformance -- self-test --dir conformance
+//! ```
+
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand};
+use lib_timeseries::conformance::{verify_kit, write_kit, SEMANTICS_VERSION};
+
+/// The arguments for the command.
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None)]
+struct Args {
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Writes every conformance dataset with its expected commitments.
+    GenConformance {
+        #[clap(long)]
+        out: PathBuf,
+    },
+    /// Checks a conformance directory against the current implementation.
+    SelfTest {
+        #[clap(long)]
+        dir: PathBuf,
+    },
+}
+
+fn main() {
+    // Parse the command line arguments.
+    let args = Args::parse();
+
+    let result = match args.command {
+        Command::GenConformance { out } => write_kit(&out).map(|()| {
+            println!(
+                "Wrote the conformance kit for semantics version {SEMANTICS_VERSION} to {}",
+                out.display()
+            );
+        }),
+        Command::SelfTest { dir } => verify_kit(&dir).map(|count| {
+            println!("{count} conformance cases match semantics version {SEMANTICS_VERSION}");
+        }),
+    };
+    if let Err(e) = result {
+        eprintln!("Error: {e}");
+        std::process::exit(1);
+    }
+}
@@ -9,11 +9,20 @@
 //! ```shell
 //! RUST_LOG=info cargo run --release -- --prove
 //! ```
+//! Add `--max-cycles N` to fail when execution takes more than `N` cycles, and `--json` to print
+//! the cycle count for CI.
+
+use std::path::PathBuf;
+use std::time::Instant;
 
 use alloy_sol_types::SolType;
 use clap::Parser;
-use sp1_sdk::{ProverClient, SP1Stdin};
-use tracing::log::{error, info};
+use sp1_sdk::ProverClient;
+use tracing::log::info;
+use zk_timeseries_script::keys::KeyCacheArgs;
+use zk_timeseries_script::proof_stats::ProofStats;
+use zk_timeseries_script::registry::ProgramRegistry;
+use zk_timeseries_script::{check_max_cycles, cycles_json, load_series};
 
 /// The ELF file for the Succinct RISC-V zkVM data hash program.
 pub const DATA_HASH_ELF: &[u8] =
@@ -28,6 +37,22 @@ struct Args {
 
     #[clap(long)]
     prove: bool,
+
+    /// A CSV or JSON file to load the series from, instead of the sample data
+    #[clap(long)]
+    input: Option<PathBuf>,
+
+    /// Exit with an error if execution takes more than this many cycles
+    #[clap(long)]
+    max_cycles: Option<u64>,
+
+    /// Print the cycle count as JSON, `{"cycles": N}`, after executing, or the proof stats
+    /// after proving
+    #[clap(long)]
+    json: bool,
+
+    #[clap(flatten)]
+    keys: KeyCacheArgs,
 }
 
 fn main() {
@@ -37,22 +62,38 @@ fn main() {
     // Parse the command line arguments.
     let args = Args::parse();
 
-    if args.execute == args.prove {
-        eprintln!("Error: You must specify either --execute or --prove");
+    if let Err(e) = run(&args) {
+        eprintln!("Error: {e}");
         std::process::exit(1);
     }
+}
+
+fn run(args: &Args) -> Result<(), String> {
+    if args.execute == args.prove {
+        return Err("You must specify either --execute or --prove".to_string());
+    }
 
     // Setup the prover client.
     let client = ProverClient::new();
 
-    // Setup the inputs.
-    let mut stdin = SP1Stdin::new();
-    // Generate some sample data
-    let timestamps: Vec<u64> = (0..5).map(|i| i as u64 * 86400).collect();
-    let forecast_values: Vec<f64> = (0..5).map(|i| i as f64 * 1.5).collect();
-
-    stdin.write(&timestamps);
-    stdin.write(&forecast_values);
+    // Load the series from --input, or generate some sample data
+    let (timestamps, forecast_values) = match &args.input {
+        Some(path) => {
+            let series = load_series(path)?;
+            (series.timestamps, series.values)
+        }
+        None => (
+            (0..5).map(|i| i as u64 * 86400).collect(),
+            (0..5).map(|i| i as f64 * 1.5).collect(),
+        ),
+    };
+
+    // Setup the inputs in the order the program reads them.
+    let registry = ProgramRegistry::new();
+    let spec = registry.get("data-hash").expect("data-hash is registered");
+    let stdin = spec
+        .inputs
+        .build_stdin(&timestamps, &forecast_values, &[])?;
 
     info!("Timestamps: {:?}", timestamps);
     info!("Forecast values: {:?}", forecast_values);
@@ -60,52 +101,58 @@ fn main() {
     if args.execute {
         // Execute the program
         info!("Executing the program...");
-        match client.execute(DATA_HASH_ELF, stdin).run() {
-            Ok((output, report)) => {
-                info!("Program executed successfully.");
-
-                // Read the output.
-                match lib_timeseries::PublicValuesStruct::abi_decode(output.as_slice(), true) {
-                    Ok(decoded) => {
-                        let lib_timeseries::PublicValuesStruct {
-                            start_timestamp,
-                            end_timestamp,
-                            values_hash,
-                            mean,
-                            median,
-                            std_dev,
-                        } = decoded;
-
-                        info!("Decoded output:");
-                        info!("Start timestamp: {}", start_timestamp);
-                        info!("End timestamp: {}", end_timestamp);
-                        info!("Values hash: {}", values_hash);
-                        info!("Mean: {}", mean);
-                        info!("Median: {}", median);
-                        info!("Standard Deviation: {}", std_dev);
-                    }
-                    Err(e) => error!("Failed to decode output: {:?}", e),
-                }
-
-                // Record the number of cycles executed.
-                info!("Number of cycles: {}", report.total_instruction_count());
-            }
-            Err(e) => error!("Execution failed: {:?}", e),
+        let (output, report) = client
+            .execute(DATA_HASH_ELF, stdin)
+            .run()
+            .map_err(|e| format!("execution failed: {e:?}"))?;
+        info!("Program executed successfully.");
+
+        // Read the output.
+        let lib_timeseries::PublicValuesStruct {
+            start_timestamp,
+            end_timestamp,
+            values_hash,
+            mean,
+            median,
+            std_dev,
+        } = lib_timeseries::PublicValuesStruct::abi_decode(output.as_slice(), true)
+            .map_err(|e| format!("failed to decode output: {e:?}"))?;
+
+        info!("Decoded output:");
+        info!("Start timestamp: {}", start_timestamp);
+        info!("End timestamp: {}", end_timestamp);
+        info!("Values hash: {}", values_hash);
+        info!("Mean: {}", mean);
+        info!("Median: {}", median);
+        info!("Standard Deviation: {}", std_dev);
+
+        // Record the number of cycles executed.
+        let cycles = report.total_instruction_count();
+        info!("Number of cycles: {}", cycles);
+        if args.json {
+            println!("{}", cycles_json(cycles));
         }
+        check_max_cycles(cycles, args.max_cycles)?;
     } else {
         // Setup the program for proving.
-        let (pk, vk) = client.setup(DATA_HASH_ELF);
+        let (pk, vk) = args.keys.setup(&client, "data-hash", DATA_HASH_ELF);
 
-        // Generate the proof
+        // Generate the proof, timing it
+        let started = Instant::now();
         let proof = client
             .prove(&pk, stdin)
             .run()
-            .expect("failed to generate proof");
+            .map_err(|e| format!("failed to generate proof: {e:?}"))?;
+        let stats = ProofStats::core(started.elapsed(), &proof);
 
         println!("Successfully generated proof!");
+        stats.print(args.json);
 
         // Verify the proof.
-        client.verify(&proof, &vk).expect("failed to verify proof");
+        client
+            .verify(&proof, &vk)
+            .map_err(|e| format!("failed to verify proof: {e:?}"))?;
         println!("Successfully verified proof!");
     }
+    Ok(())
 }
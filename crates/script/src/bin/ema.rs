@@ -0,0 +1,114 @@
+//! An end-to-end example of using the SP1 SDK to generate a proof of a program that can be executed
+//! or have a core proof generated.
+//!
+//! You can run this script using the following command:
+//! ```shell
+//! RUST_LOG=info cargo run --release --bin ema -- --execute --ema-period 20
+//! ```
+//! or
+//! ```shell
+//! RUST_LOG=info cargo run --release --bin ema -- --prove --alpha 0.3
+//! ```
+
+use alloy_sol_types::SolType;
+use clap::{Parser, ValueEnum};
+use lib_timeseries::{AlphaConvention, EmaPublicValuesStruct, EmaSmoothing};
+use sp1_sdk::SP1Stdin;
+use tracing::log::info;
+use zk_timeseries_script::run::RunArgs;
+
+/// The ELF file for the Succinct RISC-V zkVM exponential moving average program.
+pub const EMA_ELF: &[u8] = include_bytes!("../../../../elf/riscv32im-succinct-zkvm-ema-elf");
+
+/// The arguments for the command.
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None)]
+struct Args {
+    #[clap(flatten)]
+    run: RunArgs,
+
+    /// The smoothing factor, used unless `--ema-period` is given
+    #[clap(long, default_value = "0.5")]
+    alpha: f64,
+
+    /// Derive the smoothing factor from this period instead of `--alpha`
+    #[clap(long)]
+    ema_period: Option<usize>,
+
+    /// The convention used to derive the smoothing factor from `--ema-period`
+    #[clap(long, value_enum, default_value = "span")]
+    ema_convention: Convention,
+}
+
+/// The conventions for deriving alpha from a period.
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum, Debug)]
+enum Convention {
+    /// alpha = 2 / (period + 1)
+    Span,
+    /// alpha = 1 / period
+    Wilder,
+}
+
+fn main() {
+    // Setup the logger.
+    sp1_sdk::utils::setup_logger();
+
+    // Parse the command line arguments.
+    let args = Args::parse();
+
+    if let Err(e) = run(&args) {
+        eprintln!("Error: {e}");
+        std::process::exit(1);
+    }
+}
+
+fn run(args: &Args) -> Result<(), String> {
+    args.run.check_mode()?;
+
+    // Setup the inputs.
+    let mut stdin = SP1Stdin::new();
+    // Generate some sample data
+    let timestamps: Vec<u64> = (0..5).map(|i| i as u64 * 86400).collect();
+    let forecast_values: Vec<f64> = (0..5).map(|i| i as f64 * 1.5).collect();
+
+    stdin.write(&timestamps);
+    stdin.write(&forecast_values);
+    let smoothing = match args.ema_period {
+        Some(period) => EmaSmoothing::Period {
+            period,
+            convention: match args.ema_convention {
+                Convention::Span => AlphaConvention::Span,
+                Convention::Wilder => AlphaConvention::Wilder,
+            },
+        },
+        None => EmaSmoothing::Alpha(args.alpha),
+    };
+    stdin.write(&smoothing);
+
+    info!("Timestamps: {:?}", timestamps);
+    info!("Forecast values: {:?}", forecast_values);
+    info!("Smoothing: {:?}", smoothing);
+
+    args.run.run("ema", EMA_ELF, stdin, |output| {
+        let EmaPublicValuesStruct {
+            start_timestamp,
+            end_timestamp,
+            values_hash,
+            period,
+            convention,
+            alpha,
+            ema,
+        } = EmaPublicValuesStruct::abi_decode(output, true)
+            .map_err(|e| format!("failed to decode output: {e:?}"))?;
+
+        info!("Decoded output:");
+        info!("Start timestamp: {}", start_timestamp);
+        info!("End timestamp: {}", end_timestamp);
+        info!("Values hash: {}", values_hash);
+        info!("Period: {}", period);
+        info!("Convention: {}", convention);
+        info!("Alpha: {}", alpha);
+        info!("EMA: {:?}", ema);
+        Ok(())
+    })
+}
@@ -9,19 +9,33 @@
 //! ```shell
 //! RUST_LOG=info cargo run --release --bin evm -- --system plonk
 //! ```
+//! Pass `--moving-average`, `--dual-hash` or `--extended` to prove those programs instead of the
+//! statistics. With `--periods-per-year`, the statistics fixture also carries the fund
+//! performance of the series, read as per-period returns.
 
 use alloy_sol_types::SolType;
 use clap::{Parser, ValueEnum};
-use lib_timeseries::{MovingAveragePublicValuesStruct, PublicValuesStruct};
+use lib_timeseries::{
+    f64_to_i256, f64_to_u256, DualHashPublicValuesStruct, ExtendedPublicValuesStruct,
+    MovingAveragePublicValuesStruct, PublicValuesStruct, TimeSeries,
+};
 use serde::{Deserialize, Serialize};
 use sp1_sdk::{HashableKey, ProverClient, SP1ProofWithPublicValues, SP1Stdin, SP1VerifyingKey};
 use std::path::PathBuf;
+use std::time::Instant;
+use zk_timeseries_script::keys::KeyCacheArgs;
+use zk_timeseries_script::load_series;
+use zk_timeseries_script::proof_stats::ProofStats;
 
 /// The ELF (executable and linkable format) file for the Succinct RISC-V zkVM.
 pub const TIMESERIES_ELF: &[u8] =
     include_bytes!("../../../../elf/riscv32im-succinct-zkvm-data-hash-elf");
 pub const MOVING_AVERAGE_ELF: &[u8] =
     include_bytes!("../../../../elf/riscv32im-succinct-zkvm-moving-average-elf");
+pub const DUAL_HASH_ELF: &[u8] =
+    include_bytes!("../../../../elf/riscv32im-succinct-zkvm-dual-hash-elf");
+pub const EXTENDED_STATS_ELF: &[u8] =
+    include_bytes!("../../../../elf/riscv32im-succinct-zkvm-extended-stats-elf");
 
 /// The arguments for the EVM command.
 #[derive(Parser, Debug)]
@@ -31,10 +45,29 @@ struct EVMArgs {
     n: u32,
     #[clap(long, value_enum, default_value = "groth16")]
     system: ProofSystem,
-    #[clap(long)]
+    #[clap(long, conflicts_with_all = ["dual_hash", "extended"])]
     moving_average: bool,
+    #[clap(long, conflicts_with = "extended")]
+    dual_hash: bool,
+    #[clap(long)]
+    extended: bool,
     #[clap(long, default_value = "3")]
     window_size: usize,
+    /// A CSV or JSON file to load the series from, instead of the sample data
+    #[clap(long)]
+    input: Option<PathBuf>,
+    /// Add the Sharpe ratio and annualized volatility of the values, read as per-period returns,
+    /// to the statistics fixture
+    #[clap(long, conflicts_with_all = ["moving_average", "dual_hash", "extended"])]
+    periods_per_year: Option<f64>,
+    /// The annual risk-free rate for the Sharpe ratio
+    #[clap(long, default_value = "0.0")]
+    risk_free_rate: f64,
+    /// Print the proof stats as JSON after proving
+    #[clap(long)]
+    json: bool,
+    #[clap(flatten)]
+    keys: KeyCacheArgs,
 }
 
 /// Enum representing the available proof systems
@@ -44,147 +77,450 @@ enum ProofSystem {
     Groth16,
 }
 
-/// A fixture that can be used to test the verification of SP1 zkVM proofs inside Solidity.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// The guest programs this script can prove.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum Program {
+    Statistics,
+    MovingAverage,
+    DualHash,
+    Extended,
+}
+
+/// The proof fields shared by every fixture.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct SP1TimeSeriesProofFixture {
-    start_timestamp: String,
-    end_timestamp: String,
-    values_hash: String,
-    window_size: Option<String>,
-    moving_averages: Option<Vec<String>>,
-    mean: Option<String>,
-    median: Option<String>,
-    std_dev: Option<String>,
+struct ProofFixture {
     vkey: String,
     public_values: String,
     proof: String,
 }
 
+/// A fixture for the statistics program, used to test proof verification inside Solidity.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StatsFixture {
+    start_timestamp: String,
+    end_timestamp: String,
+    values_hash: String,
+    mean: String,
+    median: String,
+    std_dev: String,
+    /// Only present with `--periods-per-year`, and when the returns vary. Signed, so the string
+    /// carries a leading '-' when the returns fall short of the risk-free rate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    sharpe_ratio: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    annualized_volatility: Option<String>,
+    #[serde(flatten)]
+    proof: ProofFixture,
+}
+
+/// A fixture for the moving average program.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MovingAverageFixture {
+    start_timestamp: String,
+    end_timestamp: String,
+    values_hash: String,
+    window_size: String,
+    moving_averages: Vec<String>,
+    #[serde(flatten)]
+    proof: ProofFixture,
+}
+
+/// A fixture for the dual hash program, which commits both the Keccak256 and SHA-256 hashes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DualHashFixture {
+    start_timestamp: String,
+    end_timestamp: String,
+    values_hash: String,
+    values_hash_sha256: String,
+    mean: String,
+    median: String,
+    std_dev: String,
+    #[serde(flatten)]
+    proof: ProofFixture,
+}
+
+/// A fixture for the extended statistics program.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ExtendedFixture {
+    start_timestamp: String,
+    end_timestamp: String,
+    values_hash: String,
+    mean: String,
+    median: String,
+    std_dev: String,
+    /// Signed, so the string carries a leading '-' when the series shrank.
+    annualized_growth: String,
+    #[serde(flatten)]
+    proof: ProofFixture,
+}
+
+impl StatsFixture {
+    fn new(public_values: &[u8], proof: ProofFixture) -> Self {
+        let PublicValuesStruct {
+            start_timestamp,
+            end_timestamp,
+            values_hash,
+            mean,
+            median,
+            std_dev,
+        } = PublicValuesStruct::abi_decode(public_values, false).unwrap();
+
+        StatsFixture {
+            start_timestamp: start_timestamp.to_string(),
+            end_timestamp: end_timestamp.to_string(),
+            values_hash: values_hash.to_string(),
+            mean: mean.to_string(),
+            median: median.to_string(),
+            std_dev: std_dev.to_string(),
+            sharpe_ratio: None,
+            annualized_volatility: None,
+            proof,
+        }
+    }
+
+    /// Adds the fund performance of `returns` in the 1e18 fixed-point form of the committed
+    /// statistics.
+    ///
+    /// No program commits these yet, so they are computed on the host from the proven series.
+    fn with_fund_performance(
+        mut self,
+        returns: &TimeSeries,
+        risk_free_rate: f64,
+        periods_per_year: f64,
+    ) -> Self {
+        self.sharpe_ratio = returns
+            .sharpe_ratio(risk_free_rate, periods_per_year)
+            .map(|ratio| f64_to_i256(ratio).to_string());
+        self.annualized_volatility = returns
+            .annualized_volatility(periods_per_year)
+            .map(|volatility| f64_to_u256(volatility).to_string());
+        self
+    }
+}
+
+impl MovingAverageFixture {
+    fn new(public_values: &[u8], proof: ProofFixture) -> Self {
+        let MovingAveragePublicValuesStruct {
+            start_timestamp,
+            end_timestamp,
+            values_hash,
+            window_size,
+            moving_averages,
+        } = MovingAveragePublicValuesStruct::abi_decode(public_values, false).unwrap();
+
+        MovingAverageFixture {
+            start_timestamp: start_timestamp.to_string(),
+            end_timestamp: end_timestamp.to_string(),
+            values_hash: values_hash.to_string(),
+            window_size: window_size.to_string(),
+            moving_averages: moving_averages.iter().map(|v| v.to_string()).collect(),
+            proof,
+        }
+    }
+}
+
+impl DualHashFixture {
+    fn new(public_values: &[u8], proof: ProofFixture) -> Self {
+        let DualHashPublicValuesStruct {
+            start_timestamp,
+            end_timestamp,
+            values_hash_keccak,
+            values_hash_sha256,
+            mean,
+            median,
+            std_dev,
+        } = DualHashPublicValuesStruct::abi_decode(public_values, false).unwrap();
+
+        DualHashFixture {
+            start_timestamp: start_timestamp.to_string(),
+            end_timestamp: end_timestamp.to_string(),
+            values_hash: values_hash_keccak.to_string(),
+            values_hash_sha256: values_hash_sha256.to_string(),
+            mean: mean.to_string(),
+            median: median.to_string(),
+            std_dev: std_dev.to_string(),
+            proof,
+        }
+    }
+}
+
+impl ExtendedFixture {
+    fn new(public_values: &[u8], proof: ProofFixture) -> Self {
+        let ExtendedPublicValuesStruct {
+            start_timestamp,
+            end_timestamp,
+            values_hash,
+            mean,
+            median,
+            std_dev,
+            annualized_growth,
+        } = ExtendedPublicValuesStruct::abi_decode(public_values, false).unwrap();
+
+        ExtendedFixture {
+            start_timestamp: start_timestamp.to_string(),
+            end_timestamp: end_timestamp.to_string(),
+            values_hash: values_hash.to_string(),
+            mean: mean.to_string(),
+            median: median.to_string(),
+            std_dev: std_dev.to_string(),
+            annualized_growth: annualized_growth.to_string(),
+            proof,
+        }
+    }
+}
+
 fn main() {
     // Setup the logger.
     sp1_sdk::utils::setup_logger();
 
     // Parse the command line arguments.
     let args = EVMArgs::parse();
+    if args
+        .periods_per_year
+        .is_some_and(|periods| periods.is_nan() || periods <= 0.0)
+    {
+        eprintln!("Error: --periods-per-year must be positive");
+        std::process::exit(1);
+    }
 
     // Setup the prover client.
     let client = ProverClient::new();
 
     // Setup the program.
-    let elf = if args.moving_average {
-        MOVING_AVERAGE_ELF
+    let program = if args.moving_average {
+        Program::MovingAverage
+    } else if args.dual_hash {
+        Program::DualHash
+    } else if args.extended {
+        Program::Extended
     } else {
-        TIMESERIES_ELF
+        Program::Statistics
     };
-    let (pk, vk) = client.setup(elf);
+    let (name, elf) = match program {
+        Program::Statistics => ("data-hash", TIMESERIES_ELF),
+        Program::MovingAverage => ("moving-average", MOVING_AVERAGE_ELF),
+        Program::DualHash => ("dual-hash", DUAL_HASH_ELF),
+        Program::Extended => ("extended-stats", EXTENDED_STATS_ELF),
+    };
+    let (pk, vk) = args.keys.setup(&client, name, elf);
 
     // Setup the inputs.
     let mut stdin = SP1Stdin::new();
 
-    // Generate some sample data
-    let timestamps: Vec<u64> = (0..args.n).map(|i| i as u64 * 86400).collect();
-    let forecast_values: Vec<f64> = (0..args.n).map(|i| i as f64 * 1.5).collect();
+    // Load the series from --input, or generate some sample data. The growth rate needs a
+    // positive first value, so the extended program's sample data starts at 1.5.
+    let offset = if program == Program::Extended { 1 } else { 0 };
+    let (timestamps, forecast_values) = match &args.input {
+        Some(path) => match load_series(path) {
+            Ok(series) => (series.timestamps, series.values),
+            Err(e) => {
+                eprintln!("Error: {e}");
+                std::process::exit(1);
+            }
+        },
+        None => (
+            (0..args.n).map(|i| i as u64 * 86400).collect(),
+            (0..args.n).map(|i| (i + offset) as f64 * 1.5).collect(),
+        ),
+    };
 
     // Write the sample data to stdin
     stdin.write(&timestamps);
     stdin.write(&forecast_values);
-    if args.moving_average {
+    if program == Program::MovingAverage {
         stdin.write(&args.window_size);
     }
 
-    println!("n: {}", args.n);
+    println!("n: {}", timestamps.len());
     println!("Proof System: {:?}", args.system);
 
-    // Generate the proof based on the selected proof system.
+    // Generate the proof based on the selected proof system, timing it.
+    let started = Instant::now();
     let proof = match args.system {
         ProofSystem::Plonk => client.prove(&pk, stdin).plonk().run(),
         ProofSystem::Groth16 => client.prove(&pk, stdin).groth16().run(),
     }
     .expect("failed to generate proof");
+    let system = match args.system {
+        ProofSystem::Plonk => "plonk",
+        ProofSystem::Groth16 => "groth16",
+    };
+    // The size of the proof as submitted on chain.
+    ProofStats::new(system, started.elapsed(), proof.bytes().len()).print(args.json);
 
-    create_proof_fixture(&proof, &vk, args.system, args.moving_average);
+    let series = TimeSeries::new(timestamps, forecast_values);
+    create_proof_fixture(&proof, &vk, &args, program, &series);
 }
 
 /// Create a fixture for the given proof.
+///
+/// The statistics fixture is written to `<system>-fixture.json`, and the other programs' to
+/// `<system>-<program>-fixture.json`, each with only the fields its program commits, plus the
+/// fund performance of `series` with `--periods-per-year`.
 fn create_proof_fixture(
     proof: &SP1ProofWithPublicValues,
     vk: &SP1VerifyingKey,
-    system: ProofSystem,
-    is_moving_average: bool,
+    args: &EVMArgs,
+    program: Program,
+    series: &TimeSeries,
 ) {
     let bytes = proof.public_values.as_slice();
-    let fixture = if is_moving_average {
-        let MovingAveragePublicValuesStruct {
-            start_timestamp,
-            end_timestamp,
-            values_hash,
-            window_size,
-            moving_averages,
-        } = MovingAveragePublicValuesStruct::abi_decode(bytes, false).unwrap();
-
-        SP1TimeSeriesProofFixture {
-            start_timestamp: start_timestamp.to_string(),
-            end_timestamp: end_timestamp.to_string(),
-            values_hash: values_hash.to_string(),
-            window_size: Some(window_size.to_string()),
-            moving_averages: Some(moving_averages.iter().map(|v| v.to_string()).collect()),
-            mean: None,
-            median: None,
-            std_dev: None,
-            vkey: vk.bytes32().to_string(),
-            public_values: format!("0x{}", hex::encode(bytes)),
-            proof: format!("0x{}", hex::encode(proof.bytes())),
-        }
-    } else {
-        // Deserialize the public values.
-        let PublicValuesStruct {
-            start_timestamp,
-            end_timestamp,
-            values_hash,
-            mean,
-            median,
-            std_dev,
-        } = PublicValuesStruct::abi_decode(bytes, false).unwrap();
-
-        // Create the testing fixture so we can test things end-to-end.
-        SP1TimeSeriesProofFixture {
-            start_timestamp: start_timestamp.to_string(),
-            end_timestamp: end_timestamp.to_string(),
-            values_hash: values_hash.to_string(),
-            mean: Some(mean.to_string()),
-            median: Some(median.to_string()),
-            std_dev: Some(std_dev.to_string()),
-            window_size: None,
-            moving_averages: None,
-            vkey: vk.bytes32().to_string(),
-            public_values: format!("0x{}", hex::encode(bytes)),
-            proof: format!("0x{}", hex::encode(proof.bytes())),
-        }
+    let proof_fields = ProofFixture {
+        vkey: vk.bytes32().to_string(),
+        public_values: format!("0x{}", hex::encode(bytes)),
+        proof: format!("0x{}", hex::encode(proof.bytes())),
     };
 
     // The verification key is used to verify that the proof corresponds to the execution of the
     // program on the given input.
     //
     // Note that the verification key stays the same regardless of the input.
-    println!("Verification Key: {}", fixture.vkey);
+    println!("Verification Key: {}", proof_fields.vkey);
 
     // The public values are the values which are publicly committed to by the zkVM.
     //
     // If you need to expose the inputs or outputs of your program, you should commit them in
     // the public values.
-    println!("Public Values: {}", fixture.public_values);
+    println!("Public Values: {}", proof_fields.public_values);
 
     // The proof proves to the verifier that the program was executed with some inputs that led to
     // the give public values.
-    println!("Proof Bytes: {}", fixture.proof);
+    println!("Proof Bytes: {}", proof_fields.proof);
+
+    let (suffix, json) = match program {
+        Program::Statistics => {
+            let mut fixture = StatsFixture::new(bytes, proof_fields);
+            if let Some(periods_per_year) = args.periods_per_year {
+                fixture =
+                    fixture.with_fund_performance(series, args.risk_free_rate, periods_per_year);
+            }
+            ("", to_json(&fixture))
+        }
+        Program::MovingAverage => (
+            "-moving-average",
+            to_json(&MovingAverageFixture::new(bytes, proof_fields)),
+        ),
+        Program::DualHash => (
+            "-dual-hash",
+            to_json(&DualHashFixture::new(bytes, proof_fields)),
+        ),
+        Program::Extended => (
+            "-extended",
+            to_json(&ExtendedFixture::new(bytes, proof_fields)),
+        ),
+    };
 
     // Save the fixture to a file.
     let fixture_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../contracts/src/fixtures");
     std::fs::create_dir_all(&fixture_path).expect("failed to create fixture path");
     std::fs::write(
-        fixture_path.join(format!("{:?}-fixture.json", system).to_lowercase()),
-        serde_json::to_string_pretty(&fixture).unwrap(),
+        fixture_path.join(format!("{:?}{suffix}-fixture.json", args.system).to_lowercase()),
+        json,
     )
     .expect("failed to write fixture");
 }
+
+fn to_json<T: Serialize>(fixture: &T) -> String {
+    serde_json::to_string_pretty(fixture).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_sol_types::SolValue;
+
+    fn proof_fields(public_values: &[u8]) -> ProofFixture {
+        ProofFixture {
+            vkey: "0x00ab".to_string(),
+            public_values: format!("0x{}", hex::encode(public_values)),
+            proof: "0x1234".to_string(),
+        }
+    }
+
+    fn series() -> TimeSeries {
+        TimeSeries::new(vec![0, 86400, 172800], vec![1.5, 3.0, 4.5])
+    }
+
+    /// Serializes a fixture, checks it has no null fields and deserializes it back.
+    fn round_trip<T: Serialize + serde::de::DeserializeOwned>(fixture: &T) -> (T, String) {
+        let json = to_json(fixture);
+        assert!(!json.contains("null"), "{json}");
+        (serde_json::from_str(&json).unwrap(), json)
+    }
+
+    #[test]
+    fn test_stats_fixture_round_trip() {
+        let bytes = series().to_public_values().unwrap().abi_encode();
+        let fixture = StatsFixture::new(&bytes, proof_fields(&bytes));
+        let (decoded, json) = round_trip(&fixture);
+        assert_eq!(decoded, fixture);
+        assert!(json.contains("\"stdDev\""));
+        assert!(json.contains("\"vkey\""));
+        assert!(!json.contains("movingAverages"));
+        assert!(!json.contains("sharpeRatio"));
+    }
+
+    #[test]
+    fn test_stats_fixture_carries_fund_performance() {
+        let returns = TimeSeries::new(vec![0, 1, 2, 3], vec![0.01, -0.02, 0.03, 0.01]);
+        let bytes = returns.to_public_values().unwrap().abi_encode();
+        let fixture = StatsFixture::new(&bytes, proof_fields(&bytes))
+            .with_fund_performance(&returns, 0.0, 12.0);
+        assert_eq!(
+            fixture.sharpe_ratio,
+            Some(f64_to_i256(returns.sharpe_ratio(0.0, 12.0).unwrap()).to_string())
+        );
+        assert_eq!(
+            fixture.annualized_volatility,
+            Some(f64_to_u256(returns.annualized_volatility(12.0).unwrap()).to_string())
+        );
+        let (decoded, json) = round_trip(&fixture);
+        assert_eq!(decoded, fixture);
+        assert!(json.contains("\"sharpeRatio\""));
+        assert!(json.contains("\"annualizedVolatility\""));
+
+        // A losing fund has a negative Sharpe ratio.
+        let fixture = StatsFixture::new(&bytes, proof_fields(&bytes))
+            .with_fund_performance(&returns, 1.0, 12.0);
+        assert!(fixture.sharpe_ratio.unwrap().starts_with('-'));
+
+        // Constant returns have no ratio or volatility, so both are left out.
+        let flat = TimeSeries::new(vec![0, 1, 2], vec![0.01; 3]);
+        let fixture =
+            StatsFixture::new(&bytes, proof_fields(&bytes)).with_fund_performance(&flat, 0.0, 12.0);
+        assert_eq!(round_trip(&fixture).0, fixture);
+        assert!(!to_json(&fixture).contains("sharpeRatio"));
+    }
+
+    #[test]
+    fn test_moving_average_fixture_round_trip() {
+        let bytes = series()
+            .to_moving_average_public_values(2)
+            .unwrap()
+            .abi_encode();
+        let fixture = MovingAverageFixture::new(&bytes, proof_fields(&bytes));
+        assert_eq!(fixture.moving_averages.len(), 3);
+        let (decoded, json) = round_trip(&fixture);
+        assert_eq!(decoded, fixture);
+        assert!(!json.contains("\"mean\""));
+    }
+
+    #[test]
+    fn test_dual_hash_fixture_round_trip() {
+        let bytes = series().to_dual_hash_public_values().abi_encode();
+        let fixture = DualHashFixture::new(&bytes, proof_fields(&bytes));
+        assert_eq!(round_trip(&fixture).0, fixture);
+    }
+
+    #[test]
+    fn test_extended_fixture_round_trip() {
+        let bytes = series().to_extended_public_values().unwrap().abi_encode();
+        let fixture = ExtendedFixture::new(&bytes, proof_fields(&bytes));
+        assert_eq!(round_trip(&fixture).0, fixture);
+    }
+}
@@ -0,0 +1,96 @@
+//! An end-to-end example of proving statistics over a time series with maintenance windows
+//! excluded.
+//!
+//! You can run this script using the following command:
+//! ```shell
+//! RUST_LOG=info cargo run --release --bin exclude -- --execute --exclude 86400-172800
+//! ```
+//! or
+//! ```shell
+//! RUST_LOG=info cargo run --release --bin exclude -- --prove --exclude 86400-172800
+//! ```
+
+use alloy_sol_types::SolType;
+use clap::Parser;
+use lib_timeseries::ExclusionPublicValuesStruct;
+use sp1_sdk::SP1Stdin;
+use tracing::log::info;
+use zk_timeseries_script::parse_ranges;
+use zk_timeseries_script::run::RunArgs;
+
+/// The ELF file for the Succinct RISC-V zkVM exclusion statistics program.
+pub const EXCLUSION_STATS_ELF: &[u8] =
+    include_bytes!("../../../../elf/riscv32im-succinct-zkvm-exclusion-stats-elf");
+
+/// The arguments for the command.
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None)]
+struct Args {
+    #[clap(flatten)]
+    run: RunArgs,
+
+    /// Comma-separated half-open time ranges to exclude, e.g. `1700000000-1700003600,...`
+    #[clap(long, default_value = "")]
+    exclude: String,
+}
+
+fn main() {
+    // Setup the logger.
+    sp1_sdk::utils::setup_logger();
+
+    // Parse the command line arguments.
+    let args = Args::parse();
+
+    if let Err(e) = run(&args) {
+        eprintln!("Error: {e}");
+        std::process::exit(1);
+    }
+}
+
+fn run(args: &Args) -> Result<(), String> {
+    args.run.check_mode()?;
+
+    let exclude = parse_ranges(&args.exclude)?;
+
+    // Setup the inputs.
+    let mut stdin = SP1Stdin::new();
+    // Generate some sample data
+    let timestamps: Vec<u64> = (0..5).map(|i| i as u64 * 86400).collect();
+    let forecast_values: Vec<f64> = (0..5).map(|i| i as f64 * 1.5).collect();
+
+    stdin.write(&timestamps);
+    stdin.write(&forecast_values);
+    stdin.write(&exclude);
+
+    info!("Timestamps: {:?}", timestamps);
+    info!("Forecast values: {:?}", forecast_values);
+    info!("Excluded ranges: {:?}", exclude);
+
+    args.run
+        .run("exclusion-stats", EXCLUSION_STATS_ELF, stdin, |output| {
+            let ExclusionPublicValuesStruct {
+                start_timestamp,
+                end_timestamp,
+                values_hash,
+                filtered_values_hash,
+                exclusions_hash,
+                excluded_duration,
+                mean,
+                median,
+                std_dev,
+            } = ExclusionPublicValuesStruct::abi_decode(output, true)
+                .map_err(|e| format!("failed to decode output: {e:?}"))?;
+
+            info!("Decoded output:");
+            info!("Start timestamp: {}", start_timestamp);
+            info!("End timestamp: {}", end_timestamp);
+            info!("Values hash: {}", values_hash);
+            info!("Filtered values hash: {}", filtered_values_hash);
+            info!("Exclusions hash: {}", exclusions_hash);
+            info!("Excluded duration: {}", excluded_duration);
+            info!("Mean: {}", mean);
+            info!("Median: {}", median);
+            info!("Standard Deviation: {}", std_dev);
+            Ok(())
+        })
+}
@@ -0,0 +1,100 @@
+//! An end-to-end example of proving the longest flatline of a time series.
+//!
+//! You can run this script using the following command:
+//! ```shell
+//! RUST_LOG=info cargo run --release --bin flatline -- --execute --stuck 50
+//! ```
+//! or
+//! ```shell
+//! RUST_LOG=info cargo run --release --bin flatline -- --prove --stuck 50
+//! ```
+
+use alloy_sol_types::SolType;
+use clap::Parser;
+use lib_timeseries::FlatlineStruct;
+use sp1_sdk::SP1Stdin;
+use tracing::log::info;
+use zk_timeseries_script::run::RunArgs;
+
+/// The ELF file for the Succinct RISC-V zkVM flatline program.
+pub const FLATLINE_ELF: &[u8] =
+    include_bytes!("../../../../elf/riscv32im-succinct-zkvm-flatline-elf");
+
+/// The arguments for the command.
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None)]
+struct Args {
+    #[clap(flatten)]
+    run: RunArgs,
+
+    #[clap(long, default_value = "200")]
+    n: usize,
+
+    /// The largest change between consecutive values that still counts as flat
+    #[clap(long, default_value = "0")]
+    epsilon: f64,
+
+    /// The number of samples to hold constant in the middle of the sample data
+    #[clap(long, default_value = "0")]
+    stuck: usize,
+}
+
+fn main() {
+    // Setup the logger.
+    sp1_sdk::utils::setup_logger();
+
+    // Parse the command line arguments.
+    let args = Args::parse();
+
+    if let Err(e) = run(&args) {
+        eprintln!("Error: {e}");
+        std::process::exit(1);
+    }
+}
+
+fn run(args: &Args) -> Result<(), String> {
+    args.run.check_mode()?;
+
+    // Setup the inputs.
+    let mut stdin = SP1Stdin::new();
+    // Generate some sample sensor readings, one per minute, with a stuck stretch in the middle
+    let timestamps: Vec<u64> = (0..args.n).map(|i| i as u64 * 60).collect();
+    let stuck_start = args.n.saturating_sub(args.stuck) / 2;
+    let values: Vec<f64> = (0..args.n)
+        .map(|i| {
+            let i = if (stuck_start..stuck_start + args.stuck).contains(&i) {
+                stuck_start
+            } else {
+                i
+            };
+            (i as f64 / 10.0).sin()
+        })
+        .collect();
+
+    stdin.write(&timestamps);
+    stdin.write(&values);
+    stdin.write(&args.epsilon);
+
+    info!("Timestamps: {:?}", timestamps);
+    info!("Values: {:?}", values);
+    info!("Epsilon: {}", args.epsilon);
+
+    args.run.run("flatline", FLATLINE_ELF, stdin, |output| {
+        let FlatlineStruct {
+            values_hash,
+            epsilon,
+            longest_run_count,
+            longest_run_seconds,
+            n,
+        } = FlatlineStruct::abi_decode(output, true)
+            .map_err(|e| format!("failed to decode output: {e:?}"))?;
+
+        info!("Decoded output:");
+        info!("Values hash: {}", values_hash);
+        info!("Epsilon: {}", epsilon);
+        info!("Longest run count: {}", longest_run_count);
+        info!("Longest run seconds: {}", longest_run_seconds);
+        info!("Number of points: {}", n);
+        Ok(())
+    })
+}
@@ -0,0 +1,108 @@
+//! Lists the guest programs, describes the stdin each one reads, and executes any of them with
+//! parameters checked against that description.
+//!
+//! You can run this script using the following commands:
+//! ```shell
+//! cargo run --release --bin programs -- list
+//! cargo run --release --bin programs -- describe-program moving-average
+//! RUST_LOG=info cargo run --release --bin programs -- execute moving-average --param window_size=2
+//! ```
+
+use std::path::PathBuf;
+
+use clap::{CommandFactory, FromArgMatches, Parser, Subcommand};
+use sp1_sdk::ProverClient;
+use tracing::log::info;
+use zk_timeseries_script::registry::ProgramRegistry;
+
+/// The arguments for the command.
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None)]
+struct Args {
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Lists the registered programs.
+    List,
+    /// Prints the stdin schema of a program.
+    DescribeProgram { name: String },
+    /// Executes a program on sample data.
+    Execute {
+        name: String,
+        /// A parameter as `NAME=VALUE`; may be repeated.
+        #[clap(long = "param")]
+        params: Vec<String>,
+        #[clap(long, default_value = "5")]
+        n: u32,
+    },
+}
+
+fn main() {
+    // Setup the logger.
+    sp1_sdk::utils::setup_logger();
+
+    // Parse the command line arguments, listing the programs in the help text.
+    let registry = ProgramRegistry::new();
+    let programs: Vec<&str> = registry.iter().map(|program| program.name).collect();
+    let matches = Args::command()
+        .after_help(format!("Programs: {}", programs.join(", ")))
+        .get_matches();
+    let args = Args::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+
+    match args.command {
+        Command::List => {
+            for program in registry.iter() {
+                println!("{:<20} {}", program.name, program.description);
+            }
+        }
+        Command::DescribeProgram { name } => {
+            let program = registry.get(&name).unwrap_or_else(|| fail(&unknown(&name)));
+            print!("{}", program.help());
+        }
+        Command::Execute { name, params, n } => {
+            let program = registry.get(&name).unwrap_or_else(|| fail(&unknown(&name)));
+            let params: Vec<(&str, &str)> = params
+                .iter()
+                .map(|param| {
+                    param
+                        .split_once('=')
+                        .unwrap_or_else(|| fail(&format!("invalid parameter `{param}`")))
+                })
+                .collect();
+
+            // Generate some sample data
+            let timestamps: Vec<u64> = (0..n).map(|i| i as u64 * 86400).collect();
+            let values: Vec<f64> = (0..n).map(|i| i as f64 * 1.5).collect();
+
+            // Check the parameters before loading the program.
+            let stdin = program
+                .inputs
+                .build_stdin(&timestamps, &values, &params)
+                .unwrap_or_else(|e| fail(&e));
+
+            let elf_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                .join(format!("../../elf/riscv32im-succinct-zkvm-{name}-elf"));
+            let elf = std::fs::read(&elf_path)
+                .unwrap_or_else(|e| fail(&format!("failed to read {}: {e}", elf_path.display())));
+
+            let (output, report) = ProverClient::new()
+                .execute(&elf, stdin)
+                .run()
+                .unwrap_or_else(|e| fail(&format!("execution failed: {e:?}")));
+            info!("Number of cycles: {}", report.total_instruction_count());
+            println!("Public Values: 0x{}", hex::encode(output.as_slice()));
+        }
+    }
+}
+
+fn unknown(name: &str) -> String {
+    format!("unknown program `{name}`; run `list` to see the registered programs")
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("Error: {message}");
+    std::process::exit(1);
+}
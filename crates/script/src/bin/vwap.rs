@@ -0,0 +1,78 @@
+//! An end-to-end example of proving the volume-weighted average price of a price series.
+//!
+//! You can run this script using the following command:
+//! ```shell
+//! RUST_LOG=info cargo run --release --bin vwap -- --execute
+//! ```
+//! or
+//! ```shell
+//! RUST_LOG=info cargo run --release --bin vwap -- --prove
+//! ```
+
+use alloy_sol_types::SolType;
+use clap::Parser;
+use lib_timeseries::VwapPublicValuesStruct;
+use sp1_sdk::SP1Stdin;
+use tracing::log::info;
+use zk_timeseries_script::run::RunArgs;
+
+/// The ELF file for the Succinct RISC-V zkVM VWAP program.
+pub const VWAP_ELF: &[u8] = include_bytes!("../../../../elf/riscv32im-succinct-zkvm-vwap-elf");
+
+/// The arguments for the command.
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None)]
+struct Args {
+    #[clap(flatten)]
+    run: RunArgs,
+
+    #[clap(long, default_value = "20")]
+    n: usize,
+}
+
+fn main() {
+    // Setup the logger.
+    sp1_sdk::utils::setup_logger();
+
+    // Parse the command line arguments.
+    let args = Args::parse();
+
+    if let Err(e) = run(&args) {
+        eprintln!("Error: {e}");
+        std::process::exit(1);
+    }
+}
+
+fn run(args: &Args) -> Result<(), String> {
+    args.run.check_mode()?;
+
+    // Setup the inputs.
+    let mut stdin = SP1Stdin::new();
+    // Generate some sample trades, one per minute, with prices drifting up and volumes cycling
+    let timestamps: Vec<u64> = (0..args.n).map(|i| i as u64 * 60).collect();
+    let prices: Vec<f64> = (0..args.n).map(|i| 100.0 + i as f64 * 0.25).collect();
+    let volumes: Vec<f64> = (0..args.n).map(|i| (10 + (i * 7) % 13) as f64).collect();
+
+    stdin.write(&timestamps);
+    stdin.write(&prices);
+    stdin.write(&volumes);
+
+    info!("Timestamps: {:?}", timestamps);
+    info!("Prices: {:?}", prices);
+    info!("Volumes: {:?}", volumes);
+
+    args.run.run("vwap", VWAP_ELF, stdin, |output| {
+        let VwapPublicValuesStruct {
+            values_hash,
+            volumes_hash,
+            vwap,
+        } = VwapPublicValuesStruct::abi_decode(output, true)
+            .map_err(|e| format!("failed to decode output: {e:?}"))?;
+
+        info!("Decoded output:");
+        info!("Values hash: {}", values_hash);
+        info!("Volumes hash: {}", volumes_hash);
+        info!("VWAP: {}", vwap);
+        Ok(())
+    })
+}
@@ -0,0 +1,95 @@
+//! Caches the proving and verifying keys on disk, so repeated runs can skip `setup`.
+//!
+//! Each program's keys are stored in `<dir>/<name>.keys` together with the SHA-256 hash of the ELF
+//! they were generated from. A cached file whose hash does not match the current ELF is stale: it
+//! is ignored and the keys are generated again.
+
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use sha2::{Digest, Sha256};
+use sp1_sdk::{ProverClient, SP1ProvingKey, SP1VerifyingKey};
+use tracing::log::{info, warn};
+
+/// The `--load-keys` and `--save-keys` options shared by the proving scripts.
+#[derive(clap::Args, Debug, Default)]
+pub struct KeyCacheArgs {
+    /// Load the proving and verifying keys from this directory instead of running setup, if
+    /// they were generated from the current program
+    #[clap(long)]
+    pub load_keys: Option<PathBuf>,
+
+    /// Save the proving and verifying keys to this directory
+    #[clap(long)]
+    pub save_keys: Option<PathBuf>,
+}
+
+impl KeyCacheArgs {
+    /// Returns the keys for `elf`, loading them from `--load-keys` when the cache is current and
+    /// running setup otherwise, then saves them to `--save-keys` if given.
+    ///
+    /// `name` identifies the program within the key directories, e.g. `data-hash`.
+    pub fn setup(
+        &self,
+        client: &ProverClient,
+        name: &str,
+        elf: &[u8],
+    ) -> (SP1ProvingKey, SP1VerifyingKey) {
+        let cached = self.load_keys.as_deref().and_then(|dir| {
+            let keys = load_keys(dir, name, elf);
+            if keys.is_none() {
+                info!(
+                    "No current keys for {name} in {}; running setup",
+                    dir.display()
+                );
+            }
+            keys
+        });
+        let (pk, vk) = cached.unwrap_or_else(|| client.setup(elf));
+        if let Some(dir) = &self.save_keys {
+            if let Err(e) = save_keys(dir, name, elf, &pk, &vk) {
+                warn!("Failed to save keys to {}: {e}", dir.display());
+            }
+        }
+        (pk, vk)
+    }
+}
+
+/// Returns the SHA-256 hash of a program ELF, which identifies the keys generated from it.
+pub fn elf_hash(elf: &[u8]) -> [u8; 32] {
+    Sha256::digest(elf).into()
+}
+
+fn key_path(dir: &Path, name: &str) -> PathBuf {
+    dir.join(format!("{name}.keys"))
+}
+
+/// Writes the keys for `elf` to `<dir>/<name>.keys`, creating `dir` if needed.
+pub fn save_keys(
+    dir: &Path,
+    name: &str,
+    elf: &[u8],
+    pk: &SP1ProvingKey,
+    vk: &SP1VerifyingKey,
+) -> Result<(), String> {
+    fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    let file = File::create(key_path(dir, name)).map_err(|e| e.to_string())?;
+    let mut writer = BufWriter::new(file);
+    bincode::serialize_into(&mut writer, &elf_hash(elf)).map_err(|e| e.to_string())?;
+    bincode::serialize_into(&mut writer, &(pk, vk)).map_err(|e| e.to_string())?;
+    writer.flush().map_err(|e| e.to_string())
+}
+
+/// Reads the keys in `<dir>/<name>.keys`, or `None` if the file is missing, unreadable or was
+/// generated from a different ELF.
+pub fn load_keys(dir: &Path, name: &str, elf: &[u8]) -> Option<(SP1ProvingKey, SP1VerifyingKey)> {
+    let file = File::open(key_path(dir, name)).ok()?;
+    let mut reader = BufReader::new(file);
+    // The hash comes first, so a stale file is rejected without reading the keys.
+    let cached_hash: [u8; 32] = bincode::deserialize_from(&mut reader).ok()?;
+    if cached_hash != elf_hash(elf) {
+        return None;
+    }
+    bincode::deserialize_from(&mut reader).ok()
+}
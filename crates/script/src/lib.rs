@@ -0,0 +1,139 @@
+//! Shared helpers for the host scripts.
+
+use std::path::Path;
+
+use lib_timeseries::{CsvOptions, TimeSeries};
+
+pub mod keys;
+pub mod proof_stats;
+pub mod registry;
+pub mod run;
+
+/// Loads a time series from a file, choosing the format by extension.
+///
+/// `.json` files hold `{"timestamps": [...], "values": [...]}`; anything else is read as a
+/// `timestamp,value` CSV, skipping a header row if the first line does not start with a digit.
+pub fn load_series(path: &Path) -> Result<TimeSeries, String> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+    let series = if path.extension().is_some_and(|ext| ext == "json") {
+        TimeSeries::from_json(&contents)
+    } else {
+        let has_header = contents
+            .lines()
+            .find(|line| !line.trim().is_empty())
+            .is_some_and(|line| !line.trim_start().starts_with(|c: char| c.is_ascii_digit()));
+        let options = CsvOptions {
+            has_header,
+            ..CsvOptions::default()
+        };
+        TimeSeries::from_csv_reader_with_options(contents.as_bytes(), options)
+    };
+    series.map_err(|e| format!("failed to load {}: {e}", path.display()))
+}
+
+/// Parses a comma-separated list of `start-end` time ranges, e.g.
+/// `1700000000-1700003600,1700086400-1700090000`.
+///
+/// Each range is half-open, `[start, end)`, and must have `start < end`.
+pub fn parse_ranges(s: &str) -> Result<Vec<(u64, u64)>, String> {
+    s.split(',')
+        .filter(|range| !range.trim().is_empty())
+        .map(|range| {
+            let (start, end) = range
+                .trim()
+                .split_once('-')
+                .ok_or_else(|| format!("invalid range `{range}`: expected START-END"))?;
+            let start: u64 = start
+                .parse()
+                .map_err(|e| format!("invalid range start `{start}`: {e}"))?;
+            let end: u64 = end
+                .parse()
+                .map_err(|e| format!("invalid range end `{end}`: {e}"))?;
+            if start >= end {
+                return Err(format!("invalid range `{range}`: start must be before end"));
+            }
+            Ok((start, end))
+        })
+        .collect()
+}
+
+/// Parses a JSON array of `[start, end]` time ranges, e.g. `[[1700000000, 1700003600]]`.
+///
+/// Ranges are half-open, `[start, end)`, and must have `start < end`, as in [`parse_ranges`].
+pub fn parse_ranges_json(s: &str) -> Result<Vec<(u64, u64)>, String> {
+    let ranges: Vec<(u64, u64)> =
+        serde_json::from_str(s).map_err(|e| format!("invalid ranges JSON: {e}"))?;
+    if let Some((start, end)) = ranges.iter().find(|(start, end)| start >= end) {
+        return Err(format!(
+            "invalid range `[{start}, {end}]`: start must be before end"
+        ));
+    }
+    Ok(ranges)
+}
+
+/// Formats an execution's cycle count as the JSON line printed by `--json`, `{"cycles":N}`.
+pub fn cycles_json(cycles: u64) -> String {
+    serde_json::json!({ "cycles": cycles }).to_string()
+}
+
+/// Checks an execution's cycle count against the `--max-cycles` budget, if one was given.
+pub fn check_max_cycles(cycles: u64, max_cycles: Option<u64>) -> Result<(), String> {
+    match max_cycles {
+        Some(max_cycles) if cycles > max_cycles => Err(format!(
+            "program used {cycles} cycles, exceeding the limit of {max_cycles}"
+        )),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ranges() {
+        assert_eq!(
+            parse_ranges("1700000000-1700003600, 1700086400-1700090000"),
+            Ok(vec![(1700000000, 1700003600), (1700086400, 1700090000)])
+        );
+        assert_eq!(parse_ranges(""), Ok(vec![]));
+        assert!(parse_ranges("10-5").is_err());
+        assert!(parse_ranges("10").is_err());
+        assert!(parse_ranges("a-5").is_err());
+    }
+
+    #[test]
+    fn test_load_series_fixtures() {
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+        let csv = load_series(&fixtures.join("series.csv")).unwrap();
+        let json = load_series(&fixtures.join("series.json")).unwrap();
+        assert_eq!(
+            csv.timestamps,
+            vec![1700000000, 1700003600, 1700007200, 1700010800]
+        );
+        assert_eq!(csv.values, vec![10.5, 11.0, 9.75, 12.25]);
+        assert_eq!(json.timestamps, csv.timestamps);
+        assert_eq!(json.values, csv.values);
+        assert!(load_series(&fixtures.join("missing.csv")).is_err());
+    }
+
+    #[test]
+    fn test_parse_ranges_json() {
+        assert_eq!(
+            parse_ranges_json("[[10, 20], [30, 40]]"),
+            Ok(vec![(10, 20), (30, 40)])
+        );
+        assert_eq!(parse_ranges_json("[]"), Ok(vec![]));
+        assert!(parse_ranges_json("[[20, 10]]").is_err());
+        assert!(parse_ranges_json("[10, 20]").is_err());
+    }
+
+    #[test]
+    fn test_cycle_budget() {
+        assert_eq!(cycles_json(1234), r#"{"cycles":1234}"#);
+        assert_eq!(check_max_cycles(100, None), Ok(()));
+        assert_eq!(check_max_cycles(100, Some(100)), Ok(()));
+        assert!(check_max_cycles(101, Some(100)).is_err());
+    }
+}
@@ -0,0 +1,80 @@
+//! Timing and size of generated proofs, for comparing the cost of proof systems.
+
+use std::fmt;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use sp1_sdk::SP1ProofWithPublicValues;
+
+/// How long a proof took to generate and how large it is.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProofStats {
+    /// The proof system, such as `core`, `groth16` or `plonk`.
+    pub system: String,
+    /// Wall-clock time spent generating the proof, in milliseconds.
+    pub proving_time_ms: u64,
+    /// The length of the proof in bytes.
+    pub proof_bytes: usize,
+}
+
+impl ProofStats {
+    pub fn new(system: &str, proving_time: Duration, proof_bytes: usize) -> Self {
+        ProofStats {
+            system: system.to_string(),
+            proving_time_ms: u64::try_from(proving_time.as_millis()).unwrap_or(u64::MAX),
+            proof_bytes,
+        }
+    }
+
+    /// Records a core proof, measuring its size as the bincode-serialized proof with public
+    /// values, which is what [`SP1ProofWithPublicValues::save`] writes.
+    pub fn core(proving_time: Duration, proof: &SP1ProofWithPublicValues) -> Self {
+        let proof_bytes = bincode::serialized_size(proof).expect("proof serializes") as usize;
+        ProofStats::new("core", proving_time, proof_bytes)
+    }
+
+    /// Formats the stats as a single line of JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("proof stats serialize")
+    }
+
+    /// Prints the stats, followed by their JSON record when `json` is set.
+    pub fn print(&self, json: bool) {
+        println!("{self}");
+        if json {
+            println!("{}", self.to_json());
+        }
+    }
+}
+
+impl fmt::Display for ProofStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Proof system: {}, proving time: {:.3} s, proof size: {} bytes",
+            self.system,
+            self.proving_time_ms as f64 / 1000.0,
+            self.proof_bytes
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_proof_stats_serialization() {
+        let stats = ProofStats::new("groth16", Duration::from_millis(61_250), 260);
+        let json = stats.to_json();
+        assert_eq!(
+            json,
+            r#"{"system":"groth16","proving_time_ms":61250,"proof_bytes":260}"#
+        );
+        assert_eq!(serde_json::from_str::<ProofStats>(&json).unwrap(), stats);
+        assert_eq!(
+            stats.to_string(),
+            "Proof system: groth16, proving time: 61.250 s, proof size: 260 bytes"
+        );
+    }
+}
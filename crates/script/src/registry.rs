@@ -0,0 +1,818 @@
+//! The guest programs and the stdin schema each one reads.
+//!
+//! Every guest reads its inputs from stdin in a fixed order with fixed types. Recording that
+//! order here lets the host scripts build `SP1Stdin` and their help text from one place, and
+//! reject a bad parameter before spending cycles on an execution that would fail to decode it.
+
+use std::fmt;
+
+use lib_timeseries::{EmaSmoothing, Predicate, Transform};
+use sp1_sdk::SP1Stdin;
+
+use crate::parse_ranges;
+
+/// The type a guest reads for an input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InputType {
+    U64,
+    Usize,
+    F64,
+    U64List,
+    F64List,
+    /// A list of `[start, end)` time ranges, given in the format of [`parse_ranges`].
+    Ranges,
+    /// A [`Transform`], given as JSON.
+    Transform,
+    /// A [`Predicate`], given as JSON.
+    Predicate,
+    /// An [`EmaSmoothing`], given as JSON.
+    EmaSmoothing,
+}
+
+impl InputType {
+    /// Parses a command-line parameter into a value of this type.
+    ///
+    /// The error names the field, so it can be shown to the user as is.
+    pub fn parse(self, field: &str, raw: &str) -> Result<InputValue, String> {
+        let invalid = |e: &dyn fmt::Display| {
+            format!("invalid value `{raw}` for `{field}`: expected {self} ({e})")
+        };
+        let raw = raw.trim();
+        Ok(match self {
+            InputType::U64 => InputValue::U64(raw.parse().map_err(|e| invalid(&e))?),
+            InputType::Usize => InputValue::Usize(raw.parse().map_err(|e| invalid(&e))?),
+            InputType::F64 => InputValue::F64(raw.parse().map_err(|e| invalid(&e))?),
+            InputType::U64List => InputValue::U64List(
+                split_list(raw)
+                    .map(str::parse)
+                    .collect::<Result<_, _>>()
+                    .map_err(|e| invalid(&e))?,
+            ),
+            InputType::F64List => InputValue::F64List(
+                split_list(raw)
+                    .map(str::parse)
+                    .collect::<Result<_, _>>()
+                    .map_err(|e| invalid(&e))?,
+            ),
+            InputType::Ranges => InputValue::Ranges(parse_ranges(raw).map_err(|e| invalid(&e))?),
+            InputType::Transform => {
+                InputValue::Transform(serde_json::from_str(raw).map_err(|e| invalid(&e))?)
+            }
+            InputType::Predicate => {
+                InputValue::Predicate(serde_json::from_str(raw).map_err(|e| invalid(&e))?)
+            }
+            InputType::EmaSmoothing => {
+                InputValue::EmaSmoothing(serde_json::from_str(raw).map_err(|e| invalid(&e))?)
+            }
+        })
+    }
+}
+
+impl fmt::Display for InputType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            InputType::U64 => "an unsigned 64-bit integer",
+            InputType::Usize => "an unsigned integer",
+            InputType::F64 => "a number",
+            InputType::U64List => "a comma-separated list of unsigned integers",
+            InputType::F64List => "a comma-separated list of numbers",
+            InputType::Ranges => "a comma-separated list of START-END ranges",
+            InputType::Transform => "a JSON transform",
+            InputType::Predicate => "a JSON predicate",
+            InputType::EmaSmoothing => "a JSON EMA smoothing",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Splits a comma-separated list, ignoring empty entries.
+fn split_list(raw: &str) -> impl Iterator<Item = &str> {
+    raw.split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+}
+
+/// A typed input, ready to be written to stdin.
+#[derive(Clone, Debug, PartialEq)]
+pub enum InputValue {
+    U64(u64),
+    Usize(usize),
+    F64(f64),
+    U64List(Vec<u64>),
+    F64List(Vec<f64>),
+    Ranges(Vec<(u64, u64)>),
+    Transform(Transform),
+    Predicate(Predicate),
+    EmaSmoothing(EmaSmoothing),
+}
+
+impl InputValue {
+    /// Returns the type of this value.
+    pub fn input_type(&self) -> InputType {
+        match self {
+            InputValue::U64(_) => InputType::U64,
+            InputValue::Usize(_) => InputType::Usize,
+            InputValue::F64(_) => InputType::F64,
+            InputValue::U64List(_) => InputType::U64List,
+            InputValue::F64List(_) => InputType::F64List,
+            InputValue::Ranges(_) => InputType::Ranges,
+            InputValue::Transform(_) => InputType::Transform,
+            InputValue::Predicate(_) => InputType::Predicate,
+            InputValue::EmaSmoothing(_) => InputType::EmaSmoothing,
+        }
+    }
+
+    /// Writes the value to stdin with the exact type the guest reads.
+    pub fn write_to(&self, stdin: &mut SP1Stdin) {
+        match self {
+            InputValue::U64(v) => stdin.write(v),
+            InputValue::Usize(v) => stdin.write(v),
+            InputValue::F64(v) => stdin.write(v),
+            InputValue::U64List(v) => stdin.write(v),
+            InputValue::F64List(v) => stdin.write(v),
+            InputValue::Ranges(v) => stdin.write(v),
+            InputValue::Transform(v) => stdin.write(v),
+            InputValue::Predicate(v) => stdin.write(v),
+            InputValue::EmaSmoothing(v) => stdin.write(v),
+        }
+    }
+}
+
+/// Where the host takes an input from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    /// The series being proven: the timestamps or the values.
+    Data,
+    /// A parameter supplied by the user.
+    Parameter,
+}
+
+/// One item a guest reads from stdin.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InputField {
+    pub name: &'static str,
+    pub ty: InputType,
+    pub source: InputSource,
+    pub help: &'static str,
+    /// The value used when the user does not supply the parameter.
+    pub default: Option<&'static str>,
+}
+
+impl InputField {
+    const fn data(name: &'static str, ty: InputType, help: &'static str) -> Self {
+        InputField {
+            name,
+            ty,
+            source: InputSource::Data,
+            help,
+            default: None,
+        }
+    }
+
+    const fn parameter(
+        name: &'static str,
+        ty: InputType,
+        help: &'static str,
+        default: Option<&'static str>,
+    ) -> Self {
+        InputField {
+            name,
+            ty,
+            source: InputSource::Parameter,
+            help,
+            default,
+        }
+    }
+}
+
+const TIMESTAMPS: InputField = InputField::data("timestamps", InputType::U64List, "Timestamps");
+const VALUES: InputField = InputField::data("values", InputType::F64List, "Values");
+
+/// The stdin schema of a guest program, in the order the guest reads it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InputSpec {
+    pub fields: &'static [InputField],
+}
+
+impl InputSpec {
+    /// Returns the fields supplied as parameters.
+    pub fn parameters(&self) -> impl Iterator<Item = &InputField> {
+        self.fields
+            .iter()
+            .filter(|field| field.source == InputSource::Parameter)
+    }
+
+    /// Builds the typed inputs for a series and a set of `(name, value)` parameters.
+    ///
+    /// Parameters the user leaves out take their default, and every parameter is parsed against
+    /// its declared type, so a bad value is reported by field name before anything runs.
+    pub fn build_inputs(
+        &self,
+        timestamps: &[u64],
+        values: &[f64],
+        parameters: &[(&str, &str)],
+    ) -> Result<Vec<InputValue>, String> {
+        if let Some((name, _)) = parameters
+            .iter()
+            .find(|(name, _)| !self.parameters().any(|field| field.name == *name))
+        {
+            return Err(format!("unknown parameter `{name}`"));
+        }
+        self.fields
+            .iter()
+            .map(|field| match field.source {
+                InputSource::Data if field.ty == InputType::U64List => {
+                    Ok(InputValue::U64List(timestamps.to_vec()))
+                }
+                InputSource::Data => Ok(InputValue::F64List(values.to_vec())),
+                InputSource::Parameter => {
+                    let raw = parameters
+                        .iter()
+                        .find(|(name, _)| *name == field.name)
+                        .map(|(_, raw)| *raw)
+                        .or(field.default)
+                        .ok_or_else(|| format!("missing parameter `{}`", field.name))?;
+                    field.ty.parse(field.name, raw)
+                }
+            })
+            .collect()
+    }
+
+    /// Builds the stdin for a series and a set of parameters; see [`InputSpec::build_inputs`].
+    pub fn build_stdin(
+        &self,
+        timestamps: &[u64],
+        values: &[f64],
+        parameters: &[(&str, &str)],
+    ) -> Result<SP1Stdin, String> {
+        let mut stdin = SP1Stdin::new();
+        for input in self.build_inputs(timestamps, values, parameters)? {
+            input.write_to(&mut stdin);
+        }
+        Ok(stdin)
+    }
+}
+
+/// A guest program and its stdin schema.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ProgramSpec {
+    /// The guest crate's directory name, which is also its ELF name.
+    pub name: &'static str,
+    pub description: &'static str,
+    pub inputs: InputSpec,
+}
+
+impl ProgramSpec {
+    /// Returns the help text describing the program's stdin schema.
+    pub fn help(&self) -> String {
+        let mut help = format!("{}: {}\n\nStdin, in order:\n", self.name, self.description);
+        for field in self.inputs.fields {
+            let source = match field.source {
+                InputSource::Data => "data",
+                InputSource::Parameter => "parameter",
+            };
+            help.push_str(&format!(
+                "  {:<14} {:<9} {} ({})",
+                field.name, source, field.help, field.ty
+            ));
+            if let Some(default) = field.default {
+                help.push_str(&format!(" [default: {default}]"));
+            }
+            help.push('\n');
+        }
+        help
+    }
+}
+
+/// Every guest program in the workspace.
+const PROGRAMS: &[ProgramSpec] = &[
+    ProgramSpec {
+        name: "data-hash",
+        description: "Summary statistics and the values hash",
+        inputs: InputSpec {
+            fields: &[TIMESTAMPS, VALUES],
+        },
+    },
+    ProgramSpec {
+        name: "moving-average",
+        description: "Trailing moving average",
+        inputs: InputSpec {
+            fields: &[
+                TIMESTAMPS,
+                VALUES,
+                InputField::parameter("window_size", InputType::Usize, "Window size", Some("3")),
+            ],
+        },
+    },
+    ProgramSpec {
+        name: "transform-link",
+        description: "Checks that output values are a transform of the raw values",
+        inputs: InputSpec {
+            fields: &[
+                TIMESTAMPS,
+                VALUES,
+                InputField::parameter("transform", InputType::Transform, "Transform applied", None),
+                InputField::parameter(
+                    "output_values",
+                    InputType::F64List,
+                    "Transformed values",
+                    None,
+                ),
+                InputField::parameter(
+                    "tolerance",
+                    InputType::F64,
+                    "Allowed deviation",
+                    Some("0.000001"),
+                ),
+            ],
+        },
+    },
+    ProgramSpec {
+        name: "rolling-compliance",
+        description: "Rolling-window compliance bitmap",
+        inputs: InputSpec {
+            fields: &[
+                TIMESTAMPS,
+                VALUES,
+                InputField::parameter("window_size", InputType::Usize, "Window size", Some("3")),
+                InputField::parameter("predicate", InputType::Predicate, "Window condition", None),
+            ],
+        },
+    },
+    ProgramSpec {
+        name: "acf",
+        description: "Autocorrelation function with its significance bound",
+        inputs: InputSpec {
+            fields: &[
+                TIMESTAMPS,
+                VALUES,
+                InputField::parameter("max_lag", InputType::Usize, "Largest lag", Some("5")),
+                InputField::parameter(
+                    "confidence",
+                    InputType::F64,
+                    "Confidence level",
+                    Some("0.95"),
+                ),
+            ],
+        },
+    },
+    ProgramSpec {
+        name: "dual-hash",
+        description: "Statistics with Keccak256 and SHA-256 hashes",
+        inputs: InputSpec {
+            fields: &[TIMESTAMPS, VALUES],
+        },
+    },
+    ProgramSpec {
+        name: "weighted-average",
+        description: "Weighted mean",
+        inputs: InputSpec {
+            fields: &[
+                TIMESTAMPS,
+                VALUES,
+                InputField::parameter("weights", InputType::F64List, "One weight per point", None),
+            ],
+        },
+    },
+    ProgramSpec {
+        name: "piecewise-linear",
+        description: "Piecewise linear approximation",
+        inputs: InputSpec {
+            fields: &[
+                TIMESTAMPS,
+                VALUES,
+                InputField::parameter(
+                    "max_error",
+                    InputType::F64,
+                    "Largest allowed deviation",
+                    Some("0.1"),
+                ),
+            ],
+        },
+    },
+    ProgramSpec {
+        name: "holt-winters",
+        description: "Additive Holt-Winters forecast",
+        inputs: InputSpec {
+            fields: &[
+                TIMESTAMPS,
+                VALUES,
+                InputField::parameter("alpha", InputType::F64, "Level smoothing", Some("0.5")),
+                InputField::parameter("beta", InputType::F64, "Trend smoothing", Some("0.1")),
+                InputField::parameter("gamma", InputType::F64, "Seasonal smoothing", Some("0.1")),
+                InputField::parameter("season_length", InputType::Usize, "Season length", None),
+                InputField::parameter("horizon", InputType::Usize, "Forecast horizon", Some("1")),
+            ],
+        },
+    },
+    ProgramSpec {
+        name: "exclusion-stats",
+        description: "Statistics excluding time ranges",
+        inputs: InputSpec {
+            fields: &[
+                TIMESTAMPS,
+                VALUES,
+                InputField::parameter("ranges", InputType::Ranges, "Excluded ranges", Some("")),
+            ],
+        },
+    },
+    ProgramSpec {
+        name: "order-stats",
+        description: "Minimum, maximum and median",
+        inputs: InputSpec {
+            fields: &[TIMESTAMPS, VALUES],
+        },
+    },
+    ProgramSpec {
+        name: "flatline",
+        description: "Longest flatline",
+        inputs: InputSpec {
+            fields: &[
+                TIMESTAMPS,
+                VALUES,
+                InputField::parameter(
+                    "epsilon",
+                    InputType::F64,
+                    "Largest change within a flatline",
+                    Some("0"),
+                ),
+            ],
+        },
+    },
+    ProgramSpec {
+        name: "welch-t",
+        description: "Welch's t-statistic between two halves of the series",
+        inputs: InputSpec {
+            fields: &[
+                TIMESTAMPS,
+                VALUES,
+                InputField::parameter(
+                    "split_index",
+                    InputType::Usize,
+                    "First index of the second half",
+                    None,
+                ),
+            ],
+        },
+    },
+    ProgramSpec {
+        name: "ema",
+        description: "Exponential moving average",
+        inputs: InputSpec {
+            fields: &[
+                TIMESTAMPS,
+                VALUES,
+                InputField::parameter(
+                    "smoothing",
+                    InputType::EmaSmoothing,
+                    "Smoothing factor",
+                    None,
+                ),
+            ],
+        },
+    },
+    ProgramSpec {
+        name: "forecast-interval",
+        description: "Exponential smoothing forecast with a prediction interval",
+        inputs: InputSpec {
+            fields: &[
+                TIMESTAMPS,
+                VALUES,
+                InputField::parameter("alpha", InputType::F64, "Smoothing factor", Some("0.5")),
+                InputField::parameter("horizon", InputType::Usize, "Forecast horizon", Some("1")),
+                InputField::parameter(
+                    "z",
+                    InputType::F64,
+                    "Interval width in standard errors",
+                    Some("1.96"),
+                ),
+            ],
+        },
+    },
+    ProgramSpec {
+        name: "non-negativity",
+        description: "Whether every value is non-negative",
+        inputs: InputSpec {
+            fields: &[TIMESTAMPS, VALUES],
+        },
+    },
+    ProgramSpec {
+        name: "extended-stats",
+        description: "Statistics with the annualized growth rate",
+        inputs: InputSpec {
+            fields: &[TIMESTAMPS, VALUES],
+        },
+    },
+    ProgramSpec {
+        name: "range-count",
+        description: "Number of points within a time range",
+        inputs: InputSpec {
+            fields: &[
+                TIMESTAMPS,
+                VALUES,
+                InputField::parameter(
+                    "range_start",
+                    InputType::U64,
+                    "First timestamp counted",
+                    None,
+                ),
+                InputField::parameter("range_end", InputType::U64, "Last timestamp counted", None),
+            ],
+        },
+    },
+    ProgramSpec {
+        name: "exponential-growth",
+        description: "Exponential growth rate of a log-linear fit",
+        inputs: InputSpec {
+            fields: &[TIMESTAMPS, VALUES],
+        },
+    },
+    ProgramSpec {
+        name: "disjoint-claim",
+        description: "Seconds of the series not covered by claimed ranges",
+        inputs: InputSpec {
+            fields: &[
+                TIMESTAMPS,
+                VALUES,
+                InputField::parameter("claimed", InputType::Ranges, "Claimed ranges", Some("")),
+            ],
+        },
+    },
+    ProgramSpec {
+        name: "anomaly",
+        description: "Number and first timestamp of points beyond a z-score threshold",
+        inputs: InputSpec {
+            fields: &[
+                TIMESTAMPS,
+                VALUES,
+                InputField::parameter(
+                    "z_threshold",
+                    InputType::F64,
+                    "Absolute z-score a point must exceed",
+                    Some("3"),
+                ),
+            ],
+        },
+    },
+    ProgramSpec {
+        name: "band-breach",
+        description: "Number of points above their upper Bollinger band",
+        inputs: InputSpec {
+            fields: &[
+                TIMESTAMPS,
+                VALUES,
+                InputField::parameter("window_size", InputType::Usize, "Window size", Some("20")),
+                InputField::parameter(
+                    "k",
+                    InputType::F64,
+                    "Standard deviations between the mean and the band",
+                    Some("2"),
+                ),
+            ],
+        },
+    },
+    ProgramSpec {
+        name: "vwap",
+        description: "Volume-weighted average price",
+        inputs: InputSpec {
+            fields: &[
+                TIMESTAMPS,
+                VALUES,
+                InputField::parameter("volumes", InputType::F64List, "One volume per point", None),
+            ],
+        },
+    },
+    ProgramSpec {
+        name: "histogram",
+        description: "Value counts in fixed histogram bins",
+        inputs: InputSpec {
+            fields: &[
+                TIMESTAMPS,
+                VALUES,
+                InputField::parameter(
+                    "edges",
+                    InputType::F64List,
+                    "Strictly increasing bin edges",
+                    None,
+                ),
+            ],
+        },
+    },
+    ProgramSpec {
+        name: "cusum",
+        description: "Number of CUSUM change points",
+        inputs: InputSpec {
+            fields: &[
+                TIMESTAMPS,
+                VALUES,
+                InputField::parameter("target", InputType::F64, "Expected level", None),
+                InputField::parameter(
+                    "slack",
+                    InputType::F64,
+                    "Tolerated deviation per point",
+                    Some("0.5"),
+                ),
+                InputField::parameter(
+                    "threshold",
+                    InputType::F64,
+                    "Sum that signals a change",
+                    Some("5.0"),
+                ),
+            ],
+        },
+    },
+    ProgramSpec {
+        name: "correlation",
+        description: "Whether the Pearson correlation with a second series exceeds a threshold",
+        inputs: InputSpec {
+            fields: &[
+                TIMESTAMPS,
+                VALUES,
+                InputField::parameter(
+                    "other_timestamps",
+                    InputType::U64List,
+                    "Timestamps of the second series",
+                    None,
+                ),
+                InputField::parameter(
+                    "other_values",
+                    InputType::F64List,
+                    "Values of the second series",
+                    None,
+                ),
+                InputField::parameter(
+                    "threshold",
+                    InputType::F64,
+                    "Correlation to exceed",
+                    Some("0.9"),
+                ),
+            ],
+        },
+    },
+    ProgramSpec {
+        name: "kalman",
+        description: "Hash of the Kalman-smoothed series",
+        inputs: InputSpec {
+            fields: &[
+                TIMESTAMPS,
+                VALUES,
+                InputField::parameter(
+                    "process_var",
+                    InputType::F64,
+                    "Variance of the level per step",
+                    Some("1.0"),
+                ),
+                InputField::parameter(
+                    "measurement_var",
+                    InputType::F64,
+                    "Variance of the measurement noise",
+                    Some("1.0"),
+                ),
+            ],
+        },
+    },
+];
+
+/// The guest programs the host scripts know how to drive.
+#[derive(Clone, Debug)]
+pub struct ProgramRegistry {
+    programs: Vec<ProgramSpec>,
+}
+
+impl Default for ProgramRegistry {
+    fn default() -> Self {
+        ProgramRegistry::new()
+    }
+}
+
+impl ProgramRegistry {
+    /// Creates a registry holding every guest program in the workspace.
+    pub fn new() -> Self {
+        ProgramRegistry {
+            programs: PROGRAMS.to_vec(),
+        }
+    }
+
+    /// Looks up a program by name.
+    pub fn get(&self, name: &str) -> Option<&ProgramSpec> {
+        self.programs.iter().find(|program| program.name == name)
+    }
+
+    /// Iterates over the registered programs.
+    pub fn iter(&self) -> impl Iterator<Item = &ProgramSpec> {
+        self.programs.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns a valid value for every parameter of a program.
+    fn sample_parameters(spec: &ProgramSpec) -> Vec<(&'static str, &'static str)> {
+        spec.inputs
+            .parameters()
+            .map(|field| {
+                let raw = match field.ty {
+                    InputType::U64 | InputType::Usize => "2",
+                    InputType::F64 => "0.5",
+                    InputType::U64List => "1,2,3",
+                    InputType::F64List => "1.0,2.0,3.0",
+                    InputType::Ranges => "10-20",
+                    InputType::Transform => r#"{"MovingAverage":{"window_size":2}}"#,
+                    InputType::Predicate => r#"{"MeanBelow":5.0}"#,
+                    InputType::EmaSmoothing => r#"{"Alpha":0.5}"#,
+                };
+                (field.name, raw)
+            })
+            .collect()
+    }
+
+    /// Decodes one stdin item the way a guest reading `ty` would.
+    fn read_back(ty: InputType, bytes: &[u8]) -> InputValue {
+        fn read<T: serde::de::DeserializeOwned>(bytes: &[u8]) -> T {
+            bincode::deserialize(bytes).expect("stdin item decodes")
+        }
+        match ty {
+            InputType::U64 => InputValue::U64(read(bytes)),
+            InputType::Usize => InputValue::Usize(read(bytes)),
+            InputType::F64 => InputValue::F64(read(bytes)),
+            InputType::U64List => InputValue::U64List(read(bytes)),
+            InputType::F64List => InputValue::F64List(read(bytes)),
+            InputType::Ranges => InputValue::Ranges(read(bytes)),
+            InputType::Transform => InputValue::Transform(read(bytes)),
+            InputType::Predicate => InputValue::Predicate(read(bytes)),
+            InputType::EmaSmoothing => InputValue::EmaSmoothing(read(bytes)),
+        }
+    }
+
+    #[test]
+    fn test_every_program_builds_its_stdin() {
+        let registry = ProgramRegistry::new();
+        let timestamps = [0, 86400, 172800];
+        let values = [1.0, 2.0, 3.0];
+        for spec in registry.iter() {
+            let parameters = sample_parameters(spec);
+            let inputs = spec
+                .inputs
+                .build_inputs(&timestamps, &values, &parameters)
+                .unwrap_or_else(|e| panic!("{}: {e}", spec.name));
+            let types: Vec<InputType> = inputs.iter().map(InputValue::input_type).collect();
+            let expected: Vec<InputType> = spec.inputs.fields.iter().map(|f| f.ty).collect();
+            assert_eq!(types, expected, "{}", spec.name);
+            assert_eq!(inputs[0], InputValue::U64List(timestamps.to_vec()));
+            assert_eq!(inputs[1], InputValue::F64List(values.to_vec()));
+
+            let stdin = spec
+                .inputs
+                .build_stdin(&timestamps, &values, &parameters)
+                .unwrap_or_else(|e| panic!("{}: {e}", spec.name));
+            let read: Vec<InputValue> = spec
+                .inputs
+                .fields
+                .iter()
+                .zip(&stdin.buffer)
+                .map(|(field, bytes)| read_back(field.ty, bytes))
+                .collect();
+            assert_eq!(stdin.buffer.len(), inputs.len(), "{}", spec.name);
+            assert_eq!(read, inputs, "{}", spec.name);
+        }
+    }
+
+    #[test]
+    fn test_parameters_are_validated() {
+        let registry = ProgramRegistry::new();
+        let spec = registry.get("moving-average").expect("registered");
+
+        let inputs = spec.inputs.build_inputs(&[1], &[1.0], &[]).unwrap();
+        assert_eq!(inputs[2], InputValue::Usize(3));
+
+        let error = spec
+            .inputs
+            .build_inputs(&[1], &[1.0], &[("window_size", "1.5")])
+            .unwrap_err();
+        assert!(error.contains("`window_size`"), "{error}");
+
+        let error = spec
+            .inputs
+            .build_inputs(&[1], &[1.0], &[("window", "3")])
+            .unwrap_err();
+        assert_eq!(error, "unknown parameter `window`");
+
+        let spec = registry.get("range-count").expect("registered");
+        let error = spec.inputs.build_inputs(&[1], &[1.0], &[]).unwrap_err();
+        assert_eq!(error, "missing parameter `range_start`");
+    }
+
+    #[test]
+    fn test_help_lists_every_field() {
+        let registry = ProgramRegistry::new();
+        for spec in registry.iter() {
+            let help = spec.help();
+            for field in spec.inputs.fields {
+                assert!(help.contains(field.name), "{}: {}", spec.name, field.name);
+            }
+        }
+        assert!(registry.get("missing").is_none());
+    }
+}
@@ -0,0 +1,93 @@
+//! The execute-or-prove flow shared by the example scripts.
+
+use std::time::Instant;
+
+use sp1_sdk::{ProverClient, SP1Stdin};
+use tracing::log::info;
+
+use crate::keys::KeyCacheArgs;
+use crate::proof_stats::ProofStats;
+
+/// The `--execute`, `--prove` and `--json` options shared by the example scripts, along with the
+/// key cache options.
+#[derive(clap::Args, Debug, Default)]
+pub struct RunArgs {
+    #[clap(long)]
+    pub execute: bool,
+
+    #[clap(long)]
+    pub prove: bool,
+
+    /// Print the proof stats as JSON after proving
+    #[clap(long)]
+    pub json: bool,
+
+    #[clap(flatten)]
+    pub keys: KeyCacheArgs,
+}
+
+impl RunArgs {
+    /// Checks that exactly one of `--execute` and `--prove` was given.
+    pub fn check_mode(&self) -> Result<(), String> {
+        if self.execute == self.prove {
+            return Err("You must specify either --execute or --prove".to_string());
+        }
+        Ok(())
+    }
+
+    /// Executes `elf` on `stdin`, or with `--prove` generates a core proof, prints its stats and
+    /// verifies it. Either way the committed public values are then passed to `decode`, which
+    /// can reject them.
+    ///
+    /// `name` identifies the program in the key cache, e.g. `disjoint-claim`.
+    pub fn run(
+        &self,
+        name: &str,
+        elf: &[u8],
+        stdin: SP1Stdin,
+        decode: impl FnOnce(&[u8]) -> Result<(), String>,
+    ) -> Result<(), String> {
+        // Setup the prover client.
+        let client = ProverClient::new();
+
+        if self.execute {
+            // Execute the program
+            info!("Executing the program...");
+            let (output, report) = client
+                .execute(elf, stdin)
+                .run()
+                .map_err(|e| format!("execution failed: {e:?}"))?;
+            info!("Program executed successfully.");
+
+            // Read the output.
+            decode(output.as_slice())?;
+
+            // Record the number of cycles executed.
+            info!("Number of cycles: {}", report.total_instruction_count());
+        } else {
+            // Setup the program for proving.
+            let (pk, vk) = self.keys.setup(&client, name, elf);
+
+            // Generate the proof, timing it
+            let started = Instant::now();
+            let proof = client
+                .prove(&pk, stdin)
+                .run()
+                .map_err(|e| format!("failed to generate proof: {e:?}"))?;
+            let stats = ProofStats::core(started.elapsed(), &proof);
+
+            println!("Successfully generated proof!");
+            stats.print(self.json);
+
+            // Verify the proof.
+            client
+                .verify(&proof, &vk)
+                .map_err(|e| format!("failed to verify proof: {e:?}"))?;
+            println!("Successfully verified proof!");
+
+            // Read the output the proof commits to.
+            decode(proof.public_values.as_slice())?;
+        }
+        Ok(())
+    }
+}
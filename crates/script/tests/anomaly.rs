@@ -0,0 +1,40 @@
+//! Executes the anomaly program and checks its committed output.
+
+use alloy_sol_types::SolType;
+use lib_timeseries::AnomalyPublicValuesStruct;
+use sp1_sdk::{ProverClient, SP1Stdin};
+
+const ANOMALY_ELF: &[u8] = include_bytes!("../../../elf/riscv32im-succinct-zkvm-anomaly-elf");
+
+#[test]
+fn test_two_outliers_are_counted() {
+    // 100 readings on a gentle wave, one per minute, with two obvious spikes.
+    let timestamps: Vec<u64> = (0..100).map(|i| i * 60).collect();
+    let mut values: Vec<f64> = (0..100).map(|i| (i as f64 / 10.0).sin()).collect();
+    values[30] = 40.0;
+    values[70] = -40.0;
+
+    let mut stdin = SP1Stdin::new();
+    stdin.write(&timestamps);
+    stdin.write(&values);
+    stdin.write(&3.0f64);
+    let (output, _) = ProverClient::new()
+        .execute(ANOMALY_ELF, stdin)
+        .run()
+        .expect("execution failed");
+    let output =
+        AnomalyPublicValuesStruct::abi_decode(output.as_slice(), true).expect("invalid output");
+
+    assert_eq!(
+        output.anomaly_count,
+        alloy_sol_types::private::Uint::<256, 4>::from(2u64)
+    );
+    assert_eq!(
+        output.first_anomaly_timestamp,
+        alloy_sol_types::private::Uint::<256, 4>::from(30u64 * 60)
+    );
+    assert_eq!(
+        output.end_timestamp,
+        alloy_sol_types::private::Uint::<256, 4>::from(99u64 * 60)
+    );
+}
@@ -0,0 +1,41 @@
+//! Runs the scripts with `--json` and `--max-cycles`, as CI does to track proving cost.
+
+use std::process::{Command, Output};
+
+fn execute(bin: &str, extra_args: &[&str]) -> Output {
+    Command::new(bin)
+        .arg("--execute")
+        .args(extra_args)
+        .output()
+        .expect("failed to run the script")
+}
+
+/// Finds the `{"cycles": N}` line among the script's output.
+fn parse_cycles(output: &Output) -> u64 {
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(line.trim()).ok())
+        .find_map(|value| value.get("cycles").and_then(serde_json::Value::as_u64))
+        .expect("no cycle count in the output")
+}
+
+#[test]
+fn test_json_reports_cycles() {
+    for bin in [env!("CARGO_BIN_EXE_hash"), env!("CARGO_BIN_EXE_ma")] {
+        let output = execute(bin, &["--json"]);
+        assert!(output.status.success(), "{bin}");
+        assert!(parse_cycles(&output) > 0, "{bin}");
+    }
+}
+
+#[test]
+fn test_max_cycles_fails_when_exceeded() {
+    let bin = env!("CARGO_BIN_EXE_hash");
+    let cycles = parse_cycles(&execute(bin, &["--json"]));
+
+    let within = execute(bin, &["--max-cycles", &cycles.to_string()]);
+    assert!(within.status.success());
+
+    let exceeded = execute(bin, &["--max-cycles", &(cycles - 1).to_string()]);
+    assert!(!exceeded.status.success());
+}
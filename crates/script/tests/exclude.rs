@@ -0,0 +1,54 @@
+//! Executes the exclusion statistics program and checks its committed output.
+
+use alloy_sol_types::SolType;
+use lib_timeseries::{f64_to_u256, ExclusionPublicValuesStruct, TimeSeries};
+use sp1_sdk::{ProverClient, SP1Stdin};
+
+const EXCLUSION_STATS_ELF: &[u8] =
+    include_bytes!("../../../elf/riscv32im-succinct-zkvm-exclusion-stats-elf");
+
+fn execute(
+    timestamps: &[u64],
+    values: &[f64],
+    ranges: &[(u64, u64)],
+) -> ExclusionPublicValuesStruct {
+    let mut stdin = SP1Stdin::new();
+    stdin.write(&timestamps.to_vec());
+    stdin.write(&values.to_vec());
+    stdin.write(&ranges.to_vec());
+    let (output, _) = ProverClient::new()
+        .execute(EXCLUSION_STATS_ELF, stdin)
+        .run()
+        .expect("execution failed");
+    ExclusionPublicValuesStruct::abi_decode(output.as_slice(), true).expect("invalid output")
+}
+
+#[test]
+fn test_excluded_points_do_not_affect_mean() {
+    let timestamps = [1700000000, 1700001800, 1700003600, 1700005400];
+    let values = [2.0, 1000.0, 4.0, 6.0];
+    let output = execute(&timestamps, &values, &[(1700001000, 1700002000)]);
+    assert_eq!(output.mean, f64_to_u256(4.0));
+
+    let full = TimeSeries::new(timestamps.to_vec(), values.to_vec());
+    assert_eq!(
+        output.values_hash,
+        full.to_public_values().expect("finite values").values_hash
+    );
+}
+
+#[test]
+fn test_excluded_duration_is_exact() {
+    let timestamps = [0, 3600, 7200];
+    let values = [1.0, 2.0, 3.0];
+    // Out of order and overlapping: the union is [100, 500) and [1000, 1100).
+    let output = execute(
+        &timestamps,
+        &values,
+        &[(1000, 1100), (300, 500), (100, 400)],
+    );
+    assert_eq!(
+        output.excluded_duration,
+        alloy_sol_types::private::Uint::<256, 4>::from(500u64)
+    );
+}
@@ -0,0 +1,42 @@
+//! Executes the flatline program and checks its committed output.
+
+use alloy_sol_types::SolType;
+use lib_timeseries::FlatlineStruct;
+use sp1_sdk::{ProverClient, SP1Stdin};
+
+const FLATLINE_ELF: &[u8] = include_bytes!("../../../elf/riscv32im-succinct-zkvm-flatline-elf");
+
+#[test]
+fn test_planted_flatline_is_detected() {
+    // 300 varying samples, one per minute, with 50 stuck samples planted at index 100.
+    let timestamps: Vec<u64> = (0..300).map(|i| i * 60).collect();
+    let values: Vec<f64> = (0..300)
+        .map(|i| {
+            let i = if (100..150).contains(&i) { 100 } else { i };
+            (i as f64 / 10.0).sin()
+        })
+        .collect();
+
+    let mut stdin = SP1Stdin::new();
+    stdin.write(&timestamps);
+    stdin.write(&values);
+    stdin.write(&0.0f64);
+    let (output, _) = ProverClient::new()
+        .execute(FLATLINE_ELF, stdin)
+        .run()
+        .expect("execution failed");
+    let output = FlatlineStruct::abi_decode(output.as_slice(), true).expect("invalid output");
+
+    assert_eq!(
+        output.longest_run_count,
+        alloy_sol_types::private::Uint::<256, 4>::from(50u64)
+    );
+    assert_eq!(
+        output.longest_run_seconds,
+        alloy_sol_types::private::Uint::<256, 4>::from(49u64 * 60)
+    );
+    assert_eq!(
+        output.n,
+        alloy_sol_types::private::Uint::<256, 4>::from(300u64)
+    );
+}
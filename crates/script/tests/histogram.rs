@@ -0,0 +1,59 @@
+//! Executes the histogram program and checks its committed output.
+
+use alloy_sol_types::SolType;
+use lib_timeseries::{HistogramPublicValuesStruct, TimeSeries};
+use sp1_sdk::ProverClient;
+use zk_timeseries_script::registry::ProgramRegistry;
+
+const HISTOGRAM_ELF: &[u8] = include_bytes!("../../../elf/riscv32im-succinct-zkvm-histogram-elf");
+
+#[test]
+fn test_histogram_matches_host() {
+    // -3.0 falls below the first edge and 12.0 above the last.
+    let timestamps: Vec<u64> = (0..6).map(|i| i * 60).collect();
+    let values = vec![-3.0, 1.0, 5.0, 7.5, 10.0, 12.0];
+    let edges = [0.0, 5.0, 10.0];
+
+    let stdin = ProgramRegistry::new()
+        .get("histogram")
+        .expect("histogram is registered")
+        .inputs
+        .build_stdin(&timestamps, &values, &[("edges", "0,5,10")])
+        .expect("valid inputs");
+    let (output, _) = ProverClient::new()
+        .execute(HISTOGRAM_ELF, stdin)
+        .run()
+        .expect("execution failed");
+    let output =
+        HistogramPublicValuesStruct::abi_decode(output.as_slice(), true).expect("invalid output");
+
+    let expected = TimeSeries::new(timestamps, values)
+        .to_histogram_public_values(&edges)
+        .expect("valid inputs");
+    assert_eq!(output.values_hash, expected.values_hash);
+    assert_eq!(output.bin_edges, expected.bin_edges);
+    assert_eq!(output.counts, expected.counts);
+    assert_eq!(output.total, expected.total);
+    assert_eq!(output.underflow, expected.underflow);
+    assert_eq!(output.overflow, expected.overflow);
+
+    let count = |n: u64| alloy_sol_types::private::Uint::<256, 4>::from(n);
+    assert_eq!(output.counts, vec![count(1), count(3)]);
+    assert_eq!(output.total, count(6));
+    assert_eq!(output.underflow, count(1));
+    assert_eq!(output.overflow, count(1));
+}
+
+#[test]
+fn test_histogram_rejects_non_finite_values() {
+    let stdin = ProgramRegistry::new()
+        .get("histogram")
+        .expect("histogram is registered")
+        .inputs
+        .build_stdin(&[0, 60], &[1.0, f64::NAN], &[("edges", "0,5,10")])
+        .expect("valid inputs");
+    assert!(ProverClient::new()
+        .execute(HISTOGRAM_ELF, stdin)
+        .run()
+        .is_err());
+}
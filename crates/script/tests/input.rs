@@ -0,0 +1,60 @@
+//! Runs the proving scripts on a fixture file passed with `--input`.
+
+use std::path::Path;
+use std::process::Command;
+
+use alloy_sol_types::SolType;
+use lib_timeseries::PublicValuesStruct;
+use sp1_sdk::ProverClient;
+use zk_timeseries_script::{load_series, registry::ProgramRegistry};
+
+const DATA_HASH_ELF: &[u8] = include_bytes!("../../../elf/riscv32im-succinct-zkvm-data-hash-elf");
+
+fn fixture(name: &str) -> std::path::PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name)
+}
+
+#[test]
+fn test_loaded_series_round_trips_through_execution() {
+    let series = load_series(&fixture("series.csv")).expect("fixture loads");
+    let stdin = ProgramRegistry::new()
+        .get("data-hash")
+        .expect("data-hash is registered")
+        .inputs
+        .build_stdin(&series.timestamps, &series.values, &[])
+        .expect("valid inputs");
+    let (output, _) = ProverClient::new()
+        .execute(DATA_HASH_ELF, stdin)
+        .run()
+        .expect("execution failed");
+    let output = PublicValuesStruct::abi_decode(output.as_slice(), true).expect("invalid output");
+
+    let expected = series.to_public_values().expect("finite values");
+    assert_eq!(output.start_timestamp, expected.start_timestamp);
+    assert_eq!(output.end_timestamp, expected.end_timestamp);
+    assert_eq!(output.values_hash, expected.values_hash);
+    assert_eq!(output.mean, expected.mean);
+}
+
+#[test]
+fn test_execute_with_input_flag() {
+    for name in ["series.csv", "series.json"] {
+        let status = Command::new(env!("CARGO_BIN_EXE_hash"))
+            .arg("--execute")
+            .arg("--input")
+            .arg(fixture(name))
+            .status()
+            .expect("failed to run the hash script");
+        assert!(status.success(), "{name}");
+    }
+
+    let status = Command::new(env!("CARGO_BIN_EXE_hash"))
+        .arg("--execute")
+        .arg("--input")
+        .arg(fixture("missing.csv"))
+        .status()
+        .expect("failed to run the hash script");
+    assert!(!status.success());
+}
@@ -0,0 +1,33 @@
+//! Saves the proving and verifying keys, reloads them and proves with the reloaded keys.
+
+use sp1_sdk::{HashableKey, ProverClient, SP1Stdin};
+use zk_timeseries_script::keys::{load_keys, save_keys};
+
+const DATA_HASH_ELF: &[u8] = include_bytes!("../../../elf/riscv32im-succinct-zkvm-data-hash-elf");
+const MOVING_AVERAGE_ELF: &[u8] =
+    include_bytes!("../../../elf/riscv32im-succinct-zkvm-moving-average-elf");
+
+#[test]
+fn test_reloaded_keys_verify_a_proof() {
+    let dir = std::env::temp_dir().join(format!("zk-timeseries-keys-{}", std::process::id()));
+    let client = ProverClient::new();
+    let (pk, vk) = client.setup(DATA_HASH_ELF);
+    save_keys(&dir, "data-hash", DATA_HASH_ELF, &pk, &vk).expect("keys save");
+
+    let (pk, reloaded_vk) = load_keys(&dir, "data-hash", DATA_HASH_ELF).expect("keys reload");
+    assert_eq!(reloaded_vk.bytes32(), vk.bytes32());
+
+    let mut stdin = SP1Stdin::new();
+    stdin.write(&vec![0u64, 86400, 172800]);
+    stdin.write(&vec![1.0f64, 2.5, 4.0]);
+    let proof = client.prove(&pk, stdin).run().expect("proving failed");
+    client
+        .verify(&proof, &reloaded_vk)
+        .expect("proof does not verify against the reloaded vk");
+
+    // Keys saved for another ELF are stale and must not be returned.
+    assert!(load_keys(&dir, "data-hash", MOVING_AVERAGE_ELF).is_none());
+    assert!(load_keys(&dir, "missing", DATA_HASH_ELF).is_none());
+
+    std::fs::remove_dir_all(&dir).expect("failed to remove the key directory");
+}
@@ -0,0 +1,33 @@
+//! Executes the VWAP program and checks its committed output.
+
+use alloy_sol_types::SolType;
+use lib_timeseries::{TimeSeries, VwapPublicValuesStruct};
+use sp1_sdk::{ProverClient, SP1Stdin};
+
+const VWAP_ELF: &[u8] = include_bytes!("../../../elf/riscv32im-succinct-zkvm-vwap-elf");
+
+#[test]
+fn test_vwap_matches_host() {
+    let timestamps: Vec<u64> = vec![0, 60, 120, 180];
+    let prices = vec![10.0, 12.0, 11.0, 20.0];
+    let volumes = vec![0.0, 100.0, 300.0, 0.0];
+
+    let mut stdin = SP1Stdin::new();
+    stdin.write(&timestamps);
+    stdin.write(&prices);
+    stdin.write(&volumes);
+    let (output, _) = ProverClient::new()
+        .execute(VWAP_ELF, stdin)
+        .run()
+        .expect("execution failed");
+    let output =
+        VwapPublicValuesStruct::abi_decode(output.as_slice(), true).expect("invalid output");
+
+    let volumes = TimeSeries::new(timestamps.clone(), volumes);
+    let expected = TimeSeries::new(timestamps, prices)
+        .to_vwap_public_values(&volumes)
+        .expect("valid volumes");
+    assert_eq!(output.vwap, expected.vwap);
+    assert_eq!(output.values_hash, expected.values_hash);
+    assert_eq!(output.volumes_hash, expected.volumes_hash);
+}
@@ -0,0 +1,35 @@
+//! A SP1 program proving that a published series is a deterministic transform of a private one.
+//!
+//! The program reads a private raw series together with the published output values, recomputes
+//! the declared transform inside the zkVM and aborts if any output element differs from the
+//! recomputed value by more than the committed tolerance.
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use alloy_sol_types::SolValue;
+use lib_timeseries::{TimeSeries, Transform};
+
+pub fn main() {
+    // Read the raw timestamps and values from the prover
+    let timestamps = sp1_zkvm::io::read::<Vec<u64>>();
+    let raw_values = sp1_zkvm::io::read::<Vec<f64>>();
+
+    // Read the declared transform, the published output and the allowed tolerance
+    let transform = sp1_zkvm::io::read::<Transform>();
+    let output_values = sp1_zkvm::io::read::<Vec<f64>>();
+    let tolerance = sp1_zkvm::io::read::<f64>();
+
+    // Create a TimeSeries instance for the raw data
+    let time_series = TimeSeries::new(timestamps, raw_values);
+
+    // Recompute the transform and link it to the published output
+    let public_values = time_series
+        .to_transform_link_public_values(&transform, &output_values, tolerance)
+        .expect("values must be finite");
+
+    // Encode the public values using ABI encoding
+    let bytes = public_values.abi_encode();
+
+    // Commit the encoded public values as output of the ZK proof
+    sp1_zkvm::io::commit_slice(&bytes);
+}
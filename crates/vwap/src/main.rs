@@ -0,0 +1,31 @@
+//! A SP1 program committing the volume-weighted average price of a price series.
+//!
+//! The prices and volumes are committed by separate data hashes, so an on-chain VWAP oracle can
+//! check each against its source while the individual trades stay private.
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use alloy_sol_types::SolValue;
+use lib_timeseries::TimeSeries;
+
+pub fn main() {
+    // Read the timestamps, prices and volumes from the prover
+    let timestamps = sp1_zkvm::io::read::<Vec<u64>>();
+    let prices = sp1_zkvm::io::read::<Vec<f64>>();
+    let volumes = sp1_zkvm::io::read::<Vec<f64>>();
+
+    // Pair the volumes with the prices on the same timestamps
+    let volumes = TimeSeries::new(timestamps.clone(), volumes);
+    let time_series = TimeSeries::new(timestamps, prices);
+
+    // Generate the public values struct for the VWAP
+    let public_values = time_series
+        .to_vwap_public_values(&volumes)
+        .expect("invalid prices or volumes");
+
+    // Encode the public values using ABI encoding
+    let bytes = public_values.abi_encode();
+
+    // Commit the encoded public values as output of the ZK proof
+    sp1_zkvm::io::commit_slice(&bytes);
+}
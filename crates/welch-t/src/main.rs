@@ -0,0 +1,28 @@
+//! A SP1 program committing Welch's t statistic for a structural break in a time series.
+//!
+//! The statistic compares the values before and after a split point, giving evidence that the
+//! series changed regime there.
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use alloy_sol_types::SolValue;
+use lib_timeseries::TimeSeries;
+
+pub fn main() {
+    // Read the timestamps, values and split point from the prover
+    let timestamps = sp1_zkvm::io::read::<Vec<u64>>();
+    let values = sp1_zkvm::io::read::<Vec<f64>>();
+    let split_index = sp1_zkvm::io::read::<usize>();
+
+    // Create a TimeSeries instance for statistical analysis
+    let time_series = TimeSeries::new(timestamps, values);
+
+    // Generate the public values struct for the two-sample test
+    let public_values = time_series.to_welch_t_public_values(split_index);
+
+    // Encode the public values using ABI encoding
+    let bytes = public_values.abi_encode();
+
+    // Commit the encoded public values as output of the ZK proof
+    sp1_zkvm::io::commit_slice(&bytes);
+}